@@ -124,4 +124,232 @@ pub enum MiraiError {
     
     #[msg("Invalid vesting type")]
     InvalidVestingType,
+
+    // Account Lifecycle Errors
+    #[msg("Stream must be completed or cancelled before it can be closed")]
+    StreamNotFinished,
+
+    #[msg("Vesting must be completed or cancelled before it can be closed")]
+    VestingNotFinished,
+
+    #[msg("Escrow token account still holds tokens")]
+    EscrowNotEmpty,
+
+    #[msg("Unauthorized: only the original payer can close this account")]
+    UnauthorizedClose,
+
+    // Metadata Errors
+    #[msg("Exactly one of stream or vesting must be provided")]
+    InvalidPositionTarget,
+
+    #[msg("Unauthorized: only the DAO authority can update metadata")]
+    UnauthorizedMetadataUpdate,
+
+    // Operator Errors
+    #[msg("Unauthorized: signer is not the registered operator for this position")]
+    UnauthorizedOperator,
+
+    #[msg("Operator delegation has expired")]
+    OperatorExpired,
+
+    #[msg("Withdrawal amount exceeds the operator's per-claim limit")]
+    OperatorLimitExceeded,
+
+    #[msg("Operator expiry must be in the future")]
+    InvalidOperatorExpiry,
+
+    #[msg("Operator may only pay out to the recipient's associated token account")]
+    InvalidPayoutDestination,
+
+    // Rate Change Errors
+    #[msg("Rate change must take effect in the future, within the stream's duration")]
+    InvalidRateChangeTiming,
+
+    #[msg("A rate change is already pending for this stream")]
+    RateChangeAlreadyPending,
+
+    // Acceptance Errors
+    #[msg("Stream is not awaiting acceptance")]
+    StreamNotPending,
+
+    #[msg("Acceptance deadline must be in the future")]
+    InvalidAcceptanceDeadline,
+
+    #[msg("Acceptance deadline has passed")]
+    AcceptanceDeadlinePassed,
+
+    #[msg("Acceptance deadline has not passed yet")]
+    AcceptanceDeadlineNotReached,
+
+    // Expiry Errors
+    #[msg("Invalid expiry policy: claim deadline must be after the end time and inactivity period positive")]
+    InvalidExpiryPolicy,
+
+    #[msg("Unclaimed funds cannot be swept yet")]
+    SweepNotAllowed,
+
+    // Position Terms Errors
+    #[msg("Position cannot be cancelled by the DAO authority")]
+    NotCancelableByAuthority,
+
+    #[msg("Position cannot be cancelled by the recipient")]
+    NotCancelableByRecipient,
+
+    #[msg("Position is not transferable")]
+    NotTransferable,
+
+    #[msg("New recipient must differ from the current recipient")]
+    InvalidTransferRecipient,
+
+    // Renewal Errors
+    #[msg("Stream does not have an active renewal policy")]
+    RenewalNotEnabled,
+
+    #[msg("Current stream period has not ended yet")]
+    RenewalNotDue,
+
+    #[msg("Invalid renewal policy")]
+    InvalidRenewalPolicy,
+
+    // Template Errors
+    #[msg("Template name must be between 1 and 32 bytes")]
+    InvalidTemplateName,
+
+    #[msg("Template creates a different kind of position")]
+    TemplateKindMismatch,
+
+    #[msg("Amount exceeds the template's maximum")]
+    TemplateAmountExceeded,
+
+    // Stream Group Errors
+    #[msg("Stream group name must be between 1 and 32 bytes")]
+    InvalidGroupName,
+
+    #[msg("Account is not a writable member stream of this group")]
+    InvalidGroupMember,
+
+    #[msg("Remaining accounts do not match the expected layout for this group operation")]
+    InvalidGroupAccounts,
+
+    // Payout Allowlist Errors
+    #[msg("Destination change delay must be positive")]
+    InvalidChangeDelay,
+
+    #[msg("Payout destination is not on the recipient's allowlist")]
+    PayoutDestinationNotAllowed,
+
+    #[msg("Payout allowlist is full")]
+    AllowlistFull,
+
+    #[msg("Destination is already on the allowlist")]
+    DestinationAlreadyAllowed,
+
+    #[msg("Destination is not on the allowlist")]
+    DestinationNotFound,
+
+    // Payout Split Errors
+    #[msg("Split shares must name up to 4 distinct destinations, include the primary and sum to 10,000 basis points")]
+    InvalidPayoutSplit,
+
+    #[msg("Split destinations must be passed in the configured order")]
+    PayoutSplitMismatch,
+
+    // Team Errors
+    #[msg("Team name must be between 1 and 32 bytes")]
+    InvalidTeamName,
+
+    #[msg("Team shares must name distinct members and sum to 10,000 basis points")]
+    InvalidTeamShares,
+
+    #[msg("Only the team admin can perform this action")]
+    UnauthorizedTeamAdmin,
+
+    #[msg("Signer is not a member of this team")]
+    NotTeamMember,
+
+    #[msg("Amount exceeds the member's accrued share")]
+    InsufficientTeamShare,
+
+    #[msg("Team streams cannot wait for acceptance since the team cannot sign for itself")]
+    TeamCannotAccept,
+
+    // Withholding Errors
+    #[msg("Withholding rate must be between 1 and 9,999 basis points")]
+    InvalidWithholdingRate,
+
+    #[msg("Withholding vault and ledger are required for this position")]
+    WithholdingAccountsMissing,
+
+    #[msg("Withholding ledger does not cover the current year")]
+    WithholdingLedgerYearMismatch,
+
+    // Termination Errors
+    #[msg("Leaver terms must keep at most 10,000 basis points and the claim window must be positive")]
+    InvalidTerminationPolicy,
+
+    // Tranche Errors
+    #[msg("Vesting already holds the maximum number of tranches")]
+    TooManyTranches,
+
+    // Unlock Errors
+    #[msg("TGE unlock must be at most 10,000 basis points")]
+    InvalidTgeUnlock,
+
+    // Backdating Errors
+    #[msg("Start time is further in the past than the DAO allows")]
+    BackdateExceedsLimit,
+
+    // Acceleration Errors
+    #[msg("Acceleration must cover between 1 and 10,000 basis points of the unvested amount")]
+    InvalidAccelerationTerms,
+    #[msg("Acceleration terms are not triggered for this vesting")]
+    AccelerationNotTriggered,
+    #[msg("Vesting has already been accelerated")]
+    VestingAlreadyAccelerated,
+    #[msg("Acceleration terms are fixed once a change of control covers the vesting")]
+    AccelerationTermsLocked,
+
+    // Merge and Split Errors
+    #[msg("Vestings must be active with matching terms to be merged")]
+    IncompatibleVestings,
+    #[msg("Split share must be between 1 and 9,999 basis points and carve out a non-zero amount")]
+    InvalidSplitShare,
+
+    // Vesting Pool Errors
+    #[msg("Pool name must be between 1 and 32 bytes")]
+    InvalidPoolName,
+    #[msg("Vesting pool does not hold enough unallocated tokens")]
+    InsufficientPoolBalance,
+    #[msg("Vesting pool accounts do not match the grant")]
+    InvalidVestingPool,
+
+    // Option Grant Errors
+    #[msg("Option terms need a positive strike price and exercise window")]
+    InvalidOptionTerms,
+    #[msg("Option grants must be exercised rather than claimed")]
+    OptionMustBeExercised,
+    #[msg("Vesting is not an option grant")]
+    NotAnOptionGrant,
+    #[msg("Exercise window has closed")]
+    OptionExpired,
+    #[msg("Strike payment exceeds the maximum token amount")]
+    StrikePaymentOverflow,
+
+    // Milestone Errors
+    #[msg("Milestone name must be between 1 and 32 bytes")]
+    InvalidMilestoneName,
+    #[msg("Milestone gates must be distinct, non-zero and add up to 10,000 basis points")]
+    InvalidMilestoneGates,
+    #[msg("Milestone gates can only be added before any of the grant has vested")]
+    MilestoneGatesLocked,
+    #[msg("Milestone account does not match the vesting's gates")]
+    InvalidMilestone,
+    #[msg("Unauthorized: only the milestone attester can mark it achieved")]
+    UnauthorizedAttester,
+    #[msg("Milestone has already been achieved")]
+    MilestoneAlreadyAchieved,
+    #[msg("Milestone deadline has passed")]
+    MilestoneDeadlinePassed,
+    #[msg("Milestone can still be achieved")]
+    MilestoneNotMissed,
 } 
\ No newline at end of file
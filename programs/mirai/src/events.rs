@@ -0,0 +1,199 @@
+use anchor_lang::prelude::*;
+use crate::state::{CancellationReason, PaymentCategory, PositionKind, TerminationReason};
+
+/// Emitted when the category or description of a stream or vesting changes
+#[event]
+pub struct MetadataUpdated {
+    /// DAO configuration the position belongs to
+    pub dao_config: Pubkey,
+    /// Stream or vesting account that was updated
+    pub position: Pubkey,
+    /// Whether the position is a stream or a vesting
+    pub kind: PositionKind,
+    /// Category before the update
+    pub old_category: PaymentCategory,
+    /// Category after the update
+    pub new_category: PaymentCategory,
+    /// Description after the update
+    pub description: String,
+    /// Timestamp of the update
+    pub updated_at: i64,
+}
+
+/// Emitted when a future rate change is scheduled on a stream
+#[event]
+pub struct RateChangeScheduled {
+    /// Stream the change applies to
+    pub stream: Pubkey,
+    /// Recipient of the stream
+    pub recipient: Pubkey,
+    /// Timestamp from which the new rate applies
+    pub effective_time: i64,
+    /// Amount already accrued when the change takes effect
+    pub accrued_at_effective_time: u64,
+    /// Total amount of the stream before the change
+    pub old_total_amount: u64,
+    /// Total amount of the stream after the change
+    pub new_total_amount: u64,
+}
+
+/// Emitted when a stream starts a new renewal period
+#[event]
+pub struct StreamRenewed {
+    /// Stream that was renewed
+    pub stream: Pubkey,
+    /// Amount added for the new period
+    pub period_amount: u64,
+    /// Start of the new period
+    pub period_start_time: i64,
+    /// End of the new period
+    pub period_end_time: i64,
+    /// Renewals left after this one, unset when indefinite
+    pub remaining_renewals: Option<u32>,
+}
+
+/// Emitted when a due renewal could not be funded or was over the governance caps
+#[event]
+pub struct StreamRenewalSkipped {
+    /// Stream whose renewal was skipped
+    pub stream: Pubkey,
+    /// Amount the new period required
+    pub period_amount: u64,
+    /// Balance available in the DAO vault
+    pub vault_balance: u64,
+}
+
+/// Emitted whenever a stream is cancelled, with the reason it ended
+#[event]
+pub struct StreamCancelled {
+    /// Stream that was cancelled
+    pub stream: Pubkey,
+    /// Recipient of the stream
+    pub recipient: Pubkey,
+    /// Why the stream was cancelled
+    pub reason: CancellationReason,
+    /// Amount paid out to the recipient on cancellation
+    pub settled_amount: u64,
+    /// Amount returned to the DAO treasury
+    pub refunded_amount: u64,
+    /// Timestamp of the cancellation
+    pub cancelled_at: i64,
+}
+
+/// Emitted when a recipient queues a new payout destination
+#[event]
+pub struct PayoutDestinationQueued {
+    /// Recipient whose allowlist changed
+    pub recipient: Pubkey,
+    /// Token account that was queued
+    pub destination: Pubkey,
+    /// Timestamp from which the destination may be used
+    pub effective_at: i64,
+}
+
+/// Emitted when the DAO revokes a vesting and claws back the unvested tokens
+#[event]
+pub struct VestingRevoked {
+    /// Vesting that was revoked
+    pub vesting: Pubkey,
+    /// Recipient of the vesting
+    pub recipient: Pubkey,
+    /// Amount that had vested and stays claimable by the recipient
+    pub vested_amount: u64,
+    /// Unvested amount returned to the DAO treasury
+    pub returned_amount: u64,
+    /// Timestamp of the revocation
+    pub revoked_at: i64,
+}
+
+/// Emitted when the DAO terminates a vesting under its leaver terms
+#[event]
+pub struct VestingTerminated {
+    /// Vesting that was terminated
+    pub vesting: Pubkey,
+    /// Recipient of the vesting
+    pub recipient: Pubkey,
+    /// Why the engagement ended
+    pub reason: TerminationReason,
+    /// Total amount the recipient keeps, including anything already claimed
+    pub retained_amount: u64,
+    /// Amount returned to the DAO treasury
+    pub returned_amount: u64,
+    /// Deadline for claiming the retained amount, if the policy sets a window
+    pub claim_deadline: Option<i64>,
+    /// Timestamp of the termination
+    pub terminated_at: i64,
+}
+
+/// Emitted when the DAO declares a change of control
+#[event]
+pub struct AccelerationTriggered {
+    /// DAO configuration that declared the event
+    pub dao_config: Pubkey,
+    /// Position among the DAO's events
+    pub index: u32,
+    /// Timestamp of the change of control
+    pub triggered_at: i64,
+}
+
+/// Emitted when a vesting's acceleration terms are applied
+#[event]
+pub struct VestingAccelerated {
+    /// Vesting that was accelerated
+    pub vesting: Pubkey,
+    /// Recipient of the vesting
+    pub recipient: Pubkey,
+    /// Amount that vested immediately
+    pub accelerated_amount: u64,
+    /// Timestamp of the acceleration
+    pub accelerated_at: i64,
+}
+
+/// Emitted with the totals of a vesting pool when reported
+#[event]
+pub struct VestingPoolReported {
+    /// Vesting pool being reported
+    pub vesting_pool: Pubkey,
+    /// Total amount ever deposited into the pool
+    pub total_funded: u64,
+    /// Balance not yet granted out
+    pub available_amount: u64,
+    /// Amount granted out and not since forfeited
+    pub allocated_amount: u64,
+    /// Amount vested across the reported grants
+    pub vested_amount: u64,
+    /// Amount claimed across the reported grants
+    pub claimed_amount: u64,
+    /// Total amount recycled back from revoked or terminated grants
+    pub forfeited_amount: u64,
+    /// Number of grants included in the vested and claimed totals
+    pub grants_reported: u32,
+    /// Timestamp of the report
+    pub reported_at: i64,
+}
+
+/// Emitted when a recipient exercises vested options by paying the strike
+#[event]
+pub struct OptionExercised {
+    /// Option grant that was exercised
+    pub vesting: Pubkey,
+    /// Recipient exercising the options
+    pub recipient: Pubkey,
+    /// Tokens released to the recipient
+    pub amount: u64,
+    /// Strike paid to the DAO treasury, in the strike mint
+    pub strike_amount: u64,
+    /// Timestamp of the exercise
+    pub exercised_at: i64,
+}
+
+/// Emitted when an attester marks a milestone achieved
+#[event]
+pub struct MilestoneAchieved {
+    /// Milestone that was achieved
+    pub milestone: Pubkey,
+    /// Attester that marked it achieved
+    pub attester: Pubkey,
+    /// Timestamp of the attestation
+    pub achieved_at: i64,
+}
@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+use crate::AcceptStream;
+
+pub fn handler(ctx: Context<AcceptStream>, agreement_hash: Option<[u8; 32]>) -> Result<()> {
+    let clock = Clock::get()?;
+    let stream = &mut ctx.accounts.stream;
+
+    stream.accept(clock.unix_timestamp, agreement_hash)?;
+
+    msg!("Stream accepted by recipient");
+    msg!("Recipient: {}", stream.recipient);
+    msg!("Start Time: {}", stream.start_time);
+    msg!("End Time: {}", stream.end_time);
+    msg!("Agreement Hash Committed: {}", stream.agreement_hash.is_some());
+
+    Ok(())
+}
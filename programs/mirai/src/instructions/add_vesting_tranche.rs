@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Transfer;
+
+use crate::state::VestingTranche;
+use crate::errors::MiraiError;
+use crate::AddVestingTranche;
+
+pub fn handler(ctx: Context<AddVestingTranche>, tranche: VestingTranche) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    let amount = tranche.amount;
+    
+    // A tranche starting before now backdates the grant, within the same look-back as creation
+    let backdated = tranche.start_time < current_time;
+    
+    // The tranche is new allocation on top of the existing grant
+    let dao_config = &mut ctx.accounts.dao_config;
+    require!(dao_config.is_treasury_active(), MiraiError::TreasuryPaused);
+    require!(dao_config.validate_total_allocation(amount), MiraiError::TotalAllocationExceedsLimit);
+    require!(
+        tranche.start_time > current_time || dao_config.validate_backdate(tranche.start_time, current_time),
+        MiraiError::BackdateExceedsLimit
+    );
+    
+    // Pool grants are funded only from their pool, which tracks their allocation
+    let vesting = &mut ctx.accounts.vesting;
+    require!(vesting.pool.is_none(), MiraiError::InvalidVestingPool);
+    vesting.add_tranche(tranche)?;
+    vesting.backdated = vesting.backdated || backdated;
+    require!(dao_config.validate_stream_amount(vesting.total_amount), MiraiError::StreamAmountExceedsLimit);
+    dao_config.total_allocated = dao_config.total_allocated.saturating_add(amount);
+    
+    // Fund the tranche from the authority
+    let transfer_ctx = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.authority_ata.to_account_info(),
+            to: ctx.accounts.vesting_ata.to_account_info(),
+            authority: ctx.accounts.authority.to_account_info(),
+        },
+    );
+    
+    anchor_spl::token::transfer(transfer_ctx, amount)?;
+    
+    msg!("Vesting tranche added");
+    msg!("Recipient: {}", vesting.recipient);
+    msg!("Amount: {}", amount);
+    msg!("Tranches: {}", vesting.tranches.len());
+    msg!("Total Amount: {}", vesting.total_amount);
+    if backdated {
+        msg!("Backdated: vested to date is claimable now");
+    }
+    
+    Ok(())
+}
@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+use crate::errors::MiraiError;
+use crate::events::VestingAccelerated;
+use crate::ApplyAcceleration;
+
+pub fn handler(ctx: Context<ApplyAcceleration>) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // Anyone may crank a single-trigger vesting once the DAO has declared the event;
+    // double-trigger vestings accelerate when terminated without cause within the window
+    let triggered_at = ctx.accounts.acceleration_event.triggered_at;
+    let vesting = &mut ctx.accounts.vesting;
+    require!(vesting.can_accelerate(None, triggered_at, current_time), MiraiError::AccelerationNotTriggered);
+
+    let accelerated_amount = vesting.accelerate(current_time)?;
+
+    msg!("Vesting accelerated");
+    msg!("Recipient: {}", vesting.recipient);
+    msg!("Accelerated: {}", accelerated_amount);
+    msg!("Triggered at: {}", triggered_at);
+
+    emit!(VestingAccelerated {
+        vesting: vesting.key(),
+        recipient: vesting.recipient,
+        accelerated_amount,
+        accelerated_at: current_time,
+    });
+
+    Ok(())
+}
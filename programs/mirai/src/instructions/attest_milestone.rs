@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+use crate::events::MilestoneAchieved;
+use crate::AttestMilestone;
+
+pub fn handler(ctx: Context<AttestMilestone>) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+
+    let milestone = &mut ctx.accounts.milestone;
+    milestone.attest(current_time)?;
+
+    msg!("Milestone achieved");
+    msg!("Name: {}", milestone.name);
+    msg!("Achieved at: {}", current_time);
+
+    emit!(MilestoneAchieved {
+        milestone: milestone.key(),
+        attester: milestone.attester,
+        achieved_at: current_time,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,149 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token::{TokenAccount, Transfer};
+use crate::errors::MiraiError;
+use crate::events::StreamCancelled;
+use crate::state::{
+    CancellationReason, PayoutRoute, PayoutSplit, StreamGroup, Team, WithholdingLedger, WithholdingPolicy,
+};
+use crate::CancelStreamGroup;
+
+/// Accounts every member passes before its split destinations
+const MEMBER_ACCOUNTS: usize = 6;
+
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, CancelStreamGroup<'info>>) -> Result<()> {
+    require!(!ctx.remaining_accounts.is_empty(), MiraiError::InvalidGroupAccounts);
+    let dao_config_key = ctx.accounts.dao_config.key();
+    let group_key = ctx.accounts.stream_group.key();
+    let treasury_mint_key = ctx.accounts.treasury_mint.key();
+    let current_time = Clock::get()?.unix_timestamp;
+
+    let mut accounts = ctx.remaining_accounts;
+    let mut member_count: usize = 0;
+    while !accounts.is_empty() {
+        require!(accounts.len() >= MEMBER_ACCOUNTS, MiraiError::InvalidGroupAccounts);
+        let (member, rest) = accounts.split_at(MEMBER_ACCOUNTS);
+
+        let mut stream = StreamGroup::load_member(&group_key, &member[0])?;
+        let stream_ata: Account<'info, TokenAccount> = Account::try_from(&member[1])?;
+        require!(stream_ata.key() == stream.stream_ata, MiraiError::InvalidTokenAccount);
+        let (allowlist_key, _) = Pubkey::find_program_address(
+            &[b"payout_allowlist", dao_config_key.as_ref(), stream.recipient.as_ref()],
+            &crate::ID,
+        );
+        let (split_key, _) = Pubkey::find_program_address(
+            &[b"payout_split", stream.key().as_ref()],
+            &crate::ID,
+        );
+        let (withholding_key, _) = Pubkey::find_program_address(
+            &[b"withholding", stream.key().as_ref()],
+            &crate::ID,
+        );
+        require!(
+            member[3].key() == allowlist_key
+                && member[4].key() == split_key
+                && member[5].key() == withholding_key,
+            MiraiError::InvalidGroupAccounts
+        );
+
+        // A member with a withholding policy passes the recipient's ledger next
+        let (mut withholding_ledger, rest) = match WithholdingPolicy::load(&member[5])? {
+            Some(_) => {
+                require!(!rest.is_empty(), MiraiError::InvalidGroupAccounts);
+                let ledger: Account<'info, WithholdingLedger> = Account::try_from(&rest[0])?;
+                require!(
+                    ledger.dao_config == dao_config_key && ledger.recipient == stream.recipient,
+                    MiraiError::InvalidGroupAccounts
+                );
+                (Some(ledger), &rest[1..])
+            }
+            None => (None, rest),
+        };
+
+        // A member with a split passes one destination per share after that
+        let split_len = PayoutSplit::load(&member[4])?.map_or(0, |split| split.shares.len());
+        require!(rest.len() >= split_len, MiraiError::InvalidGroupAccounts);
+        let (split_destinations, rest) = rest.split_at(split_len);
+        accounts = rest;
+        member_count += 1;
+
+        stream.terms.require_cancelable_by_authority()?;
+
+        // Everything unlocked so far still belongs to the recipient
+        let owed_amount = stream.settle_and_cancel(current_time, CancellationReason::Terminated)?;
+        let refund_amount = stream_ata.amount.saturating_sub(owed_amount);
+
+        let dao_config = &mut ctx.accounts.dao_config;
+        dao_config.add_payment(owed_amount);
+        dao_config.release_allocation(stream.get_remaining_amount());
+        ctx.accounts.stream_group.record_cancellation(owed_amount, refund_amount);
+
+        let recipient_key = stream.recipient;
+        let seeds = &[
+            b"stream",
+            dao_config_key.as_ref(),
+            recipient_key.as_ref(),
+            &[stream.bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        // A team member passes the team in place of a destination; its members withdraw
+        // their shares of the settlement from the escrow
+        let is_team = Team::is_team(&member[2]);
+        require!(!is_team || member[2].key() == recipient_key, MiraiError::InvalidGroupAccounts);
+        let settled_amount = if is_team { 0 } else { owed_amount };
+        PayoutRoute {
+            escrow: member[1].clone(),
+            position: member[0].clone(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+            mint: treasury_mint_key,
+            destination: member[2].clone(),
+            recipient_ata: get_associated_token_address(&recipient_key, &treasury_mint_key),
+            allow_any_destination: false,
+            payout_allowlist: &member[3],
+            payout_split: &member[4],
+            split_destinations,
+            withholding_policy: &member[5],
+            withholding_vault: ctx.accounts.withholding_vault.as_ref(),
+            withholding_ledger: withholding_ledger.as_mut(),
+        }.settle(settled_amount, current_time, signer_seeds)?;
+
+        if let Some(ledger) = withholding_ledger {
+            ledger.exit(&crate::ID)?;
+        }
+
+        if refund_amount > 0 {
+            let transfer_ctx = CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: member[1].clone(),
+                    to: ctx.accounts.authority_ata.to_account_info(),
+                    authority: member[0].clone(),
+                },
+                signer_seeds,
+            );
+            anchor_spl::token::transfer(transfer_ctx, refund_amount)?;
+        }
+
+        stream.exit(&crate::ID)?;
+
+        emit!(StreamCancelled {
+            stream: stream.key(),
+            recipient: recipient_key,
+            reason: CancellationReason::Terminated,
+            settled_amount: owed_amount,
+            refunded_amount: refund_amount,
+            cancelled_at: current_time,
+        });
+    }
+
+    let stream_group = &ctx.accounts.stream_group;
+    msg!("Stream group cancelled");
+    msg!("Group: {}", stream_group.name);
+    msg!("Streams: {}", member_count);
+    msg!("Group Total Settled: {}", stream_group.total_settled);
+    msg!("Group Total Refunded: {}", stream_group.total_refunded);
+    msg!("Treasury Total Allocated: {}", ctx.accounts.dao_config.total_allocated);
+
+    Ok(())
+}
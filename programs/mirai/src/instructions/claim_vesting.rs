@@ -1,45 +1,25 @@
 use anchor_lang::prelude::*;
-use anchor_spl::{
-    token::{Mint, Token, TokenAccount, Transfer},
-    associated_token::AssociatedToken,
-};
 
-use crate::state::{DaoConfig, Vesting, VestingStatus};
 use crate::errors::MiraiError;
+use crate::state::{PayoutRoute, VestingStatus};
 use crate::ClaimVesting;
 
-pub fn handler(
-    ctx: Context<ClaimVesting>,
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ClaimVesting<'info>>,
     amount: u64,
 ) -> Result<()> {
     let current_time = Clock::get()?.unix_timestamp;
     
-    // Validate claim amount
-    require!(amount > 0, MiraiError::InvalidClaimAmount);
-    
     // Store values before mutable borrow
     let dao_config_key = ctx.accounts.dao_config.key();
     let recipient_key = ctx.accounts.recipient.key();
     let vesting_bump = ctx.accounts.vesting.bump;
     let vesting_account_info = ctx.accounts.vesting.to_account_info();
     
-    // Get vesting account and validate
+    // Validate and record the claim, completing the vesting when drained
     let vesting = &mut ctx.accounts.vesting;
-    require!(vesting.status == VestingStatus::Active, MiraiError::VestingNotActive);
-    require!(current_time >= vesting.start_time, MiraiError::VestingNotStarted);
-    
-    // Calculate claimable amount
-    let claimable_amount = vesting.get_claimable_amount(current_time);
-    require!(amount <= claimable_amount, MiraiError::InsufficientVestedTokens);
-    
-    // Update vesting account
-    vesting.claimed_amount += amount;
-    
-    // Check if vesting is completed
-    let is_completed = vesting.claimed_amount >= vesting.total_amount;
-    if is_completed {
-        vesting.status = VestingStatus::Completed;
-    }
+    require!(vesting.option_terms.is_none(), MiraiError::OptionMustBeExercised);
+    vesting.record_claim(amount, current_time)?;
     
     // Update DAO config statistics
     let dao_config = &mut ctx.accounts.dao_config;
@@ -53,18 +33,27 @@ pub fn handler(
         &[vesting_bump],
     ];
     let signer_seeds = &[&seeds[..]];
-    
-    let transfer_ctx = CpiContext::new_with_signer(
-        ctx.accounts.token_program.to_account_info(),
-        Transfer {
-            from: ctx.accounts.vesting_ata.to_account_info(),
-            to: ctx.accounts.recipient_ata.to_account_info(),
-            authority: vesting_account_info,
-        },
-        signer_seeds,
-    );
-    
-    anchor_spl::token::transfer(transfer_ctx, amount)?;
+
+    // Without a split, payouts go to the recipient's ATA unless they registered an allowlist
+    let destination = match &ctx.accounts.payout_destination {
+        Some(payout_destination) => payout_destination.to_account_info(),
+        None => ctx.accounts.recipient_ata.to_account_info(),
+    };
+    PayoutRoute {
+        escrow: ctx.accounts.vesting_ata.to_account_info(),
+        position: vesting_account_info,
+        token_program: ctx.accounts.token_program.to_account_info(),
+        mint: ctx.accounts.treasury_mint.key(),
+        destination,
+        recipient_ata: ctx.accounts.recipient_ata.key(),
+        allow_any_destination: false,
+        payout_allowlist: &ctx.accounts.payout_allowlist,
+        payout_split: &ctx.accounts.payout_split,
+        split_destinations: ctx.remaining_accounts,
+        withholding_policy: &ctx.accounts.withholding_policy,
+        withholding_vault: ctx.accounts.withholding_vault.as_ref(),
+        withholding_ledger: ctx.accounts.withholding_ledger.as_mut(),
+    }.settle(amount, current_time, signer_seeds)?;
     
     msg!("Vesting claimed successfully");
     msg!("Recipient: {}", ctx.accounts.recipient.key());
@@ -0,0 +1,9 @@
+use anchor_lang::prelude::*;
+use crate::ClearPayoutSplit;
+
+pub fn handler(ctx: Context<ClearPayoutSplit>) -> Result<()> {
+    msg!("Payout split cleared");
+    msg!("Position: {}", ctx.accounts.payout_split.position);
+
+    Ok(())
+}
@@ -0,0 +1,9 @@
+use anchor_lang::prelude::*;
+use crate::ClearWithholding;
+
+pub fn handler(ctx: Context<ClearWithholding>) -> Result<()> {
+    msg!("Withholding cleared");
+    msg!("Position: {}", ctx.accounts.withholding_policy.position);
+
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::CloseAccount;
+use crate::errors::MiraiError;
+use crate::CloseStream;
+
+pub fn handler(ctx: Context<CloseStream>) -> Result<()> {
+    let dao_config_key = ctx.accounts.dao_config.key();
+    let stream = &ctx.accounts.stream;
+
+    require!(stream.is_finished(), MiraiError::StreamNotFinished);
+    require!(ctx.accounts.stream_ata.amount == 0, MiraiError::EscrowNotEmpty);
+
+    let recipient_key = stream.recipient;
+    let seeds = &[
+        b"stream",
+        dao_config_key.as_ref(),
+        recipient_key.as_ref(),
+        &[stream.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    // Close the escrow token account, returning its rent to the original payer
+    let close_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.stream_ata.to_account_info(),
+            destination: ctx.accounts.authority.to_account_info(),
+            authority: ctx.accounts.stream.to_account_info(),
+        },
+        signer_seeds,
+    );
+    anchor_spl::token::close_account(close_ctx)?;
+
+    msg!("Stream closed and rent reclaimed");
+    msg!("Recipient: {}", recipient_key);
+    msg!("Final Status: {:?}", ctx.accounts.stream.status);
+
+    Ok(())
+}
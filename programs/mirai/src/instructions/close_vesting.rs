@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::CloseAccount;
+use crate::errors::MiraiError;
+use crate::CloseVesting;
+
+pub fn handler(ctx: Context<CloseVesting>) -> Result<()> {
+    let dao_config_key = ctx.accounts.dao_config.key();
+    let vesting = &ctx.accounts.vesting;
+
+    require!(vesting.is_finished(), MiraiError::VestingNotFinished);
+    require!(ctx.accounts.vesting_ata.amount == 0, MiraiError::EscrowNotEmpty);
+
+    let recipient_key = vesting.recipient;
+    let seeds = &[
+        b"vesting",
+        dao_config_key.as_ref(),
+        recipient_key.as_ref(),
+        &[vesting.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    // Close the escrow token account, returning its rent to the original payer
+    let close_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.vesting_ata.to_account_info(),
+            destination: ctx.accounts.authority.to_account_info(),
+            authority: ctx.accounts.vesting.to_account_info(),
+        },
+        signer_seeds,
+    );
+    anchor_spl::token::close_account(close_ctx)?;
+
+    msg!("Vesting closed and rent reclaimed");
+    msg!("Recipient: {}", recipient_key);
+    msg!("Final Status: {:?}", ctx.accounts.vesting.status);
+
+    Ok(())
+}
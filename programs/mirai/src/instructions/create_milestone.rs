@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+use crate::errors::MiraiError;
+use crate::state::Milestone;
+use crate::CreateMilestone;
+
+pub fn handler(ctx: Context<CreateMilestone>, name: String, attester: Pubkey, deadline: i64) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    require!(
+        !name.is_empty() && name.len() <= Milestone::MAX_NAME_LEN,
+        MiraiError::InvalidMilestoneName
+    );
+    require!(deadline > current_time, MiraiError::MilestoneDeadlinePassed);
+
+    let milestone = &mut ctx.accounts.milestone;
+    milestone.dao_config = ctx.accounts.dao_config.key();
+    milestone.name = name;
+    milestone.attester = attester;
+    milestone.deadline = deadline;
+    milestone.achieved_at = None;
+    milestone.bump = ctx.bumps.milestone;
+    milestone.created_at = current_time;
+
+    msg!("Milestone created successfully");
+    msg!("Name: {}", milestone.name);
+    msg!("Attester: {}", milestone.attester);
+    msg!("Deadline: {}", milestone.deadline);
+
+    Ok(())
+}
@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+use crate::errors::MiraiError;
+use crate::CreatePayoutAllowlist;
+
+/// Create the recipient's allowlist with its first destination. Later destinations
+/// are queued and wait `change_delay` seconds before they can receive payouts.
+pub fn handler(ctx: Context<CreatePayoutAllowlist>, change_delay: i64) -> Result<()> {
+    require!(change_delay > 0, MiraiError::InvalidChangeDelay);
+
+    let payout_allowlist = &mut ctx.accounts.payout_allowlist;
+    payout_allowlist.dao_config = ctx.accounts.dao_config.key();
+    payout_allowlist.recipient = ctx.accounts.recipient.key();
+    payout_allowlist.destinations = vec![ctx.accounts.destination.key()];
+    payout_allowlist.pending_destination = None;
+    payout_allowlist.pending_effective_at = 0;
+    payout_allowlist.change_delay = change_delay;
+    payout_allowlist.bump = ctx.bumps.payout_allowlist;
+    payout_allowlist.created_at = Clock::get()?.unix_timestamp;
+
+    msg!("Payout allowlist created");
+    msg!("Recipient: {}", payout_allowlist.recipient);
+    msg!("Destination: {}", ctx.accounts.destination.key());
+    msg!("Change Delay: {} seconds", change_delay);
+
+    Ok(())
+}
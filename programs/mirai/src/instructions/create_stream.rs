@@ -1,12 +1,10 @@
 use anchor_lang::prelude::*;
-use anchor_spl::{
-    token::{Mint, Token, TokenAccount, Transfer},
-    associated_token::AssociatedToken,
-};
+use anchor_spl::token::Transfer;
 use crate::errors::MiraiError;
-use crate::state::{DaoConfig, Stream, PaymentCategory, StreamStatus};
+use crate::state::{ExpiryPolicy, PaymentCategory, PositionTerms, StreamStatus, Team};
 use crate::CreateStream;
 
+#[allow(clippy::too_many_arguments)]
 pub fn handler(
     ctx: Context<CreateStream>,
     start_time: i64,
@@ -14,6 +12,9 @@ pub fn handler(
     total_amount: u64,
     category: PaymentCategory,
     description: String,
+    acceptance_deadline: Option<i64>,
+    expiry_policy: Option<ExpiryPolicy>,
+    terms: PositionTerms,
 ) -> Result<()> {
     let dao_config_key = ctx.accounts.dao_config.key();
     let dao_config = &mut ctx.accounts.dao_config;
@@ -33,6 +34,12 @@ pub fn handler(
     require!(dao_config.validate_stream_amount(total_amount), MiraiError::StreamAmountExceedsLimit);
     require!(dao_config.validate_total_allocation(total_amount), MiraiError::TotalAllocationExceedsLimit);
     require!(description.len() <= 64, MiraiError::DescriptionTooLong);
+    let expiry_policy = expiry_policy.unwrap_or_default();
+    expiry_policy.validate(end_time)?;
+    if let Some(deadline) = acceptance_deadline {
+        require!(deadline > clock.unix_timestamp, MiraiError::InvalidAcceptanceDeadline);
+        require!(!Team::is_team(&ctx.accounts.recipient), MiraiError::TeamCannotAccept);
+    }
     
   
     stream.dao_config = dao_config_key;
@@ -46,11 +53,34 @@ pub fn handler(
     stream.stream_ata = ctx.accounts.stream_ata.key();
     stream.bump = ctx.bumps.stream;
     stream.created_at = clock.unix_timestamp;
+    stream.segment_start_time = start_time;
+    stream.segment_base_amount = 0;
+    stream.pending_rate_change = None;
+    stream.acceptance_deadline = acceptance_deadline;
+    stream.accepted_at = None;
+    stream.agreement_hash = None;
+    stream.expiry_policy = expiry_policy;
+    stream.last_withdrawal_at = 0;
+    stream.terms = terms;
+    stream.period_start_time = start_time;
+    stream.period_base_amount = 0;
+    stream.renewal_policy = None;
+    stream.group = None;
+    stream.paused_at = None;
+    if let Some(stream_group) = ctx.accounts.stream_group.as_mut() {
+        stream_group.add_member(total_amount);
+        stream.group = Some(stream_group.key());
+    }
     
     
     stream.category = category;
     stream.description = description;
-    stream.status = StreamStatus::Active;
+    // Streams offered with a deadline wait for the recipient before accruing
+    stream.status = if acceptance_deadline.is_some() {
+        StreamStatus::Pending
+    } else {
+        StreamStatus::Active
+    };
     
     // Update treasury statistics
     dao_config.add_stream(total_amount);
@@ -74,6 +104,9 @@ pub fn handler(
     msg!("Start Time: {}", stream.start_time);
     msg!("End Time: {}", stream.end_time);
     msg!("Duration: {} seconds", end_time - start_time);
+    msg!("Status: {:?}", stream.status);
+    msg!("Terms: {:?}", stream.terms);
+    msg!("Group: {:?}", stream.group);
     msg!("Treasury Total Streams: {}", dao_config.total_streams);
     msg!("Treasury Total Allocated: {}", dao_config.total_allocated);
     
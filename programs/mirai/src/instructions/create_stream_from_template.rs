@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Transfer;
+use crate::errors::MiraiError;
+use crate::state::{ExpiryPolicy, PositionKind, StreamStatus};
+use crate::CreateStreamFromTemplate;
+
+pub fn handler(
+    ctx: Context<CreateStreamFromTemplate>,
+    total_amount: u64,
+    start_time: i64,
+) -> Result<()> {
+    let dao_config_key = ctx.accounts.dao_config.key();
+    let template = &ctx.accounts.template;
+    let dao_config = &mut ctx.accounts.dao_config;
+    let stream = &mut ctx.accounts.stream;
+    let clock = Clock::get()?;
+
+    template.validate_position(PositionKind::Stream, total_amount)?;
+    let end_time = template.end_time(start_time);
+    require!(start_time < end_time, MiraiError::InvalidStreamTiming);
+
+    require!(dao_config.is_treasury_active(), MiraiError::TreasuryPaused);
+    require!(dao_config.validate_stream_amount(total_amount), MiraiError::StreamAmountExceedsLimit);
+    require!(dao_config.validate_total_allocation(total_amount), MiraiError::TotalAllocationExceedsLimit);
+
+    stream.dao_config = dao_config_key;
+    stream.recipient = ctx.accounts.recipient.key();
+    stream.authority = ctx.accounts.authority.key();
+    stream.mint = ctx.accounts.treasury_mint.key();
+    stream.category = template.category.clone();
+    stream.description = template.description.clone();
+    stream.total_amount = total_amount;
+    stream.withdrawn_amount = 0;
+    stream.start_time = start_time;
+    stream.end_time = end_time;
+    stream.stream_ata = ctx.accounts.stream_ata.key();
+    stream.status = StreamStatus::Active;
+    stream.bump = ctx.bumps.stream;
+    stream.created_at = clock.unix_timestamp;
+    stream.segment_start_time = start_time;
+    stream.segment_base_amount = 0;
+    stream.pending_rate_change = None;
+    stream.acceptance_deadline = None;
+    stream.accepted_at = None;
+    stream.agreement_hash = None;
+    stream.expiry_policy = ExpiryPolicy::default();
+    stream.last_withdrawal_at = 0;
+    stream.terms = template.terms.clone();
+    stream.period_start_time = start_time;
+    stream.period_base_amount = 0;
+    stream.renewal_policy = None;
+    stream.group = None;
+    stream.paused_at = None;
+
+    // Update treasury statistics
+    dao_config.add_stream(total_amount);
+
+    let transfer_ctx = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.authority_ata.to_account_info(),
+            to: ctx.accounts.stream_ata.to_account_info(),
+            authority: ctx.accounts.authority.to_account_info(),
+        },
+    );
+    anchor_spl::token::transfer(transfer_ctx, total_amount)?;
+
+    msg!("Stream created from template");
+    msg!("Template: {}", template.name);
+    msg!("Recipient: {}", stream.recipient);
+    msg!("Total Amount: {}", stream.total_amount);
+    msg!("Start Time: {}", stream.start_time);
+    msg!("End Time: {}", stream.end_time);
+    msg!("Treasury Total Allocated: {}", dao_config.total_allocated);
+
+    Ok(())
+}
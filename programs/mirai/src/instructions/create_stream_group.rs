@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+use crate::errors::MiraiError;
+use crate::state::StreamGroup;
+use crate::CreateStreamGroup;
+
+pub fn handler(ctx: Context<CreateStreamGroup>, name: String) -> Result<()> {
+    require!(
+        !name.is_empty() && name.len() <= StreamGroup::MAX_NAME_LEN,
+        MiraiError::InvalidGroupName
+    );
+
+    let stream_group = &mut ctx.accounts.stream_group;
+    stream_group.dao_config = ctx.accounts.dao_config.key();
+    stream_group.name = name;
+    stream_group.member_count = 0;
+    stream_group.cancelled_count = 0;
+    stream_group.total_amount = 0;
+    stream_group.total_settled = 0;
+    stream_group.total_refunded = 0;
+    stream_group.bump = ctx.bumps.stream_group;
+    stream_group.created_at = Clock::get()?.unix_timestamp;
+
+    msg!("Stream group created successfully");
+    msg!("Name: {}", stream_group.name);
+
+    Ok(())
+}
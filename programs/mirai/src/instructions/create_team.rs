@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+use crate::errors::MiraiError;
+use crate::state::{Team, TeamShare};
+use crate::CreateTeam;
+
+/// Create a team that can be used as the recipient of a stream
+pub fn handler(ctx: Context<CreateTeam>, name: String, shares: Vec<TeamShare>) -> Result<()> {
+    require!(!name.is_empty() && name.len() <= Team::MAX_NAME_LEN, MiraiError::InvalidTeamName);
+
+    let team = &mut ctx.accounts.team;
+    team.dao_config = ctx.accounts.dao_config.key();
+    team.admin = ctx.accounts.admin.key();
+    team.name = name;
+    team.members = Vec::new();
+    team.set_shares(&shares)?;
+    team.accounted_amount = 0;
+    team.bump = ctx.bumps.team;
+    team.created_at = Clock::get()?.unix_timestamp;
+
+    msg!("Team created successfully");
+    msg!("Team: {}", team.key());
+    msg!("Name: {}", team.name);
+    msg!("Members: {}", team.members.len());
+
+    Ok(())
+}
@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+use crate::state::{Template, TemplateParams};
+use crate::CreateTemplate;
+
+pub fn handler(ctx: Context<CreateTemplate>, name: String, params: TemplateParams) -> Result<()> {
+    Template::validate_params(&name, &params)?;
+
+    let template = &mut ctx.accounts.template;
+    template.dao_config = ctx.accounts.dao_config.key();
+    template.name = name;
+    template.kind = params.kind;
+    template.vesting_type = params.vesting_type;
+    template.duration = params.duration;
+    template.cliff_offset = params.cliff_offset;
+    template.category = params.category;
+    template.description = params.description;
+    template.terms = params.terms;
+    template.max_amount = params.max_amount;
+    template.bump = ctx.bumps.template;
+    template.created_at = Clock::get()?.unix_timestamp;
+
+    msg!("Template created successfully");
+    msg!("Name: {}", template.name);
+    msg!("Kind: {:?}", template.kind);
+    msg!("Category: {:?}", template.category);
+    msg!("Duration: {} seconds", template.duration);
+    msg!("Max Amount: {:?}", template.max_amount);
+
+    Ok(())
+}
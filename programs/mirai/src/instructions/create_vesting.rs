@@ -1,13 +1,11 @@
 use anchor_lang::prelude::*;
-use anchor_spl::{
-    token::{Mint, Token, TokenAccount, Transfer},
-    associated_token::AssociatedToken,
-};
+use anchor_spl::token::Transfer;
 
-use crate::state::{DaoConfig, Vesting, VestingType, VestingStatus, PaymentCategory};
+use crate::state::{ExpiryPolicy, OptionTerms, PositionTerms, TerminationPolicy, Vesting, VestingType, VestingStatus, PaymentCategory};
 use crate::errors::MiraiError;
 use crate::CreateVesting;
 
+#[allow(clippy::too_many_arguments)]
 pub fn handler(
     ctx: Context<CreateVesting>,
     vesting_type: VestingType,
@@ -17,19 +15,44 @@ pub fn handler(
     cliff_time: i64,
     category: PaymentCategory,
     description: String,
+    expiry_policy: Option<ExpiryPolicy>,
+    terms: PositionTerms,
+    termination_policy: Option<TerminationPolicy>,
+    tge_unlock_bps: Option<u16>,
+    option_terms: Option<OptionTerms>,
 ) -> Result<()> {
     let current_time = Clock::get()?.unix_timestamp;
     
     // Validate vesting parameters
     require!(total_amount > 0, MiraiError::InvalidTotalAmount);
     require!(start_time < end_time, MiraiError::InvalidVestingTiming);
-    require!(start_time > current_time, MiraiError::InvalidVestingTiming);
+    
+    // A start time before now backdates the grant
+    let backdated = start_time < current_time;
     
     // Validate cliff timing for cliff vesting
     if matches!(vesting_type, VestingType::Cliff) {
         require!(cliff_time >= start_time && cliff_time <= end_time, MiraiError::InvalidCliffTiming);
     }
     
+    // Validate the unlock at start
+    let tge_unlock_bps = tge_unlock_bps.unwrap_or(0);
+    Vesting::validate_tge_unlock(tge_unlock_bps)?;
+    
+    // Validate strike terms for option grants
+    if let Some(option_terms) = &option_terms {
+        option_terms.validate()?;
+    }
+    
+    // Validate sweep conditions
+    let expiry_policy = expiry_policy.unwrap_or_default();
+    expiry_policy.validate(end_time)?;
+    
+    // Validate leaver terms
+    if let Some(termination_policy) = &termination_policy {
+        termination_policy.validate()?;
+    }
+    
     // Validate description length
     require!(description.len() <= 64, MiraiError::DescriptionTooLong);
     
@@ -40,11 +63,18 @@ pub fn handler(
     let treasury_mint_key = ctx.accounts.treasury_mint.key();
     let vesting_ata_key = ctx.accounts.vesting_ata.key();
     
+    // Pool grants were already counted against the treasury when the pool was funded
+    let from_pool = ctx.accounts.vesting_pool.is_some();
+    
     // Get DAO config and validate treasury is active
     let dao_config = &mut ctx.accounts.dao_config;
     require!(dao_config.is_treasury_active(), MiraiError::TreasuryPaused);
     require!(dao_config.validate_stream_amount(total_amount), MiraiError::StreamAmountExceedsLimit);
-    require!(dao_config.validate_total_allocation(total_amount), MiraiError::TotalAllocationExceedsLimit);
+    require!(from_pool || dao_config.validate_total_allocation(total_amount), MiraiError::TotalAllocationExceedsLimit);
+    require!(
+        start_time > current_time || dao_config.validate_backdate(start_time, current_time),
+        MiraiError::BackdateExceedsLimit
+    );
     
     // Initialize vesting account
     let vesting = &mut ctx.accounts.vesting;
@@ -64,21 +94,63 @@ pub fn handler(
     vesting.description = description;
     vesting.bump = ctx.bumps.vesting;
     vesting.created_at = current_time;
+    vesting.expiry_policy = expiry_policy;
+    vesting.last_claim_at = 0;
+    vesting.terms = terms.clone();
+    vesting.revoked_at = None;
+    vesting.termination_policy = termination_policy;
+    vesting.termination_reason = None;
+    vesting.tranches = Vec::new();
+    vesting.tge_unlock_bps = tge_unlock_bps;
+    vesting.backdated = backdated;
+    vesting.acceleration_terms = None;
+    vesting.accelerated_at = None;
+    vesting.pool = None;
+    vesting.option_terms = option_terms;
+    vesting.milestones = Vec::new();
     
     // Update DAO config statistics
-    dao_config.add_stream(total_amount);
-    
-    // Transfer tokens from authority to vesting account
-    let transfer_ctx = CpiContext::new(
-        ctx.accounts.token_program.to_account_info(),
-        Transfer {
-            from: ctx.accounts.authority_ata.to_account_info(),
-            to: ctx.accounts.vesting_ata.to_account_info(),
-            authority: ctx.accounts.authority.to_account_info(),
-        },
-    );
+    dao_config.add_stream(if from_pool { 0 } else { total_amount });
     
-    anchor_spl::token::transfer(transfer_ctx, total_amount)?;
+    // Grants from a pool are carved out of its unallocated balance
+    if let Some(vesting_pool) = ctx.accounts.vesting_pool.as_mut() {
+        let pool_ata = ctx.accounts.pool_ata.as_ref().ok_or(MiraiError::InvalidVestingPool)?;
+        require_keys_eq!(pool_ata.key(), vesting_pool.pool_ata, MiraiError::InvalidVestingPool);
+        vesting_pool.allocate(total_amount)?;
+        vesting.pool = Some(vesting_pool.key());
+        
+        let pool_seeds = &[
+            b"vesting_pool",
+            dao_config_key.as_ref(),
+            vesting_pool.name.as_bytes(),
+            &[vesting_pool.bump],
+        ];
+        let signer_seeds = &[&pool_seeds[..]];
+        
+        let transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: pool_ata.to_account_info(),
+                to: ctx.accounts.vesting_ata.to_account_info(),
+                authority: vesting_pool.to_account_info(),
+            },
+            signer_seeds,
+        );
+        
+        anchor_spl::token::transfer(transfer_ctx, total_amount)?;
+    } else {
+        // Transfer tokens from authority to vesting account
+        let transfer_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.authority_ata.to_account_info(),
+                to: ctx.accounts.vesting_ata.to_account_info(),
+                authority: ctx.accounts.authority.to_account_info(),
+            },
+        );
+        
+        anchor_spl::token::transfer(transfer_ctx, total_amount)?;
+    }
     
     msg!("Vesting created successfully");
     msg!("Recipient: {}", ctx.accounts.recipient.key());
@@ -86,6 +158,17 @@ pub fn handler(
     msg!("Type: {:?}", vesting_type);
     msg!("Category: {:?}", category);
     msg!("Start: {}, End: {}", start_time, end_time);
+    msg!("TGE Unlock: {} bps", tge_unlock_bps);
+    if backdated {
+        msg!("Backdated: vested to date is claimable now");
+    }
+    msg!("Terms: {:?}", terms);
+    if let Some(option_terms) = &vesting.option_terms {
+        msg!("Option: strike {} of {} per token", option_terms.strike_price, option_terms.strike_mint);
+    }
+    if let Some(pool) = vesting.pool {
+        msg!("Pool: {}", pool);
+    }
     
     Ok(())
 } 
\ No newline at end of file
@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Transfer;
+
+use crate::state::{ExpiryPolicy, PositionKind, VestingStatus};
+use crate::errors::MiraiError;
+use crate::CreateVestingFromTemplate;
+
+pub fn handler(
+    ctx: Context<CreateVestingFromTemplate>,
+    total_amount: u64,
+    start_time: i64,
+) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    
+    // Validate against the template
+    let template = &ctx.accounts.template;
+    template.validate_position(PositionKind::Vesting, total_amount)?;
+    let end_time = template.end_time(start_time);
+    let cliff_time = template.cliff_time(start_time);
+    require!(start_time < end_time, MiraiError::InvalidVestingTiming);
+    require!(start_time > current_time, MiraiError::InvalidVestingTiming);
+    
+    // Get DAO config and validate treasury is active
+    let dao_config_key = ctx.accounts.dao_config.key();
+    let dao_config = &mut ctx.accounts.dao_config;
+    require!(dao_config.is_treasury_active(), MiraiError::TreasuryPaused);
+    require!(dao_config.validate_stream_amount(total_amount), MiraiError::StreamAmountExceedsLimit);
+    require!(dao_config.validate_total_allocation(total_amount), MiraiError::TotalAllocationExceedsLimit);
+    
+    // Initialize vesting account
+    let vesting = &mut ctx.accounts.vesting;
+    vesting.authority = ctx.accounts.authority.key();
+    vesting.recipient = ctx.accounts.recipient.key();
+    vesting.dao_config = dao_config_key;
+    vesting.treasury_mint = ctx.accounts.treasury_mint.key();
+    vesting.vesting_ata = ctx.accounts.vesting_ata.key();
+    vesting.vesting_type = template.vesting_type.clone();
+    vesting.total_amount = total_amount;
+    vesting.claimed_amount = 0;
+    vesting.start_time = start_time;
+    vesting.end_time = end_time;
+    vesting.cliff_time = cliff_time;
+    vesting.status = VestingStatus::Active;
+    vesting.category = template.category.clone();
+    vesting.description = template.description.clone();
+    vesting.bump = ctx.bumps.vesting;
+    vesting.created_at = current_time;
+    vesting.expiry_policy = ExpiryPolicy::default();
+    vesting.last_claim_at = 0;
+    vesting.terms = template.terms.clone();
+    vesting.revoked_at = None;
+    vesting.termination_policy = None;
+    vesting.termination_reason = None;
+    vesting.tranches = Vec::new();
+    vesting.tge_unlock_bps = 0;
+    vesting.backdated = false;
+    vesting.acceleration_terms = None;
+    vesting.accelerated_at = None;
+    vesting.pool = None;
+    vesting.option_terms = None;
+    vesting.milestones = Vec::new();
+    
+    // Update DAO config statistics
+    dao_config.add_stream(total_amount);
+    
+    // Transfer tokens from authority to vesting account
+    let transfer_ctx = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.authority_ata.to_account_info(),
+            to: ctx.accounts.vesting_ata.to_account_info(),
+            authority: ctx.accounts.authority.to_account_info(),
+        },
+    );
+    
+    anchor_spl::token::transfer(transfer_ctx, total_amount)?;
+    
+    msg!("Vesting created from template");
+    msg!("Template: {}", template.name);
+    msg!("Recipient: {}", ctx.accounts.recipient.key());
+    msg!("Amount: {}", total_amount);
+    msg!("Type: {:?}", vesting.vesting_type);
+    msg!("Start: {}, Cliff: {}, End: {}", start_time, cliff_time, end_time);
+    
+    Ok(())
+}
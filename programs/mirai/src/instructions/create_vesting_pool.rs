@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+use crate::errors::MiraiError;
+use crate::state::VestingPool;
+use crate::CreateVestingPool;
+
+pub fn handler(ctx: Context<CreateVestingPool>, name: String) -> Result<()> {
+    require!(
+        !name.is_empty() && name.len() <= VestingPool::MAX_NAME_LEN,
+        MiraiError::InvalidPoolName
+    );
+
+    let vesting_pool = &mut ctx.accounts.vesting_pool;
+    vesting_pool.dao_config = ctx.accounts.dao_config.key();
+    vesting_pool.name = name;
+    vesting_pool.pool_ata = ctx.accounts.pool_ata.key();
+    vesting_pool.total_funded = 0;
+    vesting_pool.allocated_amount = 0;
+    vesting_pool.forfeited_amount = 0;
+    vesting_pool.grant_count = 0;
+    vesting_pool.bump = ctx.bumps.vesting_pool;
+    vesting_pool.created_at = Clock::get()?.unix_timestamp;
+
+    msg!("Vesting pool created successfully");
+    msg!("Name: {}", vesting_pool.name);
+    msg!("Pool ATA: {}", vesting_pool.pool_ata);
+
+    Ok(())
+}
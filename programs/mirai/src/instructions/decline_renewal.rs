@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+use crate::errors::MiraiError;
+use crate::DeclineRenewal;
+
+pub fn handler(ctx: Context<DeclineRenewal>) -> Result<()> {
+    let stream = &mut ctx.accounts.stream;
+    require!(stream.renewal_policy.is_some(), MiraiError::RenewalNotEnabled);
+
+    stream.renewal_policy = None;
+
+    msg!("Recipient declined further renewals");
+    msg!("Stream: {}", stream.key());
+    msg!("Final Period Ends: {}", stream.end_time);
+
+    Ok(())
+}
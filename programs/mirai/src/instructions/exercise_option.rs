@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Transfer;
+use crate::errors::MiraiError;
+use crate::events::OptionExercised;
+use crate::state::PayoutRoute;
+use crate::ExerciseOption;
+
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, ExerciseOption<'info>>, amount: u64) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // Store values before mutable borrow
+    let dao_config_key = ctx.accounts.dao_config.key();
+    let recipient_key = ctx.accounts.recipient.key();
+    let vesting_account_info = ctx.accounts.vesting.to_account_info();
+    let decimals = ctx.accounts.treasury_mint.decimals;
+
+    // Release only vested units, and only inside the exercise window
+    let vesting = &mut ctx.accounts.vesting;
+    vesting.record_exercise(amount, current_time)?;
+    let option_terms = vesting.option_terms.clone().ok_or(MiraiError::NotAnOptionGrant)?;
+    let strike_amount = option_terms.get_strike_amount(amount, decimals)?;
+
+    let dao_config = &mut ctx.accounts.dao_config;
+    dao_config.add_payment(amount);
+
+    // Collect the strike into the DAO treasury
+    let strike_ctx = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.recipient_strike_ata.to_account_info(),
+            to: ctx.accounts.treasury_strike_ata.to_account_info(),
+            authority: ctx.accounts.recipient.to_account_info(),
+        },
+    );
+    anchor_spl::token::transfer(strike_ctx, strike_amount)?;
+
+    let seeds = &[
+        b"vesting",
+        dao_config_key.as_ref(),
+        recipient_key.as_ref(),
+        &[vesting.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    // Exercising is the taxable event, so the units settle like any other payout
+    let destination = match &ctx.accounts.payout_destination {
+        Some(payout_destination) => payout_destination.to_account_info(),
+        None => ctx.accounts.recipient_ata.to_account_info(),
+    };
+    PayoutRoute {
+        escrow: ctx.accounts.vesting_ata.to_account_info(),
+        position: vesting_account_info,
+        token_program: ctx.accounts.token_program.to_account_info(),
+        mint: ctx.accounts.treasury_mint.key(),
+        destination,
+        recipient_ata: ctx.accounts.recipient_ata.key(),
+        allow_any_destination: false,
+        payout_allowlist: &ctx.accounts.payout_allowlist,
+        payout_split: &ctx.accounts.payout_split,
+        split_destinations: ctx.remaining_accounts,
+        withholding_policy: &ctx.accounts.withholding_policy,
+        withholding_vault: ctx.accounts.withholding_vault.as_ref(),
+        withholding_ledger: ctx.accounts.withholding_ledger.as_mut(),
+    }.settle(amount, current_time, signer_seeds)?;
+
+    msg!("Options exercised");
+    msg!("Recipient: {}", recipient_key);
+    msg!("Amount: {}", amount);
+    msg!("Strike paid: {} of {}", strike_amount, option_terms.strike_mint);
+    msg!("Exercise deadline: {:?}", vesting.get_exercise_deadline());
+
+    emit!(OptionExercised {
+        vesting: vesting.key(),
+        recipient: recipient_key,
+        amount,
+        strike_amount,
+        exercised_at: current_time,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+use crate::errors::MiraiError;
+use crate::state::StreamGroup;
+use crate::ManageStreamGroup;
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ManageStreamGroup<'info>>,
+    extension: i64,
+) -> Result<()> {
+    require!(!ctx.remaining_accounts.is_empty(), MiraiError::InvalidGroupAccounts);
+    let group_key = ctx.accounts.stream_group.key();
+    let current_time = Clock::get()?.unix_timestamp;
+
+    for account_info in ctx.remaining_accounts.iter() {
+        let mut stream = StreamGroup::load_member(&group_key, account_info)?;
+        stream.terms.require_cancelable_by_authority()?;
+        // Extending spreads the same funding over longer, so the group's totals do not move
+        stream.extend(extension, current_time)?;
+        stream.exit(&crate::ID)?;
+    }
+
+    msg!("Stream group extended");
+    msg!("Group: {}", ctx.accounts.stream_group.name);
+    msg!("Streams: {}", ctx.remaining_accounts.len());
+    msg!("Extension: {} seconds", extension);
+
+    Ok(())
+}
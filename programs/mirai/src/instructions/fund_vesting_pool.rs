@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Transfer;
+use crate::errors::MiraiError;
+use crate::FundVestingPool;
+
+pub fn handler(ctx: Context<FundVestingPool>, amount: u64) -> Result<()> {
+    require!(amount > 0, MiraiError::InvalidTotalAmount);
+    require!(ctx.accounts.dao_config.is_treasury_active(), MiraiError::TreasuryPaused);
+    require!(ctx.accounts.dao_config.validate_total_allocation(amount), MiraiError::TotalAllocationExceedsLimit);
+
+    let transfer_ctx = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.authority_ata.to_account_info(),
+            to: ctx.accounts.pool_ata.to_account_info(),
+            authority: ctx.accounts.authority.to_account_info(),
+        },
+    );
+    anchor_spl::token::transfer(transfer_ctx, amount)?;
+
+    // Funding a pool commits the tokens, so grants carved out of it are not counted again
+    ctx.accounts.dao_config.reserve_allocation(amount);
+
+    let vesting_pool = &mut ctx.accounts.vesting_pool;
+    vesting_pool.fund(amount);
+
+    msg!("Vesting pool funded");
+    msg!("Pool: {}", vesting_pool.name);
+    msg!("Amount: {}", amount);
+    msg!("Available: {}", vesting_pool.get_available_amount());
+
+    Ok(())
+}
@@ -1,7 +1,6 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Mint, Token};
 use crate::errors::MiraiError;
-use crate::state::{DaoConfig, GovernanceSettings};
+use crate::state::GovernanceSettings;
 use crate::InitDao;
 
 pub fn handler(ctx: Context<InitDao>, treasury_mint: Pubkey) -> Result<()> {
@@ -28,6 +27,9 @@ pub fn handler(ctx: Context<InitDao>, treasury_mint: Pubkey) -> Result<()> {
         max_total_allocation: u64::MAX,
         last_updated: clock.unix_timestamp,
     };
+    dao_config.max_backdate_period = 0;
+    dao_config.acceleration_event_count = 0;
+    dao_config.last_acceleration_at = None;
     
     msg!("DAO initialized successfully with treasury management");
     msg!("Authority: {}", dao_config.authority);
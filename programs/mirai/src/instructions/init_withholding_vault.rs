@@ -0,0 +1,10 @@
+use anchor_lang::prelude::*;
+use crate::InitWithholdingVault;
+
+pub fn handler(ctx: Context<InitWithholdingVault>) -> Result<()> {
+    msg!("Withholding vault created");
+    msg!("Vault: {}", ctx.accounts.withholding_vault.key());
+    msg!("Mint: {}", ctx.accounts.treasury_mint.key());
+
+    Ok(())
+}
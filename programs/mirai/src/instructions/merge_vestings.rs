@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{CloseAccount, Transfer};
+use crate::errors::MiraiError;
+use crate::MergeVestings;
+
+/// Fold the source vesting into the target. Claimed amounts are summed and the source's
+/// schedule is kept as-is, so nothing vests earlier or later than it would have.
+pub fn handler(ctx: Context<MergeVestings>) -> Result<()> {
+    let dao_config_key = ctx.accounts.dao_config.key();
+    let source_recipient_key = ctx.accounts.source_recipient.key();
+    let target_recipient_key = ctx.accounts.target_recipient.key();
+    let source_vesting_info = ctx.accounts.source_vesting.to_account_info();
+
+    require!(source_recipient_key != target_recipient_key, MiraiError::IncompatibleVestings);
+
+    let source = (*ctx.accounts.source_vesting).clone();
+    source.terms.require_transferable()?;
+
+    let target = &mut ctx.accounts.target_vesting;
+    target.merge(&source)?;
+
+    let seeds = &[
+        b"vesting",
+        dao_config_key.as_ref(),
+        source_recipient_key.as_ref(),
+        &[source.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    let escrow_amount = ctx.accounts.source_vesting_ata.amount;
+    let transfer_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.source_vesting_ata.to_account_info(),
+            to: ctx.accounts.target_vesting_ata.to_account_info(),
+            authority: source_vesting_info.clone(),
+        },
+        signer_seeds,
+    );
+    anchor_spl::token::transfer(transfer_ctx, escrow_amount)?;
+
+    let close_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.source_vesting_ata.to_account_info(),
+            destination: ctx.accounts.source_recipient.to_account_info(),
+            authority: source_vesting_info,
+        },
+        signer_seeds,
+    );
+    anchor_spl::token::close_account(close_ctx)?;
+
+    // Allocation is unchanged; the DAO just tracks one fewer position
+    ctx.accounts.dao_config.remove_stream();
+
+    msg!("Vestings merged");
+    msg!("From: {}", source_recipient_key);
+    msg!("Into: {}", target_recipient_key);
+    msg!("Escrow moved: {}", escrow_amount);
+    msg!("Total Amount: {}", target.total_amount);
+    msg!("Claimed Amount: {}", target.claimed_amount);
+    msg!("Tranches: {}", target.tranches.len());
+
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use anchor_lang::Discriminator;
+use crate::errors::MiraiError;
+use crate::state::DaoConfig;
+use crate::MigrateDaoConfig;
+
+pub fn handler(ctx: Context<MigrateDaoConfig>) -> Result<()> {
+    let dao_config_info = ctx.accounts.dao_config.to_account_info();
+    require!(dao_config_info.owner == &crate::ID, MiraiError::InvalidDaoConfig);
+    require!(
+        dao_config_info.try_borrow_data()?.starts_with(DaoConfig::DISCRIMINATOR),
+        MiraiError::InvalidDaoConfig
+    );
+
+    // Fields appended after the DAO was created read as zero, which keeps them disabled
+    let previous_size = dao_config_info.data_len();
+    if previous_size < DaoConfig::SIZE {
+        let rent = Rent::get()?.minimum_balance(DaoConfig::SIZE);
+        let shortfall = rent.saturating_sub(dao_config_info.lamports());
+        if shortfall > 0 {
+            let transfer_ctx = CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.authority.to_account_info(),
+                    to: dao_config_info.clone(),
+                },
+            );
+            system_program::transfer(transfer_ctx, shortfall)?;
+        }
+        dao_config_info.realloc(DaoConfig::SIZE, true)?;
+    }
+
+    msg!("DAO config migrated");
+    msg!("DAO: {}", dao_config_info.key());
+    msg!("Size: {} -> {}", previous_size, DaoConfig::SIZE);
+
+    Ok(())
+}
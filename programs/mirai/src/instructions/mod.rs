@@ -2,4 +2,66 @@ pub mod init_dao;
 pub mod create_stream;
 pub mod redeem_stream;
 pub mod create_vesting;
-pub mod claim_vesting; 
\ No newline at end of file
+pub mod claim_vesting;
+pub mod close_stream;
+pub mod close_vesting;
+pub mod update_metadata;
+pub mod set_stream_operator;
+pub mod set_vesting_operator;
+pub mod revoke_operator;
+pub mod operator_redeem_stream;
+pub mod operator_claim_vesting;
+pub mod schedule_rate_change;
+pub mod accept_stream;
+pub mod withdraw_stream_offer;
+pub mod sweep_stream;
+pub mod sweep_vesting;
+pub mod cancel_stream;
+pub mod transfer_stream;
+pub mod transfer_vesting;
+pub mod resign_stream;
+pub mod set_renewal_policy;
+pub mod decline_renewal;
+pub mod renew_stream;
+pub mod create_template;
+pub mod create_stream_from_template;
+pub mod create_vesting_from_template;
+pub mod create_stream_group;
+pub mod pause_stream_group;
+pub mod resume_stream_group;
+pub mod extend_stream_group;
+pub mod cancel_stream_group;
+pub mod create_payout_allowlist;
+pub mod queue_payout_destination;
+pub mod remove_payout_destination;
+pub mod set_stream_payout_split;
+pub mod set_vesting_payout_split;
+pub mod clear_payout_split;
+pub mod create_team;
+pub mod set_team_shares;
+pub mod withdraw_team_share;
+pub mod init_withholding_vault;
+pub mod set_stream_withholding;
+pub mod set_vesting_withholding;
+pub mod clear_withholding;
+pub mod open_withholding_ledger;
+pub mod remit_withholding;
+pub mod revoke_vesting;
+pub mod terminate_vesting;
+pub mod add_vesting_tranche;
+pub mod set_max_backdate_period;
+pub mod migrate_dao_config;
+pub mod set_acceleration_terms;
+pub mod trigger_acceleration;
+pub mod apply_acceleration;
+pub mod merge_vestings;
+pub mod split_vesting;
+pub mod create_vesting_pool;
+pub mod fund_vesting_pool;
+pub mod report_vesting_pool;
+pub mod exercise_option;
+pub mod create_milestone;
+pub mod attest_milestone;
+pub mod set_vesting_milestones;
+pub mod sync_vesting_milestones;
+pub mod reclaim_missed_milestone; 
\ No newline at end of file
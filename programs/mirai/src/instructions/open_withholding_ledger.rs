@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+use crate::OpenWithholdingLedger;
+
+/// Open the recipient's ledger for `year`. Opening an existing ledger is a no-op, so
+/// clients can include this ahead of every withheld payout.
+pub fn handler(ctx: Context<OpenWithholdingLedger>, year: u16) -> Result<()> {
+    let withholding_ledger = &mut ctx.accounts.withholding_ledger;
+    if withholding_ledger.recipient == Pubkey::default() {
+        withholding_ledger.dao_config = ctx.accounts.dao_config.key();
+        withholding_ledger.recipient = ctx.accounts.recipient.key();
+        withholding_ledger.year = year;
+        withholding_ledger.gross_amount = 0;
+        withholding_ledger.withheld_amount = 0;
+        withholding_ledger.payout_count = 0;
+        withholding_ledger.bump = ctx.bumps.withholding_ledger;
+
+        msg!("Withholding ledger opened");
+        msg!("Recipient: {}", withholding_ledger.recipient);
+        msg!("Year: {}", year);
+    }
+
+    Ok(())
+}
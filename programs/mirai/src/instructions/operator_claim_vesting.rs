@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::get_associated_token_address;
+use crate::errors::MiraiError;
+use crate::state::PayoutRoute;
+use crate::OperatorClaimVesting;
+
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, OperatorClaimVesting<'info>>, amount: u64) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // Store values before mutable borrow
+    let dao_config_key = ctx.accounts.dao_config.key();
+    let recipient_key = ctx.accounts.recipient.key();
+    let vesting_bump = ctx.accounts.vesting.bump;
+    let vesting_account_info = ctx.accounts.vesting.to_account_info();
+
+    // Validate the operator's limits; the destination is checked when the payout is settled
+    let delegation = &ctx.accounts.delegation;
+    delegation.validate_withdrawal(amount, current_time)?;
+    let allow_any_destination = delegation.allow_any_destination;
+
+    let vesting = &mut ctx.accounts.vesting;
+    require!(vesting.option_terms.is_none(), MiraiError::OptionMustBeExercised);
+    vesting.record_claim(amount, current_time)?;
+
+    let dao_config = &mut ctx.accounts.dao_config;
+    dao_config.add_payment(amount);
+
+    let seeds = &[
+        b"vesting",
+        dao_config_key.as_ref(),
+        recipient_key.as_ref(),
+        &[vesting_bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    // The recipient's split takes precedence over the operator's destination
+    PayoutRoute {
+        escrow: ctx.accounts.vesting_ata.to_account_info(),
+        position: vesting_account_info,
+        token_program: ctx.accounts.token_program.to_account_info(),
+        mint: ctx.accounts.treasury_mint.key(),
+        destination: ctx.accounts.destination.to_account_info(),
+        recipient_ata: get_associated_token_address(&recipient_key, &ctx.accounts.treasury_mint.key()),
+        allow_any_destination,
+        payout_allowlist: &ctx.accounts.payout_allowlist,
+        payout_split: &ctx.accounts.payout_split,
+        split_destinations: ctx.remaining_accounts,
+        withholding_policy: &ctx.accounts.withholding_policy,
+        withholding_vault: ctx.accounts.withholding_vault.as_ref(),
+        withholding_ledger: ctx.accounts.withholding_ledger.as_mut(),
+    }.settle(amount, current_time, signer_seeds)?;
+
+    msg!("Vesting claimed by operator");
+    msg!("Operator: {}", ctx.accounts.operator.key());
+    msg!("Destination: {}", ctx.accounts.destination.key());
+    msg!("Amount: {}", amount);
+    msg!("Total claimed: {}", vesting.claimed_amount);
+
+    Ok(())
+}
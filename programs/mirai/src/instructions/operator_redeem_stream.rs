@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::get_associated_token_address;
+use crate::state::PayoutRoute;
+use crate::OperatorRedeemStream;
+
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, OperatorRedeemStream<'info>>, amount: u64) -> Result<()> {
+    let dao_config_key = ctx.accounts.dao_config.key();
+    let recipient_key = ctx.accounts.recipient.key();
+    let stream_bump = ctx.accounts.stream.bump;
+    let stream_to_account_info = ctx.accounts.stream.to_account_info();
+    let clock = Clock::get()?;
+
+    // Validate the operator's limits; the destination is checked when the payout is settled
+    let delegation = &ctx.accounts.delegation;
+    delegation.validate_withdrawal(amount, clock.unix_timestamp)?;
+    let allow_any_destination = delegation.allow_any_destination;
+
+    let stream = &mut ctx.accounts.stream;
+    stream.record_withdrawal(amount, clock.unix_timestamp)?;
+
+    let dao_config = &mut ctx.accounts.dao_config;
+    dao_config.add_payment(amount);
+
+    let seeds = &[
+        b"stream",
+        dao_config_key.as_ref(),
+        recipient_key.as_ref(),
+        &[stream_bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    // The recipient's split takes precedence over the operator's destination
+    PayoutRoute {
+        escrow: ctx.accounts.stream_ata.to_account_info(),
+        position: stream_to_account_info,
+        token_program: ctx.accounts.token_program.to_account_info(),
+        mint: ctx.accounts.treasury_mint.key(),
+        destination: ctx.accounts.destination.to_account_info(),
+        recipient_ata: get_associated_token_address(&recipient_key, &ctx.accounts.treasury_mint.key()),
+        allow_any_destination,
+        payout_allowlist: &ctx.accounts.payout_allowlist,
+        payout_split: &ctx.accounts.payout_split,
+        split_destinations: ctx.remaining_accounts,
+        withholding_policy: &ctx.accounts.withholding_policy,
+        withholding_vault: ctx.accounts.withholding_vault.as_ref(),
+        withholding_ledger: ctx.accounts.withholding_ledger.as_mut(),
+    }.settle(amount, clock.unix_timestamp, signer_seeds)?;
+
+    msg!("Stream redeemed by operator");
+    msg!("Operator: {}", ctx.accounts.operator.key());
+    msg!("Destination: {}", ctx.accounts.destination.key());
+    msg!("Amount withdrawn: {}", amount);
+    msg!("Total withdrawn: {}", stream.withdrawn_amount);
+    msg!("Stream Status: {:?}", stream.status);
+
+    Ok(())
+}
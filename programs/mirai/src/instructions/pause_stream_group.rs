@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+use crate::errors::MiraiError;
+use crate::state::StreamGroup;
+use crate::ManageStreamGroup;
+
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, ManageStreamGroup<'info>>) -> Result<()> {
+    require!(!ctx.remaining_accounts.is_empty(), MiraiError::InvalidGroupAccounts);
+    let group_key = ctx.accounts.stream_group.key();
+    let current_time = Clock::get()?.unix_timestamp;
+
+    for account_info in ctx.remaining_accounts.iter() {
+        let mut stream = StreamGroup::load_member(&group_key, account_info)?;
+        stream.terms.require_cancelable_by_authority()?;
+        stream.pause(current_time);
+        stream.exit(&crate::ID)?;
+    }
+
+    msg!("Stream group paused");
+    msg!("Group: {}", ctx.accounts.stream_group.name);
+    msg!("Streams: {}", ctx.remaining_accounts.len());
+
+    Ok(())
+}
@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+use crate::events::PayoutDestinationQueued;
+use crate::QueuePayoutDestination;
+
+pub fn handler(ctx: Context<QueuePayoutDestination>) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    let destination = ctx.accounts.destination.key();
+
+    let payout_allowlist = &mut ctx.accounts.payout_allowlist;
+    payout_allowlist.queue(destination, current_time)?;
+
+    msg!("Payout destination queued");
+    msg!("Recipient: {}", payout_allowlist.recipient);
+    msg!("Destination: {}", destination);
+    msg!("Effective At: {}", payout_allowlist.pending_effective_at);
+
+    emit!(PayoutDestinationQueued {
+        recipient: payout_allowlist.recipient,
+        destination,
+        effective_at: payout_allowlist.pending_effective_at,
+    });
+
+    Ok(())
+}
@@ -1,13 +1,8 @@
 use anchor_lang::prelude::*;
-use anchor_spl::{
-    token::{Mint, Token, TokenAccount, Transfer},
-    associated_token::AssociatedToken,
-};
-use crate::errors::MiraiError;
-use crate::state::{DaoConfig, Stream, StreamStatus};
+use crate::state::{PayoutRoute, StreamStatus};
 use crate::RedeemStream;
 
-pub fn handler(ctx: Context<RedeemStream>, amount: u64) -> Result<()> {
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, RedeemStream<'info>>, amount: u64) -> Result<()> {
     let dao_config_key = ctx.accounts.dao_config.key();
     let dao_config = &mut ctx.accounts.dao_config;
     let stream_bump = ctx.accounts.stream.bump;
@@ -16,33 +11,12 @@ pub fn handler(ctx: Context<RedeemStream>, amount: u64) -> Result<()> {
     let stream = &mut ctx.accounts.stream;
     let clock = Clock::get()?;
     
-    
-    require!(amount > 0, MiraiError::InvalidWithdrawalAmount);
-    require!(
-        clock.unix_timestamp >= stream.start_time,
-        MiraiError::StreamNotStarted
-    );
-    
-  
-    require!(stream.status == StreamStatus::Active, MiraiError::StreamNotActive);
-    
-    let available_amount = stream.get_withdrawable_amount(clock.unix_timestamp);
-    require!(
-        amount <= available_amount,
-        MiraiError::InsufficientUnlockedTokens
-    );
-    
-    stream.withdrawn_amount = stream.withdrawn_amount.checked_add(amount)
-        .ok_or(MiraiError::InsufficientUnlockedTokens)?;
+    // Validate and record the withdrawal, completing the stream when drained
+    stream.record_withdrawal(amount, clock.unix_timestamp)?;
     
     // Update treasury statistics
     dao_config.add_payment(amount);
     
-    // Check if stream is completed
-    if stream.withdrawn_amount >= stream.total_amount {
-        stream.status = StreamStatus::Completed;
-    }
-    
     let seeds = &[
         b"stream",
         dao_config_key.as_ref(),
@@ -50,16 +24,27 @@ pub fn handler(ctx: Context<RedeemStream>, amount: u64) -> Result<()> {
         &[stream_bump],
     ];
     let signer_seeds = &[&seeds[..]];
-    let transfer_ctx = CpiContext::new_with_signer(
-        ctx.accounts.token_program.to_account_info(),
-        Transfer {
-            from: ctx.accounts.stream_ata.to_account_info(),
-            to: ctx.accounts.recipient_ata.to_account_info(),
-            authority: stream_to_account_info,
-        },
-        signer_seeds,
-    );
-    anchor_spl::token::transfer(transfer_ctx, amount)?;
+
+    // Without a split, payouts go to the recipient's ATA unless they registered an allowlist
+    let destination = match &ctx.accounts.payout_destination {
+        Some(payout_destination) => payout_destination.to_account_info(),
+        None => ctx.accounts.recipient_ata.to_account_info(),
+    };
+    PayoutRoute {
+        escrow: ctx.accounts.stream_ata.to_account_info(),
+        position: stream_to_account_info,
+        token_program: ctx.accounts.token_program.to_account_info(),
+        mint: ctx.accounts.treasury_mint.key(),
+        destination,
+        recipient_ata: ctx.accounts.recipient_ata.key(),
+        allow_any_destination: false,
+        payout_allowlist: &ctx.accounts.payout_allowlist,
+        payout_split: &ctx.accounts.payout_split,
+        split_destinations: ctx.remaining_accounts,
+        withholding_policy: &ctx.accounts.withholding_policy,
+        withholding_vault: ctx.accounts.withholding_vault.as_ref(),
+        withholding_ledger: ctx.accounts.withholding_ledger.as_mut(),
+    }.settle(amount, clock.unix_timestamp, signer_seeds)?;
     
     msg!("Stream redeemed successfully with treasury tracking");
     msg!("Amount withdrawn: {}", amount);
@@ -73,4 +58,4 @@ pub fn handler(ctx: Context<RedeemStream>, amount: u64) -> Result<()> {
     }
     
     Ok(())
-} 
\ No newline at end of file
+}
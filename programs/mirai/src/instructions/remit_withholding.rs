@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Transfer;
+use crate::errors::MiraiError;
+use crate::RemitWithholding;
+
+/// Pay withheld funds out of the vault, e.g. to the account used to settle with a tax authority
+pub fn handler(ctx: Context<RemitWithholding>, amount: u64) -> Result<()> {
+    require!(amount > 0, MiraiError::InvalidWithdrawalAmount);
+    require!(amount <= ctx.accounts.withholding_vault.amount, MiraiError::InsufficientTreasuryBalance);
+
+    let authority_key = ctx.accounts.authority.key();
+    let seeds = &[
+        b"dao_config",
+        authority_key.as_ref(),
+        &[ctx.accounts.dao_config.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+    let transfer_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.withholding_vault.to_account_info(),
+            to: ctx.accounts.destination.to_account_info(),
+            authority: ctx.accounts.dao_config.to_account_info(),
+        },
+        signer_seeds,
+    );
+    anchor_spl::token::transfer(transfer_ctx, amount)?;
+
+    msg!("Withholding remitted");
+    msg!("Destination: {}", ctx.accounts.destination.key());
+    msg!("Amount: {}", amount);
+
+    Ok(())
+}
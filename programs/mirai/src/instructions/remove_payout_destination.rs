@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+use crate::RemovePayoutDestination;
+
+/// Removing a destination only narrows where payouts can go, so it applies immediately
+pub fn handler(ctx: Context<RemovePayoutDestination>, destination: Pubkey) -> Result<()> {
+    let payout_allowlist = &mut ctx.accounts.payout_allowlist;
+    payout_allowlist.remove(&destination)?;
+
+    msg!("Payout destination removed");
+    msg!("Recipient: {}", payout_allowlist.recipient);
+    msg!("Destination: {}", destination);
+    msg!("Remaining Destinations: {}", payout_allowlist.destinations.len());
+
+    Ok(())
+}
@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Transfer;
+use crate::errors::MiraiError;
+use crate::events::{StreamRenewalSkipped, StreamRenewed};
+use crate::state::{StreamGroup, StreamStatus};
+use crate::RenewStream;
+
+pub fn handler(ctx: Context<RenewStream>) -> Result<()> {
+    let dao_authority = ctx.accounts.dao_config.authority;
+    let dao_config_bump = ctx.accounts.dao_config.bump;
+    let dao_config_account_info = ctx.accounts.dao_config.to_account_info();
+    let clock = Clock::get()?;
+
+    let stream = &mut ctx.accounts.stream;
+    stream.apply_due_rate_change(clock.unix_timestamp);
+
+    let policy = stream.renewal_policy.clone().ok_or(MiraiError::RenewalNotEnabled)?;
+    require!(policy.can_renew(), MiraiError::RenewalNotEnabled);
+    require!(
+        stream.status == StreamStatus::Active || stream.status == StreamStatus::Completed,
+        MiraiError::StreamModificationNotAllowed
+    );
+    require!(clock.unix_timestamp >= stream.end_time, MiraiError::RenewalNotDue);
+
+    let period_amount = policy.period_amount.unwrap_or_else(|| stream.get_period_amount());
+    require!(period_amount > 0, MiraiError::InvalidTotalAmount);
+
+    // An unfunded or over-cap renewal leaves the stream to run out normally. The per-stream
+    // cap covers everything the stream has been funded with, not just the new period.
+    let dao_config = &mut ctx.accounts.dao_config;
+    let vault_balance = ctx.accounts.dao_vault.amount;
+    let within_caps = dao_config.is_treasury_active() &&
+        dao_config.validate_stream_amount(stream.total_amount.saturating_add(period_amount)) &&
+        dao_config.validate_total_allocation(period_amount);
+    if !within_caps || vault_balance < period_amount {
+        msg!("Stream renewal skipped");
+        msg!("Period Amount: {}", period_amount);
+        msg!("Vault Balance: {}", vault_balance);
+        msg!("Within Governance Caps: {}", within_caps);
+
+        emit!(StreamRenewalSkipped {
+            stream: stream.key(),
+            period_amount,
+            vault_balance,
+        });
+        return Ok(());
+    }
+
+    let old_total_amount = stream.total_amount;
+    stream.renew(period_amount);
+    dao_config.total_allocated = dao_config.total_allocated.saturating_add(period_amount);
+    StreamGroup::sync_member_funding(
+        ctx.accounts.stream_group.as_mut(),
+        stream.group,
+        old_total_amount,
+        stream.total_amount,
+    )?;
+
+    let seeds = &[
+        b"dao_config",
+        dao_authority.as_ref(),
+        &[dao_config_bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+    let transfer_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.dao_vault.to_account_info(),
+            to: ctx.accounts.stream_ata.to_account_info(),
+            authority: dao_config_account_info,
+        },
+        signer_seeds,
+    );
+    anchor_spl::token::transfer(transfer_ctx, period_amount)?;
+
+    let remaining_renewals = stream.renewal_policy.as_ref().and_then(|policy| policy.remaining_renewals);
+
+    msg!("Stream renewed");
+    msg!("Stream: {}", stream.key());
+    msg!("Period Amount: {}", period_amount);
+    msg!("Period: {} - {}", stream.period_start_time, stream.end_time);
+    msg!("Remaining Renewals: {:?}", remaining_renewals);
+
+    emit!(StreamRenewed {
+        stream: stream.key(),
+        period_amount,
+        period_start_time: stream.period_start_time,
+        period_end_time: stream.end_time,
+        remaining_renewals,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use crate::errors::MiraiError;
+use crate::events::VestingPoolReported;
+use crate::state::Vesting;
+use crate::ReportVestingPool;
+
+/// Emit pool totals. Allocated and forfeited amounts are tracked on the pool; vested and
+/// claimed amounts are summed over the grants passed in `remaining_accounts`.
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, ReportVestingPool<'info>>) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    let vesting_pool = &ctx.accounts.vesting_pool;
+    let pool_key = vesting_pool.key();
+
+    let mut vested_amount: u64 = 0;
+    let mut claimed_amount: u64 = 0;
+    let mut grant_keys: Vec<Pubkey> = Vec::with_capacity(ctx.remaining_accounts.len());
+
+    for account_info in ctx.remaining_accounts.iter() {
+        require!(!grant_keys.contains(account_info.key), MiraiError::InvalidVestingPool);
+        grant_keys.push(*account_info.key);
+
+        let vesting = Account::<Vesting>::try_from(account_info)?;
+        require!(vesting.pool == Some(pool_key), MiraiError::InvalidVestingPool);
+
+        vested_amount = vested_amount.saturating_add(vesting.get_vested_amount(current_time));
+        claimed_amount = claimed_amount.saturating_add(vesting.claimed_amount);
+    }
+
+    msg!("Vesting pool report");
+    msg!("Pool: {}", vesting_pool.name);
+    msg!("Funded: {}", vesting_pool.total_funded);
+    msg!("Available: {}", vesting_pool.get_available_amount());
+    msg!("Allocated: {}", vesting_pool.allocated_amount);
+    msg!("Vested: {}", vested_amount);
+    msg!("Claimed: {}", claimed_amount);
+    msg!("Forfeited: {}", vesting_pool.forfeited_amount);
+    msg!("Grants reported: {} of {}", grant_keys.len(), vesting_pool.grant_count);
+
+    emit!(VestingPoolReported {
+        vesting_pool: pool_key,
+        total_funded: vesting_pool.total_funded,
+        available_amount: vesting_pool.get_available_amount(),
+        allocated_amount: vesting_pool.allocated_amount,
+        vested_amount,
+        claimed_amount,
+        forfeited_amount: vesting_pool.forfeited_amount,
+        grants_reported: grant_keys.len() as u32,
+        reported_at: current_time,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Transfer;
+use crate::events::StreamCancelled;
+use crate::state::{CancellationReason, PayoutRoute};
+use crate::ResignStream;
+
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, ResignStream<'info>>) -> Result<()> {
+    let dao_config_key = ctx.accounts.dao_config.key();
+    let stream_to_account_info = ctx.accounts.stream.to_account_info();
+    let clock = Clock::get()?;
+
+    let stream = &mut ctx.accounts.stream;
+    stream.terms.require_cancelable_by_recipient()?;
+
+    // Settle what has accrued so far; the rest goes back to the DAO
+    let owed_amount = stream.settle_and_cancel(clock.unix_timestamp, CancellationReason::Resigned)?;
+    let refund_amount = ctx.accounts.stream_ata.amount.saturating_sub(owed_amount);
+
+    let dao_config = &mut ctx.accounts.dao_config;
+    dao_config.add_payment(owed_amount);
+    dao_config.release_allocation(stream.get_remaining_amount());
+
+    let recipient_key = stream.recipient;
+    let seeds = &[
+        b"stream",
+        dao_config_key.as_ref(),
+        recipient_key.as_ref(),
+        &[stream.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    // The settlement follows the recipient's split, or goes wherever their allowlist permits
+    let destination = match &ctx.accounts.payout_destination {
+        Some(payout_destination) => payout_destination.to_account_info(),
+        None => ctx.accounts.recipient_ata.to_account_info(),
+    };
+    PayoutRoute {
+        escrow: ctx.accounts.stream_ata.to_account_info(),
+        position: stream_to_account_info.clone(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+        mint: ctx.accounts.treasury_mint.key(),
+        destination,
+        recipient_ata: ctx.accounts.recipient_ata.key(),
+        allow_any_destination: false,
+        payout_allowlist: &ctx.accounts.payout_allowlist,
+        payout_split: &ctx.accounts.payout_split,
+        split_destinations: ctx.remaining_accounts,
+        withholding_policy: &ctx.accounts.withholding_policy,
+        withholding_vault: ctx.accounts.withholding_vault.as_ref(),
+        withholding_ledger: ctx.accounts.withholding_ledger.as_mut(),
+    }.settle(owed_amount, clock.unix_timestamp, signer_seeds)?;
+
+    if refund_amount > 0 {
+        let transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.stream_ata.to_account_info(),
+                to: ctx.accounts.authority_ata.to_account_info(),
+                authority: stream_to_account_info,
+            },
+            signer_seeds,
+        );
+        anchor_spl::token::transfer(transfer_ctx, refund_amount)?;
+    }
+
+    msg!("Recipient resigned from stream");
+    msg!("Recipient: {}", recipient_key);
+    msg!("Settled to recipient: {}", owed_amount);
+    msg!("Returned to treasury: {}", refund_amount);
+    msg!("Treasury Total Allocated: {}", dao_config.total_allocated);
+
+    emit!(StreamCancelled {
+        stream: stream.key(),
+        recipient: recipient_key,
+        reason: CancellationReason::Resigned,
+        settled_amount: owed_amount,
+        refunded_amount: refund_amount,
+        cancelled_at: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
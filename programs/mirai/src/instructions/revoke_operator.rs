@@ -0,0 +1,10 @@
+use anchor_lang::prelude::*;
+use crate::RevokeOperator;
+
+pub fn handler(ctx: Context<RevokeOperator>) -> Result<()> {
+    msg!("Operator revoked");
+    msg!("Position: {}", ctx.accounts.delegation.position);
+    msg!("Operator: {}", ctx.accounts.delegation.operator);
+
+    Ok(())
+}
@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Transfer;
+use crate::events::VestingRevoked;
+use crate::state::VestingPool;
+use crate::RevokeVesting;
+
+pub fn handler(ctx: Context<RevokeVesting>) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // Store values before mutable borrow
+    let dao_config_key = ctx.accounts.dao_config.key();
+    let vesting_account_info = ctx.accounts.vesting.to_account_info();
+
+    let vesting = &mut ctx.accounts.vesting;
+    vesting.terms.require_cancelable_by_authority()?;
+
+    // Freeze vesting now; only the unvested remainder goes back to the treasury
+    let returned_amount = vesting.revoke(current_time)?;
+
+    // Grants carved from a pool recycle their forfeited tokens back into it
+    let dao_config = &mut ctx.accounts.dao_config;
+    let destination = VestingPool::get_forfeiture_destination(
+        vesting,
+        dao_config,
+        ctx.accounts.vesting_pool.as_mut(),
+        ctx.accounts.pool_ata.as_ref(),
+        &ctx.accounts.authority_ata,
+        returned_amount,
+    )?;
+
+    let recipient_key = vesting.recipient;
+    let seeds = &[
+        b"vesting",
+        dao_config_key.as_ref(),
+        recipient_key.as_ref(),
+        &[vesting.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    if returned_amount > 0 {
+        let transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.vesting_ata.to_account_info(),
+                to: destination,
+                authority: vesting_account_info,
+            },
+            signer_seeds,
+        );
+
+        anchor_spl::token::transfer(transfer_ctx, returned_amount)?;
+    }
+
+    msg!("Vesting revoked");
+    msg!("Recipient: {}", recipient_key);
+    msg!("Vested (still claimable): {}", vesting.total_amount - vesting.claimed_amount);
+    msg!("Returned to treasury: {}", returned_amount);
+    msg!("Status: {:?}", vesting.status);
+    msg!("Treasury Total Allocated: {}", dao_config.total_allocated);
+
+    emit!(VestingRevoked {
+        vesting: vesting.key(),
+        recipient: recipient_key,
+        vested_amount: vesting.total_amount,
+        returned_amount,
+        revoked_at: current_time,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,117 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Transfer;
+use crate::errors::MiraiError;
+use crate::events::RateChangeScheduled;
+use crate::state::{RateChange, StreamGroup, StreamStatus};
+use crate::ScheduleRateChange;
+
+/// Schedule `new_segment_amount` to stream between `effective_time` and the stream's end,
+/// on top of whatever has accrued by `effective_time` under the current rate.
+pub fn handler(
+    ctx: Context<ScheduleRateChange>,
+    effective_time: i64,
+    new_segment_amount: u64,
+) -> Result<()> {
+    let dao_config_key = ctx.accounts.dao_config.key();
+    let stream_to_account_info = ctx.accounts.stream.to_account_info();
+    let clock = Clock::get()?;
+
+    let stream = &mut ctx.accounts.stream;
+    require!(
+        stream.status == StreamStatus::Active || stream.status == StreamStatus::Paused,
+        MiraiError::StreamModificationNotAllowed
+    );
+
+    // Fold in any change that has already taken effect before scheduling the next one
+    stream.apply_due_rate_change(clock.unix_timestamp);
+    require!(stream.pending_rate_change.is_none(), MiraiError::RateChangeAlreadyPending);
+    require!(
+        effective_time > clock.unix_timestamp &&
+        effective_time >= stream.start_time &&
+        effective_time < stream.end_time,
+        MiraiError::InvalidRateChangeTiming
+    );
+
+    let accrued_amount = stream.get_unlocked_amount(effective_time);
+    let new_total_amount = accrued_amount.checked_add(new_segment_amount)
+        .ok_or(MiraiError::InvalidTotalAmount)?;
+    require!(new_total_amount > 0, MiraiError::InvalidTotalAmount);
+
+    let old_total_amount = stream.total_amount;
+    stream.pending_rate_change = Some(RateChange {
+        effective_time,
+        new_total_amount,
+    });
+
+    let dao_config = &mut ctx.accounts.dao_config;
+    require!(dao_config.is_treasury_active(), MiraiError::TreasuryPaused);
+
+    if new_total_amount > old_total_amount {
+        // Deposit the additional funding for the higher rate
+        let increase = new_total_amount - old_total_amount;
+        require!(dao_config.validate_stream_amount(new_total_amount), MiraiError::StreamAmountExceedsLimit);
+        require!(dao_config.validate_total_allocation(increase), MiraiError::TotalAllocationExceedsLimit);
+        dao_config.total_allocated = dao_config.total_allocated.saturating_add(increase);
+
+        let transfer_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.authority_ata.to_account_info(),
+                to: ctx.accounts.stream_ata.to_account_info(),
+                authority: ctx.accounts.authority.to_account_info(),
+            },
+        );
+        anchor_spl::token::transfer(transfer_ctx, increase)?;
+    } else if new_total_amount < old_total_amount {
+        // Cutting the rate takes back tokens the recipient was promised, like a cancellation
+        stream.terms.require_cancelable_by_authority()?;
+
+        // Refund the funding no longer needed at the lower rate
+        let decrease = old_total_amount - new_total_amount;
+        dao_config.release_allocation(decrease);
+
+        let recipient_key = stream.recipient;
+        let seeds = &[
+            b"stream",
+            dao_config_key.as_ref(),
+            recipient_key.as_ref(),
+            &[stream.bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+        let transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.stream_ata.to_account_info(),
+                to: ctx.accounts.authority_ata.to_account_info(),
+                authority: stream_to_account_info,
+            },
+            signer_seeds,
+        );
+        anchor_spl::token::transfer(transfer_ctx, decrease)?;
+    }
+
+    // The change is funded now, so the group counts it now rather than when it takes effect
+    StreamGroup::sync_member_funding(
+        ctx.accounts.stream_group.as_mut(),
+        stream.group,
+        old_total_amount,
+        new_total_amount,
+    )?;
+
+    msg!("Rate change scheduled");
+    msg!("Stream: {}", stream.key());
+    msg!("Effective Time: {}", effective_time);
+    msg!("Accrued At Effective Time: {}", accrued_amount);
+    msg!("Total Amount: {} -> {}", old_total_amount, new_total_amount);
+
+    emit!(RateChangeScheduled {
+        stream: stream.key(),
+        recipient: stream.recipient,
+        effective_time,
+        accrued_at_effective_time: accrued_amount,
+        old_total_amount,
+        new_total_amount,
+    });
+
+    Ok(())
+}
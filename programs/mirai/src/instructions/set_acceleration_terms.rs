@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+use crate::state::AccelerationTerms;
+use crate::SetAccelerationTerms;
+
+pub fn handler(ctx: Context<SetAccelerationTerms>, terms: Option<AccelerationTerms>) -> Result<()> {
+    let last_acceleration_at = ctx.accounts.dao_config.last_acceleration_at;
+    let vesting = &mut ctx.accounts.vesting;
+    vesting.set_acceleration_terms(terms, last_acceleration_at)?;
+
+    msg!("Acceleration terms updated");
+    msg!("Vesting: {}", vesting.key());
+    msg!("Acceleration Terms: {:?}", vesting.acceleration_terms);
+
+    Ok(())
+}
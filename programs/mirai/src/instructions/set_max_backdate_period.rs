@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+use crate::errors::MiraiError;
+use crate::SetMaxBackdatePeriod;
+
+pub fn handler(ctx: Context<SetMaxBackdatePeriod>, max_backdate_period: i64) -> Result<()> {
+    require!(max_backdate_period >= 0, MiraiError::InvalidGovernanceSettings);
+
+    let dao_config = &mut ctx.accounts.dao_config;
+    dao_config.max_backdate_period = max_backdate_period;
+    dao_config.governance_settings.last_updated = Clock::get()?.unix_timestamp;
+
+    msg!("Backdating look-back updated");
+    msg!("DAO: {}", dao_config.key());
+    msg!("Max Backdate Period: {}", max_backdate_period);
+
+    Ok(())
+}
@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+use crate::errors::MiraiError;
+use crate::state::RenewalPolicy;
+use crate::SetRenewalPolicy;
+
+pub fn handler(ctx: Context<SetRenewalPolicy>, policy: Option<RenewalPolicy>) -> Result<()> {
+    let stream = &mut ctx.accounts.stream;
+    require!(!stream.is_cancelled(), MiraiError::StreamCancelled);
+
+    if let Some(policy) = policy.as_ref() {
+        require!(policy.can_renew(), MiraiError::InvalidRenewalPolicy);
+        if let Some(period_amount) = policy.period_amount {
+            require!(period_amount > 0, MiraiError::InvalidTotalAmount);
+        }
+    }
+
+    stream.renewal_policy = policy;
+
+    msg!("Renewal policy updated");
+    msg!("Stream: {}", stream.key());
+    msg!("Renewal Policy: {:?}", stream.renewal_policy);
+
+    Ok(())
+}
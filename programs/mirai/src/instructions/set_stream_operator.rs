@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use crate::errors::MiraiError;
+use crate::SetStreamOperator;
+
+pub fn handler(
+    ctx: Context<SetStreamOperator>,
+    operator: Pubkey,
+    max_amount_per_claim: Option<u64>,
+    expires_at: Option<i64>,
+    allow_any_destination: bool,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    if let Some(expires_at) = expires_at {
+        require!(expires_at > clock.unix_timestamp, MiraiError::InvalidOperatorExpiry);
+    }
+    if let Some(max_amount) = max_amount_per_claim {
+        require!(max_amount > 0, MiraiError::InvalidWithdrawalAmount);
+    }
+
+    let delegation = &mut ctx.accounts.delegation;
+    delegation.position = ctx.accounts.stream.key();
+    delegation.recipient = ctx.accounts.recipient.key();
+    delegation.operator = operator;
+    delegation.max_amount_per_claim = max_amount_per_claim;
+    delegation.expires_at = expires_at;
+    delegation.allow_any_destination = allow_any_destination;
+    delegation.bump = ctx.bumps.delegation;
+    delegation.created_at = clock.unix_timestamp;
+
+    msg!("Stream operator registered");
+    msg!("Stream: {}", delegation.position);
+    msg!("Operator: {}", delegation.operator);
+    msg!("Max Amount Per Claim: {:?}", delegation.max_amount_per_claim);
+    msg!("Expires At: {:?}", delegation.expires_at);
+    msg!("Allow Any Destination: {}", delegation.allow_any_destination);
+
+    Ok(())
+}
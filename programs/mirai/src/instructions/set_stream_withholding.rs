@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+use crate::state::WithholdingPolicy;
+use crate::SetStreamWithholding;
+
+pub fn handler(ctx: Context<SetStreamWithholding>, rate_bps: u16) -> Result<()> {
+    WithholdingPolicy::validate_rate(rate_bps)?;
+
+    let withholding_policy = &mut ctx.accounts.withholding_policy;
+    withholding_policy.position = ctx.accounts.stream.key();
+    withholding_policy.dao_config = ctx.accounts.dao_config.key();
+    withholding_policy.recipient = ctx.accounts.stream.recipient;
+    withholding_policy.rate_bps = rate_bps;
+    withholding_policy.bump = ctx.bumps.withholding_policy;
+    withholding_policy.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!("Stream withholding rate set");
+    msg!("Stream: {}", withholding_policy.position);
+    msg!("Rate: {} bps", withholding_policy.rate_bps);
+
+    Ok(())
+}
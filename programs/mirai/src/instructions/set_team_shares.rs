@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+use crate::state::{Stream, TeamShare};
+use crate::SetTeamShares;
+
+/// Rebalance team shares. Everything accrued up to now is distributed under the old
+/// shares first, so the change only affects future accruals.
+pub fn handler(ctx: Context<SetTeamShares>, shares: Vec<TeamShare>) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    let stream_info = &ctx.accounts.stream;
+    let team = &mut ctx.accounts.team;
+
+    if stream_info.owner == &crate::ID && !stream_info.data_is_empty() {
+        let stream = Stream::try_deserialize(&mut &stream_info.try_borrow_data()?[..])?;
+        team.checkpoint(&stream, current_time);
+    }
+    team.set_shares(&shares)?;
+
+    msg!("Team shares updated");
+    msg!("Team: {}", team.name);
+    msg!("Members: {}", team.members.len());
+    msg!("Accounted Amount: {}", team.accounted_amount);
+
+    Ok(())
+}
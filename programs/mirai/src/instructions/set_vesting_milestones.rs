@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+use crate::errors::MiraiError;
+use crate::state::{Milestone, MilestoneShare};
+use crate::SetVestingMilestones;
+
+/// Gate the vesting on milestones. The milestone accounts are passed in `remaining_accounts`
+/// in the same order as `shares`.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, SetVestingMilestones<'info>>,
+    shares: Vec<MilestoneShare>,
+) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    let dao_config_key = ctx.accounts.dao_config.key();
+    require!(ctx.remaining_accounts.len() == shares.len(), MiraiError::InvalidMilestone);
+
+    let mut milestones: Vec<Milestone> = Vec::with_capacity(shares.len());
+    for (share, account_info) in shares.iter().zip(ctx.remaining_accounts.iter()) {
+        require_keys_eq!(*account_info.key, share.milestone, MiraiError::InvalidMilestone);
+        let milestone = Account::<Milestone>::try_from(account_info)?;
+        require_keys_eq!(milestone.dao_config, dao_config_key, MiraiError::InvalidMilestone);
+        milestones.push(milestone.into_inner());
+    }
+
+    let vesting = &mut ctx.accounts.vesting;
+    vesting.set_milestones(&shares, &milestones, current_time)?;
+
+    msg!("Vesting milestones updated");
+    msg!("Vesting: {}", vesting.key());
+    msg!("Milestones: {:?}", vesting.milestones);
+
+    Ok(())
+}
@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+use crate::state::{PayoutSplit, SplitShare};
+use crate::SetVestingPayoutSplit;
+
+pub fn handler(
+    ctx: Context<SetVestingPayoutSplit>,
+    shares: Vec<SplitShare>,
+    primary: Pubkey,
+) -> Result<()> {
+    PayoutSplit::validate_shares(&shares, &primary)?;
+
+    let payout_split = &mut ctx.accounts.payout_split;
+    payout_split.position = ctx.accounts.vesting.key();
+    payout_split.recipient = ctx.accounts.recipient.key();
+    payout_split.shares = shares;
+    payout_split.primary = primary;
+    payout_split.bump = ctx.bumps.payout_split;
+    payout_split.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!("Vesting payout split configured");
+    msg!("Vesting: {}", payout_split.position);
+    msg!("Destinations: {}", payout_split.shares.len());
+    msg!("Primary: {}", payout_split.primary);
+
+    Ok(())
+}
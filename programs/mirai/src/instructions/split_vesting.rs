@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Transfer;
+use crate::errors::MiraiError;
+use crate::SplitVesting;
+
+/// Carve a proportional share of the vesting out to a new recipient. Positions are addressed
+/// by recipient, so the carved share lives under the new recipient's PDA.
+pub fn handler(ctx: Context<SplitVesting>, split_bps: u16) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    let dao_config_key = ctx.accounts.dao_config.key();
+    let recipient_key = ctx.accounts.recipient.key();
+    let new_recipient_key = ctx.accounts.new_recipient.key();
+    let vesting_account_info = ctx.accounts.vesting.to_account_info();
+
+    require!(new_recipient_key != recipient_key, MiraiError::InvalidTransferRecipient);
+
+    let vesting = &mut ctx.accounts.vesting;
+    vesting.terms.require_transferable()?;
+
+    let mut carved = vesting.split(split_bps)?;
+    carved.recipient = new_recipient_key;
+    carved.vesting_ata = ctx.accounts.new_vesting_ata.key();
+    carved.bump = ctx.bumps.new_vesting;
+    carved.created_at = current_time;
+
+    // The carved position holds its own unclaimed balance
+    let escrow_amount = carved.total_amount - carved.claimed_amount;
+    let carved_total = carved.total_amount;
+    ctx.accounts.new_vesting.set_inner(carved);
+
+    let seeds = &[
+        b"vesting",
+        dao_config_key.as_ref(),
+        recipient_key.as_ref(),
+        &[vesting.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    let transfer_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.vesting_ata.to_account_info(),
+            to: ctx.accounts.new_vesting_ata.to_account_info(),
+            authority: vesting_account_info,
+        },
+        signer_seeds,
+    );
+    anchor_spl::token::transfer(transfer_ctx, escrow_amount)?;
+
+    // Allocation is unchanged; the DAO just tracks one more position
+    ctx.accounts.dao_config.add_stream(0);
+
+    msg!("Vesting split");
+    msg!("From: {}", recipient_key);
+    msg!("To: {}", new_recipient_key);
+    msg!("Split: {} bps", split_bps);
+    msg!("Carved Amount: {}", carved_total);
+    msg!("Remaining Amount: {}", vesting.total_amount);
+    msg!("Escrow moved: {}", escrow_amount);
+
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Transfer;
+use crate::errors::MiraiError;
+use crate::events::StreamCancelled;
+use crate::state::CancellationReason;
+use crate::SweepStream;
+
+pub fn handler(ctx: Context<SweepStream>) -> Result<()> {
+    let dao_config_key = ctx.accounts.dao_config.key();
+    let stream_to_account_info = ctx.accounts.stream.to_account_info();
+    let clock = Clock::get()?;
+
+    let stream = &mut ctx.accounts.stream;
+    require!(stream.is_sweepable(clock.unix_timestamp), MiraiError::SweepNotAllowed);
+
+    // A claim deadline can fall before an extended end; sweeping then claws back unstreamed funds
+    if clock.unix_timestamp < stream.end_time {
+        stream.terms.require_cancelable_by_authority()?;
+    }
+
+    let unclaimed_amount = stream.get_remaining_amount();
+    let sweep_amount = ctx.accounts.stream_ata.amount;
+    stream.cancel(CancellationReason::Swept);
+
+    // Unclaimed tokens are no longer allocated to the stream
+    let dao_config = &mut ctx.accounts.dao_config;
+    dao_config.release_allocation(unclaimed_amount);
+
+    let recipient_key = stream.recipient;
+    let seeds = &[
+        b"stream",
+        dao_config_key.as_ref(),
+        recipient_key.as_ref(),
+        &[stream.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+    let transfer_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.stream_ata.to_account_info(),
+            to: ctx.accounts.authority_ata.to_account_info(),
+            authority: stream_to_account_info,
+        },
+        signer_seeds,
+    );
+    anchor_spl::token::transfer(transfer_ctx, sweep_amount)?;
+
+    msg!("Unclaimed stream funds swept to treasury");
+    msg!("Recipient: {}", recipient_key);
+    msg!("Swept: {}", sweep_amount);
+    msg!("Treasury Total Allocated: {}", dao_config.total_allocated);
+
+    emit!(StreamCancelled {
+        stream: stream.key(),
+        recipient: recipient_key,
+        reason: CancellationReason::Swept,
+        settled_amount: 0,
+        refunded_amount: sweep_amount,
+        cancelled_at: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
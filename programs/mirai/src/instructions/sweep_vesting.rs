@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Transfer;
+use crate::errors::MiraiError;
+use crate::state::{VestingPool, VestingStatus};
+use crate::SweepVesting;
+
+pub fn handler(ctx: Context<SweepVesting>) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // Store values before mutable borrow
+    let dao_config_key = ctx.accounts.dao_config.key();
+    let vesting_account_info = ctx.accounts.vesting.to_account_info();
+
+    let vesting = &mut ctx.accounts.vesting;
+    require!(vesting.is_sweepable(current_time), MiraiError::SweepNotAllowed);
+
+    // A claim deadline can fall before the last tranche ends; sweeping then claws back unvested tokens
+    if current_time < vesting.get_latest_end_time() {
+        vesting.terms.require_cancelable_by_authority()?;
+    }
+
+    let unclaimed_amount = vesting.total_amount.saturating_sub(vesting.claimed_amount);
+    let sweep_amount = ctx.accounts.vesting_ata.amount;
+    vesting.status = VestingStatus::Cancelled;
+
+    // Unclaimed tokens are no longer allocated to the vesting; pool grants sweep back into their pool
+    let dao_config = &mut ctx.accounts.dao_config;
+    let destination = VestingPool::get_forfeiture_destination(
+        vesting,
+        dao_config,
+        ctx.accounts.vesting_pool.as_mut(),
+        ctx.accounts.pool_ata.as_ref(),
+        &ctx.accounts.authority_ata,
+        unclaimed_amount,
+    )?;
+
+    let recipient_key = vesting.recipient;
+    let seeds = &[
+        b"vesting",
+        dao_config_key.as_ref(),
+        recipient_key.as_ref(),
+        &[vesting.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    let transfer_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.vesting_ata.to_account_info(),
+            to: destination,
+            authority: vesting_account_info,
+        },
+        signer_seeds,
+    );
+
+    anchor_spl::token::transfer(transfer_ctx, sweep_amount)?;
+
+    msg!("Unclaimed vesting tokens swept to treasury");
+    msg!("Recipient: {}", recipient_key);
+    msg!("Swept: {}", sweep_amount);
+    msg!("Treasury Total Allocated: {}", dao_config.total_allocated);
+
+    Ok(())
+}
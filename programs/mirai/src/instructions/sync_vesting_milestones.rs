@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+use crate::errors::MiraiError;
+use crate::state::Milestone;
+use crate::SyncVestingMilestones;
+
+/// Copy achieved milestones onto the vesting so the unlocked share becomes claimable.
+/// Anyone may crank this with the milestone accounts in `remaining_accounts`.
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, SyncVestingMilestones<'info>>) -> Result<()> {
+    let vesting = &mut ctx.accounts.vesting;
+
+    for account_info in ctx.remaining_accounts.iter() {
+        let milestone = Account::<Milestone>::try_from(account_info)?;
+        require_keys_eq!(milestone.dao_config, vesting.dao_config, MiraiError::InvalidMilestone);
+
+        if milestone.achieved_at.is_some() {
+            require!(vesting.record_milestone_achieved(account_info.key), MiraiError::InvalidMilestone);
+        }
+    }
+
+    let achieved_count = vesting.milestones.iter().filter(|gate| gate.achieved).count();
+    msg!("Vesting milestones synced");
+    msg!("Vesting: {}", vesting.key());
+    msg!("Achieved: {} of {}", achieved_count, vesting.milestones.len());
+
+    Ok(())
+}
@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Transfer;
+use crate::events::{VestingAccelerated, VestingTerminated};
+use crate::state::{TerminationReason, VestingPool};
+use crate::TerminateVesting;
+
+pub fn handler(ctx: Context<TerminateVesting>, reason: TerminationReason) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // Store values before mutable borrow
+    let dao_config_key = ctx.accounts.dao_config.key();
+    let vesting_account_info = ctx.accounts.vesting.to_account_info();
+
+    let vesting = &mut ctx.accounts.vesting;
+    vesting.terms.require_cancelable_by_authority()?;
+
+    // After a change of control, accelerate before the leaver terms split vested from unvested
+    let triggered_at = ctx.accounts.acceleration_event.as_ref().map(|event| event.triggered_at);
+    let mut accelerated_amount = 0;
+    if matches!(triggered_at, Some(triggered_at) if vesting.can_accelerate(Some(&reason), triggered_at, current_time)) {
+        accelerated_amount = vesting.accelerate(current_time)?;
+        emit!(VestingAccelerated {
+            vesting: vesting.key(),
+            recipient: vesting.recipient,
+            accelerated_amount,
+            accelerated_at: current_time,
+        });
+    }
+
+    // Apply the leaver terms agreed at grant time for this reason
+    let returned_amount = vesting.terminate(current_time, reason.clone())?;
+
+    // Grants carved from a pool recycle their forfeited tokens back into it
+    let dao_config = &mut ctx.accounts.dao_config;
+    let destination = VestingPool::get_forfeiture_destination(
+        vesting,
+        dao_config,
+        ctx.accounts.vesting_pool.as_mut(),
+        ctx.accounts.pool_ata.as_ref(),
+        &ctx.accounts.authority_ata,
+        returned_amount,
+    )?;
+
+    let recipient_key = vesting.recipient;
+    let seeds = &[
+        b"vesting",
+        dao_config_key.as_ref(),
+        recipient_key.as_ref(),
+        &[vesting.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    if returned_amount > 0 {
+        let transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.vesting_ata.to_account_info(),
+                to: destination,
+                authority: vesting_account_info,
+            },
+            signer_seeds,
+        );
+
+        anchor_spl::token::transfer(transfer_ctx, returned_amount)?;
+    }
+
+    msg!("Vesting terminated");
+    msg!("Recipient: {}", recipient_key);
+    msg!("Reason: {:?}", reason);
+    msg!("Accelerated: {}", accelerated_amount);
+    msg!("Retained: {}", vesting.total_amount);
+    msg!("Returned to treasury: {}", returned_amount);
+    msg!("Claim Deadline: {:?}", vesting.expiry_policy.claim_deadline);
+    msg!("Treasury Total Allocated: {}", dao_config.total_allocated);
+
+    emit!(VestingTerminated {
+        vesting: vesting.key(),
+        recipient: recipient_key,
+        reason,
+        retained_amount: vesting.total_amount,
+        returned_amount,
+        claim_deadline: vesting.expiry_policy.claim_deadline,
+        terminated_at: current_time,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{CloseAccount, Transfer};
+use crate::errors::MiraiError;
+use crate::state::WithholdingPolicy;
+use crate::utils::close_if_exists;
+use crate::TransferStream;
+
+/// Move the stream to a new recipient. Positions are addressed by recipient, so the
+/// stream and its escrow are recreated under the new recipient's PDA and the old ones closed.
+/// Configuration keyed to the old address is dropped or moved with it, so it cannot apply
+/// to a later stream created for the same recipient.
+pub fn handler(ctx: Context<TransferStream>) -> Result<()> {
+    let dao_config_key = ctx.accounts.dao_config.key();
+    let recipient_key = ctx.accounts.recipient.key();
+    let new_recipient_key = ctx.accounts.new_recipient.key();
+    let stream_account_info = ctx.accounts.stream.to_account_info();
+
+    let stream = &ctx.accounts.stream;
+    stream.terms.require_transferable()?;
+    require!(!stream.is_finished(), MiraiError::StreamModificationNotAllowed);
+    require!(new_recipient_key != recipient_key, MiraiError::InvalidTransferRecipient);
+
+    let mut transferred = (**stream).clone();
+    transferred.recipient = new_recipient_key;
+    transferred.stream_ata = ctx.accounts.new_stream_ata.key();
+    transferred.bump = ctx.bumps.new_stream;
+    ctx.accounts.new_stream.set_inner(transferred);
+
+    // Operators and splits belong to the old recipient; withholding is the DAO's and follows the stream
+    close_if_exists(&ctx.accounts.operator_delegation, &ctx.accounts.recipient)?;
+    close_if_exists(&ctx.accounts.payout_split, &ctx.accounts.recipient)?;
+    WithholdingPolicy::migrate(
+        &ctx.accounts.withholding_policy,
+        &ctx.accounts.new_withholding_policy,
+        ctx.bumps.new_withholding_policy,
+        ctx.accounts.new_stream.key(),
+        new_recipient_key,
+        &ctx.accounts.recipient,
+        &ctx.accounts.authority,
+        &ctx.accounts.system_program,
+    )?;
+
+    let seeds = &[
+        b"stream",
+        dao_config_key.as_ref(),
+        recipient_key.as_ref(),
+        &[ctx.accounts.stream.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    let escrow_amount = ctx.accounts.stream_ata.amount;
+    let transfer_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.stream_ata.to_account_info(),
+            to: ctx.accounts.new_stream_ata.to_account_info(),
+            authority: stream_account_info.clone(),
+        },
+        signer_seeds,
+    );
+    anchor_spl::token::transfer(transfer_ctx, escrow_amount)?;
+
+    // The DAO authority funded the old accounts, so their rent goes back to it
+    let close_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.stream_ata.to_account_info(),
+            destination: ctx.accounts.authority.to_account_info(),
+            authority: stream_account_info,
+        },
+        signer_seeds,
+    );
+    anchor_spl::token::close_account(close_ctx)?;
+
+    msg!("Stream transferred");
+    msg!("From: {}", recipient_key);
+    msg!("To: {}", new_recipient_key);
+    msg!("Escrow moved: {}", escrow_amount);
+
+    Ok(())
+}
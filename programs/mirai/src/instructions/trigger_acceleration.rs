@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+use crate::events::AccelerationTriggered;
+use crate::TriggerAcceleration;
+
+pub fn handler(ctx: Context<TriggerAcceleration>) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+
+    let dao_config = &mut ctx.accounts.dao_config;
+    let acceleration_event = &mut ctx.accounts.acceleration_event;
+    acceleration_event.dao_config = dao_config.key();
+    acceleration_event.index = dao_config.acceleration_event_count;
+    acceleration_event.triggered_at = current_time;
+    acceleration_event.bump = ctx.bumps.acceleration_event;
+
+    // Each change of control gets its own event, so a DAO can be acquired more than once
+    dao_config.record_acceleration_event(current_time);
+
+    msg!("Acceleration triggered");
+    msg!("DAO: {}", acceleration_event.dao_config);
+    msg!("Event: {}", acceleration_event.index);
+    msg!("Triggered at: {}", current_time);
+
+    emit!(AccelerationTriggered {
+        dao_config: acceleration_event.dao_config,
+        index: acceleration_event.index,
+        triggered_at: current_time,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use crate::errors::MiraiError;
+use crate::events::MetadataUpdated;
+use crate::state::{PaymentCategory, PositionKind};
+use crate::UpdateMetadata;
+
+pub fn handler(
+    ctx: Context<UpdateMetadata>,
+    category: Option<PaymentCategory>,
+    description: Option<String>,
+) -> Result<()> {
+    let dao_config_key = ctx.accounts.dao_config.key();
+    let clock = Clock::get()?;
+
+    if let Some(description) = description.as_ref() {
+        require!(description.len() <= 64, MiraiError::DescriptionTooLong);
+    }
+
+    let (position, kind, old_category, new_category, new_description) =
+        match (ctx.accounts.stream.as_mut(), ctx.accounts.vesting.as_mut()) {
+            (Some(stream), None) => {
+                let old_category = stream.category.clone();
+                if let Some(category) = category {
+                    stream.category = category;
+                }
+                if let Some(description) = description {
+                    stream.description = description;
+                }
+                (stream.key(), PositionKind::Stream, old_category, stream.category.clone(), stream.description.clone())
+            }
+            (None, Some(vesting)) => {
+                let old_category = vesting.category.clone();
+                if let Some(category) = category {
+                    vesting.category = category;
+                }
+                if let Some(description) = description {
+                    vesting.description = description;
+                }
+                (vesting.key(), PositionKind::Vesting, old_category, vesting.category.clone(), vesting.description.clone())
+            }
+            _ => return err!(MiraiError::InvalidPositionTarget),
+        };
+
+    msg!("Metadata updated successfully");
+    msg!("Position: {}", position);
+    msg!("Category: {:?} -> {:?}", old_category, new_category);
+    msg!("Description: {}", new_description);
+
+    emit!(MetadataUpdated {
+        dao_config: dao_config_key,
+        position,
+        kind,
+        old_category,
+        new_category,
+        description: new_description,
+        updated_at: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Transfer;
+use crate::errors::MiraiError;
+use crate::events::StreamCancelled;
+use crate::state::{CancellationReason, StreamStatus};
+use crate::WithdrawStreamOffer;
+
+pub fn handler(ctx: Context<WithdrawStreamOffer>) -> Result<()> {
+    let dao_config_key = ctx.accounts.dao_config.key();
+    let stream_to_account_info = ctx.accounts.stream.to_account_info();
+    let clock = Clock::get()?;
+
+    let stream = &mut ctx.accounts.stream;
+    require!(stream.status == StreamStatus::Pending, MiraiError::StreamNotPending);
+    let deadline = stream.acceptance_deadline.ok_or(MiraiError::StreamNotPending)?;
+    require!(clock.unix_timestamp > deadline, MiraiError::AcceptanceDeadlineNotReached);
+
+    let refund_amount = ctx.accounts.stream_ata.amount;
+    stream.cancel(CancellationReason::OfferWithdrawn);
+
+    let dao_config = &mut ctx.accounts.dao_config;
+    dao_config.release_allocation(stream.total_amount);
+
+    let recipient_key = stream.recipient;
+    let seeds = &[
+        b"stream",
+        dao_config_key.as_ref(),
+        recipient_key.as_ref(),
+        &[stream.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+    let transfer_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.stream_ata.to_account_info(),
+            to: ctx.accounts.authority_ata.to_account_info(),
+            authority: stream_to_account_info,
+        },
+        signer_seeds,
+    );
+    anchor_spl::token::transfer(transfer_ctx, refund_amount)?;
+
+    msg!("Stream offer withdrawn after acceptance deadline");
+    msg!("Recipient: {}", recipient_key);
+    msg!("Refunded: {}", refund_amount);
+    msg!("Treasury Total Allocated: {}", dao_config.total_allocated);
+
+    emit!(StreamCancelled {
+        stream: stream.key(),
+        recipient: recipient_key,
+        reason: CancellationReason::OfferWithdrawn,
+        settled_amount: 0,
+        refunded_amount: refund_amount,
+        cancelled_at: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Transfer;
+use crate::state::WithholdingPolicy;
+use crate::WithdrawTeamShare;
+
+pub fn handler(ctx: Context<WithdrawTeamShare>, amount: u64) -> Result<()> {
+    let dao_config_key = ctx.accounts.dao_config.key();
+    let team_key = ctx.accounts.team.key();
+    let member_key = ctx.accounts.member.key();
+    let stream_to_account_info = ctx.accounts.stream.to_account_info();
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // Bring every member's share up to date before this member withdraws theirs
+    let team = &mut ctx.accounts.team;
+    let stream = &mut ctx.accounts.stream;
+    team.checkpoint(stream, current_time);
+    team.record_member_withdrawal(&member_key, amount)?;
+
+    // A cancelled stream already counted its settlement as paid; members draw it from the escrow
+    if !stream.is_cancelled() {
+        stream.record_withdrawal(amount, current_time)?;
+        let dao_config = &mut ctx.accounts.dao_config;
+        dao_config.add_payment(amount);
+    }
+
+    let seeds = &[
+        b"stream",
+        dao_config_key.as_ref(),
+        team_key.as_ref(),
+        &[stream.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    // Withholding is recorded against the member, who is the one actually paid
+    let net_amount = WithholdingPolicy::apply(
+        &ctx.accounts.withholding_policy,
+        ctx.accounts.withholding_vault.as_ref(),
+        ctx.accounts.withholding_ledger.as_mut(),
+        amount,
+        ctx.accounts.stream_ata.to_account_info(),
+        stream_to_account_info.clone(),
+        ctx.accounts.token_program.to_account_info(),
+        current_time,
+        signer_seeds,
+    )?;
+
+    let transfer_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.stream_ata.to_account_info(),
+            to: ctx.accounts.member_ata.to_account_info(),
+            authority: stream_to_account_info,
+        },
+        signer_seeds,
+    );
+    anchor_spl::token::transfer(transfer_ctx, net_amount)?;
+
+    msg!("Team share withdrawn");
+    msg!("Team: {}", team.name);
+    msg!("Member: {}", member_key);
+    msg!("Amount withdrawn: {}", amount);
+    msg!("Stream Total withdrawn: {}", stream.withdrawn_amount);
+
+    Ok(())
+}
@@ -1,19 +1,21 @@
 #![allow(unexpected_cfgs,deprecated)]
 pub mod constants;
 pub mod errors;
+pub mod events;
 pub mod instructions;
 pub mod state;
+pub mod utils;
 
 use anchor_lang::prelude::*;
 
 pub use constants::*;
+pub use events::*;
 pub use instructions::*;
 pub use state::*;
 
 
-use anchor_spl::token::{Mint, Token, TokenAccount, Transfer};
+use anchor_spl::token::{Mint, Token, TokenAccount};
 use anchor_spl::associated_token::AssociatedToken;
-use crate::state::{DaoConfig, Stream, PaymentCategory, Vesting, VestingType};
 use crate::errors::MiraiError;
 
 declare_id!("DMiFVyoupSnwoWs2xq8n5X3hwtaaU6vLv9EkdSFoNsSv");
@@ -30,6 +32,7 @@ pub mod mirai {
         instructions::init_dao::handler(ctx, treasury_mint)
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn create_stream(
         ctx: Context<CreateStream>,
         start_time: i64,
@@ -37,17 +40,21 @@ pub mod mirai {
         total_amount: u64,
         category: PaymentCategory,
         description: String,
+        acceptance_deadline: Option<i64>,
+        expiry_policy: Option<ExpiryPolicy>,
+        terms: PositionTerms,
     ) -> Result<()> {
-        instructions::create_stream::handler(ctx, start_time, end_time, total_amount, category, description)
+        instructions::create_stream::handler(ctx, start_time, end_time, total_amount, category, description, acceptance_deadline, expiry_policy, terms)
     }
 
-    pub fn redeem_stream(
-        ctx: Context<RedeemStream>,
+    pub fn redeem_stream<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RedeemStream<'info>>,
         amount: u64,
     ) -> Result<()> {
         instructions::redeem_stream::handler(ctx, amount)
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn create_vesting(
         ctx: Context<CreateVesting>,
         vesting_type: VestingType,
@@ -57,17 +64,340 @@ pub mod mirai {
         cliff_time: i64,
         category: PaymentCategory,
         description: String,
+        expiry_policy: Option<ExpiryPolicy>,
+        terms: PositionTerms,
+        termination_policy: Option<TerminationPolicy>,
+        tge_unlock_bps: Option<u16>,
+        option_terms: Option<OptionTerms>,
     ) -> Result<()> {
-        instructions::create_vesting::handler(ctx, vesting_type, total_amount, start_time, end_time, cliff_time, category, description)
+        instructions::create_vesting::handler(ctx, vesting_type, total_amount, start_time, end_time, cliff_time, category, description, expiry_policy, terms, termination_policy, tge_unlock_bps, option_terms)
     }
 
-    pub fn claim_vesting(
-        ctx: Context<ClaimVesting>,
+    pub fn claim_vesting<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClaimVesting<'info>>,
         amount: u64,
     ) -> Result<()> {
         instructions::claim_vesting::handler(ctx, amount)
     }
 
+    pub fn close_stream(ctx: Context<CloseStream>) -> Result<()> {
+        instructions::close_stream::handler(ctx)
+    }
+
+    pub fn close_vesting(ctx: Context<CloseVesting>) -> Result<()> {
+        instructions::close_vesting::handler(ctx)
+    }
+
+    pub fn update_metadata(
+        ctx: Context<UpdateMetadata>,
+        category: Option<PaymentCategory>,
+        description: Option<String>,
+    ) -> Result<()> {
+        instructions::update_metadata::handler(ctx, category, description)
+    }
+
+    pub fn set_stream_operator(
+        ctx: Context<SetStreamOperator>,
+        operator: Pubkey,
+        max_amount_per_claim: Option<u64>,
+        expires_at: Option<i64>,
+        allow_any_destination: bool,
+    ) -> Result<()> {
+        instructions::set_stream_operator::handler(ctx, operator, max_amount_per_claim, expires_at, allow_any_destination)
+    }
+
+    pub fn set_vesting_operator(
+        ctx: Context<SetVestingOperator>,
+        operator: Pubkey,
+        max_amount_per_claim: Option<u64>,
+        expires_at: Option<i64>,
+        allow_any_destination: bool,
+    ) -> Result<()> {
+        instructions::set_vesting_operator::handler(ctx, operator, max_amount_per_claim, expires_at, allow_any_destination)
+    }
+
+    pub fn revoke_operator(ctx: Context<RevokeOperator>) -> Result<()> {
+        instructions::revoke_operator::handler(ctx)
+    }
+
+    pub fn operator_redeem_stream<'info>(
+        ctx: Context<'_, '_, 'info, 'info, OperatorRedeemStream<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::operator_redeem_stream::handler(ctx, amount)
+    }
+
+    pub fn operator_claim_vesting<'info>(
+        ctx: Context<'_, '_, 'info, 'info, OperatorClaimVesting<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::operator_claim_vesting::handler(ctx, amount)
+    }
+
+    pub fn schedule_rate_change(
+        ctx: Context<ScheduleRateChange>,
+        effective_time: i64,
+        new_segment_amount: u64,
+    ) -> Result<()> {
+        instructions::schedule_rate_change::handler(ctx, effective_time, new_segment_amount)
+    }
+
+    pub fn accept_stream(
+        ctx: Context<AcceptStream>,
+        agreement_hash: Option<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::accept_stream::handler(ctx, agreement_hash)
+    }
+
+    pub fn withdraw_stream_offer(ctx: Context<WithdrawStreamOffer>) -> Result<()> {
+        instructions::withdraw_stream_offer::handler(ctx)
+    }
+
+    pub fn sweep_stream(ctx: Context<SweepStream>) -> Result<()> {
+        instructions::sweep_stream::handler(ctx)
+    }
+
+    pub fn sweep_vesting(ctx: Context<SweepVesting>) -> Result<()> {
+        instructions::sweep_vesting::handler(ctx)
+    }
+
+    pub fn cancel_stream<'info>(ctx: Context<'_, '_, 'info, 'info, CancelStream<'info>>) -> Result<()> {
+        instructions::cancel_stream::handler(ctx)
+    }
+
+    pub fn transfer_stream(ctx: Context<TransferStream>) -> Result<()> {
+        instructions::transfer_stream::handler(ctx)
+    }
+
+    pub fn transfer_vesting(ctx: Context<TransferVesting>) -> Result<()> {
+        instructions::transfer_vesting::handler(ctx)
+    }
+
+    pub fn resign_stream<'info>(ctx: Context<'_, '_, 'info, 'info, ResignStream<'info>>) -> Result<()> {
+        instructions::resign_stream::handler(ctx)
+    }
+
+    pub fn set_renewal_policy(
+        ctx: Context<SetRenewalPolicy>,
+        policy: Option<RenewalPolicy>,
+    ) -> Result<()> {
+        instructions::set_renewal_policy::handler(ctx, policy)
+    }
+
+    pub fn decline_renewal(ctx: Context<DeclineRenewal>) -> Result<()> {
+        instructions::decline_renewal::handler(ctx)
+    }
+
+    pub fn renew_stream(ctx: Context<RenewStream>) -> Result<()> {
+        instructions::renew_stream::handler(ctx)
+    }
+
+    pub fn create_template(
+        ctx: Context<CreateTemplate>,
+        name: String,
+        params: TemplateParams,
+    ) -> Result<()> {
+        instructions::create_template::handler(ctx, name, params)
+    }
+
+    pub fn create_stream_from_template(
+        ctx: Context<CreateStreamFromTemplate>,
+        total_amount: u64,
+        start_time: i64,
+    ) -> Result<()> {
+        instructions::create_stream_from_template::handler(ctx, total_amount, start_time)
+    }
+
+    pub fn create_vesting_from_template(
+        ctx: Context<CreateVestingFromTemplate>,
+        total_amount: u64,
+        start_time: i64,
+    ) -> Result<()> {
+        instructions::create_vesting_from_template::handler(ctx, total_amount, start_time)
+    }
+
+    pub fn create_stream_group(ctx: Context<CreateStreamGroup>, name: String) -> Result<()> {
+        instructions::create_stream_group::handler(ctx, name)
+    }
+
+    pub fn pause_stream_group<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ManageStreamGroup<'info>>,
+    ) -> Result<()> {
+        instructions::pause_stream_group::handler(ctx)
+    }
+
+    pub fn resume_stream_group<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ManageStreamGroup<'info>>,
+    ) -> Result<()> {
+        instructions::resume_stream_group::handler(ctx)
+    }
+
+    pub fn extend_stream_group<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ManageStreamGroup<'info>>,
+        extension: i64,
+    ) -> Result<()> {
+        instructions::extend_stream_group::handler(ctx, extension)
+    }
+
+    pub fn cancel_stream_group<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CancelStreamGroup<'info>>,
+    ) -> Result<()> {
+        instructions::cancel_stream_group::handler(ctx)
+    }
+
+    pub fn create_payout_allowlist(ctx: Context<CreatePayoutAllowlist>, change_delay: i64) -> Result<()> {
+        instructions::create_payout_allowlist::handler(ctx, change_delay)
+    }
+
+    pub fn queue_payout_destination(ctx: Context<QueuePayoutDestination>) -> Result<()> {
+        instructions::queue_payout_destination::handler(ctx)
+    }
+
+    pub fn remove_payout_destination(
+        ctx: Context<RemovePayoutDestination>,
+        destination: Pubkey,
+    ) -> Result<()> {
+        instructions::remove_payout_destination::handler(ctx, destination)
+    }
+
+    pub fn set_stream_payout_split(
+        ctx: Context<SetStreamPayoutSplit>,
+        shares: Vec<SplitShare>,
+        primary: Pubkey,
+    ) -> Result<()> {
+        instructions::set_stream_payout_split::handler(ctx, shares, primary)
+    }
+
+    pub fn set_vesting_payout_split(
+        ctx: Context<SetVestingPayoutSplit>,
+        shares: Vec<SplitShare>,
+        primary: Pubkey,
+    ) -> Result<()> {
+        instructions::set_vesting_payout_split::handler(ctx, shares, primary)
+    }
+
+    pub fn clear_payout_split(ctx: Context<ClearPayoutSplit>) -> Result<()> {
+        instructions::clear_payout_split::handler(ctx)
+    }
+
+    pub fn create_team(ctx: Context<CreateTeam>, name: String, shares: Vec<TeamShare>) -> Result<()> {
+        instructions::create_team::handler(ctx, name, shares)
+    }
+
+    pub fn set_team_shares(ctx: Context<SetTeamShares>, shares: Vec<TeamShare>) -> Result<()> {
+        instructions::set_team_shares::handler(ctx, shares)
+    }
+
+    pub fn withdraw_team_share(ctx: Context<WithdrawTeamShare>, amount: u64) -> Result<()> {
+        instructions::withdraw_team_share::handler(ctx, amount)
+    }
+
+    pub fn init_withholding_vault(ctx: Context<InitWithholdingVault>) -> Result<()> {
+        instructions::init_withholding_vault::handler(ctx)
+    }
+
+    pub fn set_stream_withholding(ctx: Context<SetStreamWithholding>, rate_bps: u16) -> Result<()> {
+        instructions::set_stream_withholding::handler(ctx, rate_bps)
+    }
+
+    pub fn set_vesting_withholding(ctx: Context<SetVestingWithholding>, rate_bps: u16) -> Result<()> {
+        instructions::set_vesting_withholding::handler(ctx, rate_bps)
+    }
+
+    pub fn clear_withholding(ctx: Context<ClearWithholding>) -> Result<()> {
+        instructions::clear_withholding::handler(ctx)
+    }
+
+    pub fn open_withholding_ledger(ctx: Context<OpenWithholdingLedger>, year: u16) -> Result<()> {
+        instructions::open_withholding_ledger::handler(ctx, year)
+    }
+
+    pub fn remit_withholding(ctx: Context<RemitWithholding>, amount: u64) -> Result<()> {
+        instructions::remit_withholding::handler(ctx, amount)
+    }
+
+    pub fn revoke_vesting(ctx: Context<RevokeVesting>) -> Result<()> {
+        instructions::revoke_vesting::handler(ctx)
+    }
+
+    pub fn terminate_vesting(ctx: Context<TerminateVesting>, reason: TerminationReason) -> Result<()> {
+        instructions::terminate_vesting::handler(ctx, reason)
+    }
+
+    pub fn add_vesting_tranche(ctx: Context<AddVestingTranche>, tranche: VestingTranche) -> Result<()> {
+        instructions::add_vesting_tranche::handler(ctx, tranche)
+    }
+
+    pub fn set_max_backdate_period(ctx: Context<SetMaxBackdatePeriod>, max_backdate_period: i64) -> Result<()> {
+        instructions::set_max_backdate_period::handler(ctx, max_backdate_period)
+    }
+
+    pub fn migrate_dao_config(ctx: Context<MigrateDaoConfig>) -> Result<()> {
+        instructions::migrate_dao_config::handler(ctx)
+    }
+
+    pub fn set_acceleration_terms(ctx: Context<SetAccelerationTerms>, terms: Option<AccelerationTerms>) -> Result<()> {
+        instructions::set_acceleration_terms::handler(ctx, terms)
+    }
+
+    pub fn trigger_acceleration(ctx: Context<TriggerAcceleration>) -> Result<()> {
+        instructions::trigger_acceleration::handler(ctx)
+    }
+
+    pub fn apply_acceleration(ctx: Context<ApplyAcceleration>) -> Result<()> {
+        instructions::apply_acceleration::handler(ctx)
+    }
+
+    pub fn merge_vestings(ctx: Context<MergeVestings>) -> Result<()> {
+        instructions::merge_vestings::handler(ctx)
+    }
+
+    pub fn split_vesting(ctx: Context<SplitVesting>, split_bps: u16) -> Result<()> {
+        instructions::split_vesting::handler(ctx, split_bps)
+    }
+
+    pub fn create_vesting_pool(ctx: Context<CreateVestingPool>, name: String) -> Result<()> {
+        instructions::create_vesting_pool::handler(ctx, name)
+    }
+
+    pub fn fund_vesting_pool(ctx: Context<FundVestingPool>, amount: u64) -> Result<()> {
+        instructions::fund_vesting_pool::handler(ctx, amount)
+    }
+
+    pub fn report_vesting_pool<'info>(ctx: Context<'_, '_, 'info, 'info, ReportVestingPool<'info>>) -> Result<()> {
+        instructions::report_vesting_pool::handler(ctx)
+    }
+
+    pub fn exercise_option<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ExerciseOption<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::exercise_option::handler(ctx, amount)
+    }
+
+    pub fn create_milestone(ctx: Context<CreateMilestone>, name: String, attester: Pubkey, deadline: i64) -> Result<()> {
+        instructions::create_milestone::handler(ctx, name, attester, deadline)
+    }
+
+    pub fn attest_milestone(ctx: Context<AttestMilestone>) -> Result<()> {
+        instructions::attest_milestone::handler(ctx)
+    }
+
+    pub fn set_vesting_milestones<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SetVestingMilestones<'info>>,
+        shares: Vec<MilestoneShare>,
+    ) -> Result<()> {
+        instructions::set_vesting_milestones::handler(ctx, shares)
+    }
+
+    pub fn sync_vesting_milestones<'info>(ctx: Context<'_, '_, 'info, 'info, SyncVestingMilestones<'info>>) -> Result<()> {
+        instructions::sync_vesting_milestones::handler(ctx)
+    }
+
+    pub fn reclaim_missed_milestone(ctx: Context<ReclaimMissedMilestone>) -> Result<()> {
+        instructions::reclaim_missed_milestone::handler(ctx)
+    }
+
 }
 
 
@@ -102,7 +432,7 @@ pub struct InitDao<'info> {
 
 
 #[derive(Accounts)]
-#[instruction(start_time: i64, end_time: i64, total_amount: u64, category: PaymentCategory, description: String)]
+#[instruction(start_time: i64, end_time: i64, total_amount: u64, category: PaymentCategory, description: String, acceptance_deadline: Option<i64>, expiry_policy: Option<ExpiryPolicy>, terms: PositionTerms)]
 pub struct CreateStream<'info> {
     #[account(
         mut,
@@ -150,6 +480,11 @@ pub struct CreateStream<'info> {
     pub token_program: Program<'info, Token>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub rent: Sysvar<'info, Rent>,
+    #[account(
+        mut,
+        has_one = dao_config @ MiraiError::InvalidDaoConfig
+    )]
+    pub stream_group: Option<Account<'info, StreamGroup>>,
 }
 
 #[derive(Accounts)]
@@ -188,6 +523,46 @@ pub struct RedeemStream<'info> {
         associated_token::authority = recipient
     )]
     pub recipient_ata: Account<'info, TokenAccount>,
+    /// CHECK: Only enforced once the recipient has created a payout allowlist
+    #[account(
+        seeds = [b"payout_allowlist", dao_config.key().as_ref(), recipient.key().as_ref()],
+        bump
+    )]
+    pub payout_allowlist: UncheckedAccount<'info>,
+    /// CHECK: Only applied once the recipient has configured a payout split
+    #[account(
+        seeds = [b"payout_split", stream.key().as_ref()],
+        bump
+    )]
+    pub payout_split: UncheckedAccount<'info>,
+    /// CHECK: Only applied once the DAO has set a withholding rate on this position
+    #[account(
+        seeds = [b"withholding", stream.key().as_ref()],
+        bump
+    )]
+    pub withholding_policy: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [b"withholding_vault", dao_config.key().as_ref()],
+        bump
+    )]
+    pub withholding_vault: Option<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        seeds = [
+            b"withholding_ledger",
+            dao_config.key().as_ref(),
+            recipient.key().as_ref(),
+            &withholding_ledger.year.to_le_bytes()
+        ],
+        bump = withholding_ledger.bump
+    )]
+    pub withholding_ledger: Option<Account<'info, WithholdingLedger>>,
+    #[account(
+        mut,
+        constraint = payout_destination.mint == treasury_mint.key() @ MiraiError::InvalidMint
+    )]
+    pub payout_destination: Option<Account<'info, TokenAccount>>,
     #[account(
         constraint = treasury_mint.key() == dao_config.treasury_mint @ MiraiError::InvalidMint
     )]
@@ -199,7 +574,7 @@ pub struct RedeemStream<'info> {
 }
 
 #[derive(Accounts)]
-#[instruction(vesting_type: VestingType, total_amount: u64, start_time: i64, end_time: i64, cliff_time: i64, category: PaymentCategory, description: String)]
+#[instruction(vesting_type: VestingType, total_amount: u64, start_time: i64, end_time: i64, cliff_time: i64, category: PaymentCategory, description: String, expiry_policy: Option<ExpiryPolicy>, terms: PositionTerms, termination_policy: Option<TerminationPolicy>, tge_unlock_bps: Option<u16>, option_terms: Option<OptionTerms>)]
 pub struct CreateVesting<'info> {
     #[account(
         mut,
@@ -253,6 +628,13 @@ pub struct CreateVesting<'info> {
     pub token_program: Program<'info, Token>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub rent: Sysvar<'info, Rent>,
+    #[account(
+        mut,
+        has_one = dao_config @ MiraiError::InvalidDaoConfig
+    )]
+    pub vesting_pool: Option<Account<'info, VestingPool>>,
+    #[account(mut)]
+    pub pool_ata: Option<Account<'info, TokenAccount>>,
 }
 
 #[derive(Accounts)]
@@ -296,6 +678,52 @@ pub struct ClaimVesting<'info> {
     )]
     pub recipient_ata: Account<'info, TokenAccount>,
     
+    /// CHECK: Only enforced once the recipient has created a payout allowlist
+    #[account(
+        seeds = [b"payout_allowlist", dao_config.key().as_ref(), recipient.key().as_ref()],
+        bump
+    )]
+    pub payout_allowlist: UncheckedAccount<'info>,
+    
+    /// CHECK: Only applied once the recipient has configured a payout split
+    #[account(
+        seeds = [b"payout_split", vesting.key().as_ref()],
+        bump
+    )]
+    pub payout_split: UncheckedAccount<'info>,
+    
+    /// CHECK: Only applied once the DAO has set a withholding rate on this position
+    #[account(
+        seeds = [b"withholding", vesting.key().as_ref()],
+        bump
+    )]
+    pub withholding_policy: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"withholding_vault", dao_config.key().as_ref()],
+        bump
+    )]
+    pub withholding_vault: Option<Account<'info, TokenAccount>>,
+    
+    #[account(
+        mut,
+        seeds = [
+            b"withholding_ledger",
+            dao_config.key().as_ref(),
+            recipient.key().as_ref(),
+            &withholding_ledger.year.to_le_bytes()
+        ],
+        bump = withholding_ledger.bump
+    )]
+    pub withholding_ledger: Option<Account<'info, WithholdingLedger>>,
+    
+    #[account(
+        mut,
+        constraint = payout_destination.mint == treasury_mint.key() @ MiraiError::InvalidMint
+    )]
+    pub payout_destination: Option<Account<'info, TokenAccount>>,
+    
     #[account(
         constraint = treasury_mint.key() == dao_config.treasury_mint @ MiraiError::InvalidMint
     )]
@@ -306,3 +734,2424 @@ pub struct ClaimVesting<'info> {
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub rent: Sysvar<'info, Rent>,
 }
+
+#[derive(Accounts)]
+pub struct CloseStream<'info> {
+    #[account(
+        seeds = [b"dao_config", dao_config.authority.as_ref()],
+        bump = dao_config.bump
+    )]
+    pub dao_config: Account<'info, DaoConfig>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [
+            b"stream",
+            dao_config.key().as_ref(),
+            stream.recipient.as_ref()
+        ],
+        bump = stream.bump,
+        has_one = authority @ MiraiError::UnauthorizedClose,
+        has_one = dao_config @ MiraiError::InvalidDaoConfig
+    )]
+    pub stream: Account<'info, Stream>,
+
+    /// Original payer of the stream and its escrow rent
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = stream_ata.key() == stream.stream_ata @ MiraiError::InvalidTokenAccount
+    )]
+    pub stream_ata: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct CloseVesting<'info> {
+    #[account(
+        seeds = [b"dao_config", dao_config.authority.as_ref()],
+        bump = dao_config.bump
+    )]
+    pub dao_config: Account<'info, DaoConfig>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [
+            b"vesting",
+            dao_config.key().as_ref(),
+            vesting.recipient.as_ref()
+        ],
+        bump = vesting.bump,
+        has_one = authority @ MiraiError::UnauthorizedClose,
+        has_one = dao_config @ MiraiError::InvalidDaoConfig
+    )]
+    pub vesting: Account<'info, Vesting>,
+
+    /// Original payer of the vesting and its escrow rent
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = vesting_ata.key() == vesting.vesting_ata @ MiraiError::InvalidTokenAccount
+    )]
+    pub vesting_ata: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateMetadata<'info> {
+    #[account(
+        seeds = [b"dao_config", authority.key().as_ref()],
+        bump = dao_config.bump,
+        has_one = authority @ MiraiError::UnauthorizedMetadataUpdate
+    )]
+    pub dao_config: Account<'info, DaoConfig>,
+
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = dao_config @ MiraiError::InvalidDaoConfig
+    )]
+    pub stream: Option<Account<'info, Stream>>,
+
+    #[account(
+        mut,
+        has_one = dao_config @ MiraiError::InvalidDaoConfig
+    )]
+    pub vesting: Option<Account<'info, Vesting>>,
+}
+
+#[derive(Accounts)]
+pub struct SetStreamOperator<'info> {
+    #[account(
+        seeds = [
+            b"stream",
+            stream.dao_config.as_ref(),
+            recipient.key().as_ref()
+        ],
+        bump = stream.bump,
+        has_one = recipient @ MiraiError::UnauthorizedWithdrawal
+    )]
+    pub stream: Account<'info, Stream>,
+
+    #[account(
+        init_if_needed,
+        payer = recipient,
+        space = OperatorDelegation::SIZE,
+        seeds = [b"operator", stream.key().as_ref()],
+        bump
+    )]
+    pub delegation: Account<'info, OperatorDelegation>,
+
+    #[account(mut)]
+    pub recipient: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetVestingOperator<'info> {
+    #[account(
+        seeds = [
+            b"vesting",
+            vesting.dao_config.as_ref(),
+            recipient.key().as_ref()
+        ],
+        bump = vesting.bump,
+        has_one = recipient @ MiraiError::UnauthorizedVestingClaim
+    )]
+    pub vesting: Account<'info, Vesting>,
+
+    #[account(
+        init_if_needed,
+        payer = recipient,
+        space = OperatorDelegation::SIZE,
+        seeds = [b"operator", vesting.key().as_ref()],
+        bump
+    )]
+    pub delegation: Account<'info, OperatorDelegation>,
+
+    #[account(mut)]
+    pub recipient: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RevokeOperator<'info> {
+    #[account(
+        mut,
+        close = recipient,
+        seeds = [b"operator", delegation.position.as_ref()],
+        bump = delegation.bump,
+        has_one = recipient @ MiraiError::UnauthorizedWithdrawal
+    )]
+    pub delegation: Account<'info, OperatorDelegation>,
+
+    #[account(mut)]
+    pub recipient: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(amount: u64)]
+pub struct OperatorRedeemStream<'info> {
+    #[account(
+        mut,
+        seeds = [b"dao_config", dao_config.authority.as_ref()],
+        bump = dao_config.bump
+    )]
+    pub dao_config: Account<'info, DaoConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"stream",
+            dao_config.key().as_ref(),
+            recipient.key().as_ref()
+        ],
+        bump = stream.bump,
+        has_one = recipient @ MiraiError::UnauthorizedWithdrawal,
+        has_one = dao_config @ MiraiError::InvalidDaoConfig
+    )]
+    pub stream: Account<'info, Stream>,
+
+    /// CHECK: Validated against the stream's recipient
+    pub recipient: UncheckedAccount<'info>,
+
+    pub operator: Signer<'info>,
+
+    #[account(
+        seeds = [b"operator", stream.key().as_ref()],
+        bump = delegation.bump,
+        has_one = operator @ MiraiError::UnauthorizedOperator,
+        has_one = recipient @ MiraiError::UnauthorizedOperator
+    )]
+    pub delegation: Account<'info, OperatorDelegation>,
+
+    #[account(
+        mut,
+        constraint = stream_ata.key() == stream.stream_ata @ MiraiError::InvalidTokenAccount,
+        constraint = stream_ata.mint == treasury_mint.key() @ MiraiError::InvalidMint
+    )]
+    pub stream_ata: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = destination.mint == treasury_mint.key() @ MiraiError::InvalidMint
+    )]
+    pub destination: Account<'info, TokenAccount>,
+
+    /// CHECK: Only enforced once the recipient has created a payout allowlist
+    #[account(
+        seeds = [b"payout_allowlist", dao_config.key().as_ref(), recipient.key().as_ref()],
+        bump
+    )]
+    pub payout_allowlist: UncheckedAccount<'info>,
+
+    /// CHECK: Only applied once the recipient has configured a payout split
+    #[account(
+        seeds = [b"payout_split", stream.key().as_ref()],
+        bump
+    )]
+    pub payout_split: UncheckedAccount<'info>,
+
+    /// CHECK: Only applied once the DAO has set a withholding rate on this position
+    #[account(
+        seeds = [b"withholding", stream.key().as_ref()],
+        bump
+    )]
+    pub withholding_policy: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"withholding_vault", dao_config.key().as_ref()],
+        bump
+    )]
+    pub withholding_vault: Option<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"withholding_ledger",
+            dao_config.key().as_ref(),
+            recipient.key().as_ref(),
+            &withholding_ledger.year.to_le_bytes()
+        ],
+        bump = withholding_ledger.bump
+    )]
+    pub withholding_ledger: Option<Account<'info, WithholdingLedger>>,
+
+    #[account(
+        constraint = treasury_mint.key() == dao_config.treasury_mint @ MiraiError::InvalidMint
+    )]
+    pub treasury_mint: Account<'info, Mint>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+#[instruction(amount: u64)]
+pub struct OperatorClaimVesting<'info> {
+    #[account(
+        mut,
+        seeds = [b"dao_config", dao_config.authority.as_ref()],
+        bump = dao_config.bump
+    )]
+    pub dao_config: Account<'info, DaoConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"vesting",
+            dao_config.key().as_ref(),
+            recipient.key().as_ref()
+        ],
+        bump = vesting.bump,
+        has_one = recipient @ MiraiError::UnauthorizedVestingClaim,
+        has_one = dao_config @ MiraiError::InvalidDaoConfig
+    )]
+    pub vesting: Account<'info, Vesting>,
+
+    /// CHECK: Validated against the vesting's recipient
+    pub recipient: UncheckedAccount<'info>,
+
+    pub operator: Signer<'info>,
+
+    #[account(
+        seeds = [b"operator", vesting.key().as_ref()],
+        bump = delegation.bump,
+        has_one = operator @ MiraiError::UnauthorizedOperator,
+        has_one = recipient @ MiraiError::UnauthorizedOperator
+    )]
+    pub delegation: Account<'info, OperatorDelegation>,
+
+    #[account(
+        mut,
+        constraint = vesting_ata.key() == vesting.vesting_ata @ MiraiError::InvalidTokenAccount,
+        constraint = vesting_ata.mint == treasury_mint.key() @ MiraiError::InvalidMint
+    )]
+    pub vesting_ata: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = destination.mint == treasury_mint.key() @ MiraiError::InvalidMint
+    )]
+    pub destination: Account<'info, TokenAccount>,
+
+    /// CHECK: Only enforced once the recipient has created a payout allowlist
+    #[account(
+        seeds = [b"payout_allowlist", dao_config.key().as_ref(), recipient.key().as_ref()],
+        bump
+    )]
+    pub payout_allowlist: UncheckedAccount<'info>,
+
+    /// CHECK: Only applied once the recipient has configured a payout split
+    #[account(
+        seeds = [b"payout_split", vesting.key().as_ref()],
+        bump
+    )]
+    pub payout_split: UncheckedAccount<'info>,
+
+    /// CHECK: Only applied once the DAO has set a withholding rate on this position
+    #[account(
+        seeds = [b"withholding", vesting.key().as_ref()],
+        bump
+    )]
+    pub withholding_policy: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"withholding_vault", dao_config.key().as_ref()],
+        bump
+    )]
+    pub withholding_vault: Option<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"withholding_ledger",
+            dao_config.key().as_ref(),
+            recipient.key().as_ref(),
+            &withholding_ledger.year.to_le_bytes()
+        ],
+        bump = withholding_ledger.bump
+    )]
+    pub withholding_ledger: Option<Account<'info, WithholdingLedger>>,
+
+    #[account(
+        constraint = treasury_mint.key() == dao_config.treasury_mint @ MiraiError::InvalidMint
+    )]
+    pub treasury_mint: Account<'info, Mint>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct ScheduleRateChange<'info> {
+    #[account(
+        mut,
+        seeds = [b"dao_config", authority.key().as_ref()],
+        bump = dao_config.bump,
+        has_one = authority @ MiraiError::UnauthorizedStreamCreation,
+        has_one = treasury_mint @ MiraiError::InvalidMint
+    )]
+    pub dao_config: Account<'info, DaoConfig>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"stream",
+            dao_config.key().as_ref(),
+            stream.recipient.as_ref()
+        ],
+        bump = stream.bump,
+        has_one = dao_config @ MiraiError::InvalidDaoConfig
+    )]
+    pub stream: Account<'info, Stream>,
+
+    #[account(
+        mut,
+        constraint = stream_ata.key() == stream.stream_ata @ MiraiError::InvalidTokenAccount
+    )]
+    pub stream_ata: Account<'info, TokenAccount>,
+
+    pub treasury_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        constraint = authority_ata.mint == treasury_mint.key() @ MiraiError::InvalidMint,
+        constraint = authority_ata.owner == authority.key() @ MiraiError::UnauthorizedStreamCreation
+    )]
+    pub authority_ata: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+
+    #[account(
+        mut,
+        constraint = stream.group == Some(stream_group.key()) @ MiraiError::InvalidGroupMember
+    )]
+    pub stream_group: Option<Account<'info, StreamGroup>>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptStream<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"stream",
+            stream.dao_config.as_ref(),
+            recipient.key().as_ref()
+        ],
+        bump = stream.bump,
+        has_one = recipient @ MiraiError::UnauthorizedWithdrawal
+    )]
+    pub stream: Account<'info, Stream>,
+
+    pub recipient: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawStreamOffer<'info> {
+    #[account(
+        mut,
+        seeds = [b"dao_config", authority.key().as_ref()],
+        bump = dao_config.bump,
+        has_one = authority @ MiraiError::UnauthorizedStreamCreation
+    )]
+    pub dao_config: Account<'info, DaoConfig>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"stream",
+            dao_config.key().as_ref(),
+            stream.recipient.as_ref()
+        ],
+        bump = stream.bump,
+        has_one = dao_config @ MiraiError::InvalidDaoConfig
+    )]
+    pub stream: Account<'info, Stream>,
+
+    #[account(
+        mut,
+        constraint = stream_ata.key() == stream.stream_ata @ MiraiError::InvalidTokenAccount
+    )]
+    pub stream_ata: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = authority_ata.mint == dao_config.treasury_mint @ MiraiError::InvalidMint,
+        constraint = authority_ata.owner == authority.key() @ MiraiError::UnauthorizedStreamCreation
+    )]
+    pub authority_ata: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct SweepStream<'info> {
+    #[account(
+        mut,
+        seeds = [b"dao_config", authority.key().as_ref()],
+        bump = dao_config.bump,
+        has_one = authority @ MiraiError::UnauthorizedStreamCreation
+    )]
+    pub dao_config: Account<'info, DaoConfig>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"stream",
+            dao_config.key().as_ref(),
+            stream.recipient.as_ref()
+        ],
+        bump = stream.bump,
+        has_one = dao_config @ MiraiError::InvalidDaoConfig
+    )]
+    pub stream: Account<'info, Stream>,
+
+    #[account(
+        mut,
+        constraint = stream_ata.key() == stream.stream_ata @ MiraiError::InvalidTokenAccount
+    )]
+    pub stream_ata: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = authority_ata.mint == dao_config.treasury_mint @ MiraiError::InvalidMint,
+        constraint = authority_ata.owner == authority.key() @ MiraiError::UnauthorizedStreamCreation
+    )]
+    pub authority_ata: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct SweepVesting<'info> {
+    #[account(
+        mut,
+        seeds = [b"dao_config", authority.key().as_ref()],
+        bump = dao_config.bump,
+        has_one = authority @ MiraiError::UnauthorizedVestingCreation
+    )]
+    pub dao_config: Account<'info, DaoConfig>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"vesting",
+            dao_config.key().as_ref(),
+            vesting.recipient.as_ref()
+        ],
+        bump = vesting.bump,
+        has_one = dao_config @ MiraiError::InvalidDaoConfig
+    )]
+    pub vesting: Account<'info, Vesting>,
+
+    #[account(
+        mut,
+        constraint = vesting_ata.key() == vesting.vesting_ata @ MiraiError::InvalidTokenAccount
+    )]
+    pub vesting_ata: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = authority_ata.mint == dao_config.treasury_mint @ MiraiError::InvalidMint,
+        constraint = authority_ata.owner == authority.key() @ MiraiError::UnauthorizedVestingCreation
+    )]
+    pub authority_ata: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+
+    #[account(
+        mut,
+        has_one = dao_config @ MiraiError::InvalidDaoConfig
+    )]
+    pub vesting_pool: Option<Account<'info, VestingPool>>,
+
+    #[account(mut)]
+    pub pool_ata: Option<Account<'info, TokenAccount>>,
+}
+
+#[derive(Accounts)]
+pub struct CancelStream<'info> {
+    #[account(
+        mut,
+        seeds = [b"dao_config", authority.key().as_ref()],
+        bump = dao_config.bump,
+        has_one = authority @ MiraiError::UnauthorizedStreamCreation,
+        has_one = treasury_mint @ MiraiError::InvalidMint
+    )]
+    pub dao_config: Account<'info, DaoConfig>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"stream",
+            dao_config.key().as_ref(),
+            recipient.key().as_ref()
+        ],
+        bump = stream.bump,
+        has_one = recipient @ MiraiError::UnauthorizedWithdrawal,
+        has_one = dao_config @ MiraiError::InvalidDaoConfig
+    )]
+    pub stream: Account<'info, Stream>,
+
+    /// CHECK: Validated against the stream's recipient
+    pub recipient: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        constraint = stream_ata.key() == stream.stream_ata @ MiraiError::InvalidTokenAccount
+    )]
+    pub stream_ata: Account<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        associated_token::mint = treasury_mint,
+        associated_token::authority = recipient
+    )]
+    pub recipient_ata: Account<'info, TokenAccount>,
+
+    /// CHECK: Only enforced once the recipient has created a payout allowlist
+    #[account(
+        seeds = [b"payout_allowlist", dao_config.key().as_ref(), recipient.key().as_ref()],
+        bump
+    )]
+    pub payout_allowlist: UncheckedAccount<'info>,
+
+    /// CHECK: Only applied once the recipient has configured a payout split
+    #[account(
+        seeds = [b"payout_split", stream.key().as_ref()],
+        bump
+    )]
+    pub payout_split: UncheckedAccount<'info>,
+
+    /// CHECK: Only applied once the DAO has set a withholding rate on this position
+    #[account(
+        seeds = [b"withholding", stream.key().as_ref()],
+        bump
+    )]
+    pub withholding_policy: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"withholding_vault", dao_config.key().as_ref()],
+        bump
+    )]
+    pub withholding_vault: Option<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"withholding_ledger",
+            dao_config.key().as_ref(),
+            recipient.key().as_ref(),
+            &withholding_ledger.year.to_le_bytes()
+        ],
+        bump = withholding_ledger.bump
+    )]
+    pub withholding_ledger: Option<Account<'info, WithholdingLedger>>,
+
+    #[account(
+        mut,
+        constraint = payout_destination.mint == treasury_mint.key() @ MiraiError::InvalidMint
+    )]
+    pub payout_destination: Option<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = authority_ata.mint == treasury_mint.key() @ MiraiError::InvalidMint,
+        constraint = authority_ata.owner == authority.key() @ MiraiError::UnauthorizedStreamCreation
+    )]
+    pub authority_ata: Account<'info, TokenAccount>,
+
+    pub treasury_mint: Account<'info, Mint>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+#[derive(Accounts)]
+pub struct TransferStream<'info> {
+    #[account(
+        seeds = [b"dao_config", dao_config.authority.as_ref()],
+        bump = dao_config.bump
+    )]
+    pub dao_config: Account<'info, DaoConfig>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [
+            b"stream",
+            dao_config.key().as_ref(),
+            recipient.key().as_ref()
+        ],
+        bump = stream.bump,
+        has_one = recipient @ MiraiError::UnauthorizedWithdrawal,
+        has_one = dao_config @ MiraiError::InvalidDaoConfig
+    )]
+    pub stream: Account<'info, Stream>,
+
+    #[account(mut)]
+    pub recipient: Signer<'info>,
+
+    /// CHECK: DAO authority that paid for the old stream accounts and gets their rent back
+    #[account(
+        mut,
+        address = stream.authority @ MiraiError::UnauthorizedClose
+    )]
+    pub authority: UncheckedAccount<'info>,
+
+    /// CHECK: Any account can receive a transferred position
+    pub new_recipient: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = recipient,
+        space = Stream::SIZE,
+        seeds = [
+            b"stream",
+            dao_config.key().as_ref(),
+            new_recipient.key().as_ref()
+        ],
+        bump
+    )]
+    pub new_stream: Account<'info, Stream>,
+
+    #[account(
+        mut,
+        constraint = stream_ata.key() == stream.stream_ata @ MiraiError::InvalidTokenAccount
+    )]
+    pub stream_ata: Account<'info, TokenAccount>,
+
+    #[account(
+        init,
+        payer = recipient,
+        associated_token::mint = treasury_mint,
+        associated_token::authority = new_stream
+    )]
+    pub new_stream_ata: Account<'info, TokenAccount>,
+
+    /// CHECK: Closed if the recipient registered an operator for the old stream
+    #[account(
+        mut,
+        seeds = [b"operator", stream.key().as_ref()],
+        bump
+    )]
+    pub operator_delegation: UncheckedAccount<'info>,
+
+    /// CHECK: Closed if the recipient configured a payout split for the old stream
+    #[account(
+        mut,
+        seeds = [b"payout_split", stream.key().as_ref()],
+        bump
+    )]
+    pub payout_split: UncheckedAccount<'info>,
+
+    /// CHECK: Moved to the new stream if the DAO withholds from the old one
+    #[account(
+        mut,
+        seeds = [b"withholding", stream.key().as_ref()],
+        bump
+    )]
+    pub withholding_policy: UncheckedAccount<'info>,
+
+    /// CHECK: Only created when the old stream has a withholding policy
+    #[account(
+        mut,
+        seeds = [b"withholding", new_stream.key().as_ref()],
+        bump
+    )]
+    pub new_withholding_policy: UncheckedAccount<'info>,
+
+    #[account(
+        constraint = treasury_mint.key() == dao_config.treasury_mint @ MiraiError::InvalidMint
+    )]
+    pub treasury_mint: Account<'info, Mint>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+#[derive(Accounts)]
+pub struct TransferVesting<'info> {
+    #[account(
+        seeds = [b"dao_config", dao_config.authority.as_ref()],
+        bump = dao_config.bump
+    )]
+    pub dao_config: Account<'info, DaoConfig>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [
+            b"vesting",
+            dao_config.key().as_ref(),
+            recipient.key().as_ref()
+        ],
+        bump = vesting.bump,
+        has_one = recipient @ MiraiError::UnauthorizedVestingClaim,
+        has_one = dao_config @ MiraiError::InvalidDaoConfig
+    )]
+    pub vesting: Account<'info, Vesting>,
+
+    #[account(mut)]
+    pub recipient: Signer<'info>,
+
+    /// CHECK: DAO authority that paid for the old vesting accounts and gets their rent back
+    #[account(
+        mut,
+        address = vesting.authority @ MiraiError::UnauthorizedClose
+    )]
+    pub authority: UncheckedAccount<'info>,
+
+    /// CHECK: Any account can receive a transferred position
+    pub new_recipient: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = recipient,
+        space = Vesting::SIZE,
+        seeds = [
+            b"vesting",
+            dao_config.key().as_ref(),
+            new_recipient.key().as_ref()
+        ],
+        bump
+    )]
+    pub new_vesting: Account<'info, Vesting>,
+
+    #[account(
+        mut,
+        constraint = vesting_ata.key() == vesting.vesting_ata @ MiraiError::InvalidTokenAccount
+    )]
+    pub vesting_ata: Account<'info, TokenAccount>,
+
+    #[account(
+        init,
+        payer = recipient,
+        associated_token::mint = treasury_mint,
+        associated_token::authority = new_vesting
+    )]
+    pub new_vesting_ata: Account<'info, TokenAccount>,
+
+    /// CHECK: Closed if the recipient registered an operator for the old vesting
+    #[account(
+        mut,
+        seeds = [b"operator", vesting.key().as_ref()],
+        bump
+    )]
+    pub operator_delegation: UncheckedAccount<'info>,
+
+    /// CHECK: Closed if the recipient configured a payout split for the old vesting
+    #[account(
+        mut,
+        seeds = [b"payout_split", vesting.key().as_ref()],
+        bump
+    )]
+    pub payout_split: UncheckedAccount<'info>,
+
+    /// CHECK: Moved to the new vesting if the DAO withholds from the old one
+    #[account(
+        mut,
+        seeds = [b"withholding", vesting.key().as_ref()],
+        bump
+    )]
+    pub withholding_policy: UncheckedAccount<'info>,
+
+    /// CHECK: Only created when the old vesting has a withholding policy
+    #[account(
+        mut,
+        seeds = [b"withholding", new_vesting.key().as_ref()],
+        bump
+    )]
+    pub new_withholding_policy: UncheckedAccount<'info>,
+
+    #[account(
+        constraint = treasury_mint.key() == dao_config.treasury_mint @ MiraiError::InvalidMint
+    )]
+    pub treasury_mint: Account<'info, Mint>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+#[derive(Accounts)]
+pub struct ResignStream<'info> {
+    #[account(
+        mut,
+        seeds = [b"dao_config", dao_config.authority.as_ref()],
+        bump = dao_config.bump,
+        has_one = treasury_mint @ MiraiError::InvalidMint
+    )]
+    pub dao_config: Account<'info, DaoConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"stream",
+            dao_config.key().as_ref(),
+            recipient.key().as_ref()
+        ],
+        bump = stream.bump,
+        has_one = recipient @ MiraiError::UnauthorizedWithdrawal,
+        has_one = dao_config @ MiraiError::InvalidDaoConfig
+    )]
+    pub stream: Account<'info, Stream>,
+
+    #[account(mut)]
+    pub recipient: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = stream_ata.key() == stream.stream_ata @ MiraiError::InvalidTokenAccount
+    )]
+    pub stream_ata: Account<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = recipient,
+        associated_token::mint = treasury_mint,
+        associated_token::authority = recipient
+    )]
+    pub recipient_ata: Account<'info, TokenAccount>,
+
+    /// CHECK: Only enforced once the recipient has created a payout allowlist
+    #[account(
+        seeds = [b"payout_allowlist", dao_config.key().as_ref(), recipient.key().as_ref()],
+        bump
+    )]
+    pub payout_allowlist: UncheckedAccount<'info>,
+
+    /// CHECK: Only applied once the recipient has configured a payout split
+    #[account(
+        seeds = [b"payout_split", stream.key().as_ref()],
+        bump
+    )]
+    pub payout_split: UncheckedAccount<'info>,
+
+    /// CHECK: Only applied once the DAO has set a withholding rate on this position
+    #[account(
+        seeds = [b"withholding", stream.key().as_ref()],
+        bump
+    )]
+    pub withholding_policy: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"withholding_vault", dao_config.key().as_ref()],
+        bump
+    )]
+    pub withholding_vault: Option<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"withholding_ledger",
+            dao_config.key().as_ref(),
+            recipient.key().as_ref(),
+            &withholding_ledger.year.to_le_bytes()
+        ],
+        bump = withholding_ledger.bump
+    )]
+    pub withholding_ledger: Option<Account<'info, WithholdingLedger>>,
+
+    #[account(
+        mut,
+        constraint = payout_destination.mint == treasury_mint.key() @ MiraiError::InvalidMint
+    )]
+    pub payout_destination: Option<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = authority_ata.mint == treasury_mint.key() @ MiraiError::InvalidMint,
+        constraint = authority_ata.owner == dao_config.authority @ MiraiError::InvalidTokenAccount
+    )]
+    pub authority_ata: Account<'info, TokenAccount>,
+
+    pub treasury_mint: Account<'info, Mint>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+#[derive(Accounts)]
+pub struct SetRenewalPolicy<'info> {
+    #[account(
+        seeds = [b"dao_config", authority.key().as_ref()],
+        bump = dao_config.bump,
+        has_one = authority @ MiraiError::UnauthorizedStreamCreation
+    )]
+    pub dao_config: Account<'info, DaoConfig>,
+
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"stream",
+            dao_config.key().as_ref(),
+            stream.recipient.as_ref()
+        ],
+        bump = stream.bump,
+        has_one = dao_config @ MiraiError::InvalidDaoConfig
+    )]
+    pub stream: Account<'info, Stream>,
+}
+
+#[derive(Accounts)]
+pub struct DeclineRenewal<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"stream",
+            stream.dao_config.as_ref(),
+            recipient.key().as_ref()
+        ],
+        bump = stream.bump,
+        has_one = recipient @ MiraiError::UnauthorizedWithdrawal
+    )]
+    pub stream: Account<'info, Stream>,
+
+    pub recipient: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RenewStream<'info> {
+    #[account(
+        mut,
+        seeds = [b"dao_config", dao_config.authority.as_ref()],
+        bump = dao_config.bump,
+        has_one = treasury_mint @ MiraiError::InvalidMint
+    )]
+    pub dao_config: Account<'info, DaoConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"stream",
+            dao_config.key().as_ref(),
+            stream.recipient.as_ref()
+        ],
+        bump = stream.bump,
+        has_one = dao_config @ MiraiError::InvalidDaoConfig
+    )]
+    pub stream: Account<'info, Stream>,
+
+    #[account(
+        mut,
+        constraint = stream_ata.key() == stream.stream_ata @ MiraiError::InvalidTokenAccount
+    )]
+    pub stream_ata: Account<'info, TokenAccount>,
+
+    /// DAO-controlled vault that funds renewals
+    #[account(
+        mut,
+        associated_token::mint = treasury_mint,
+        associated_token::authority = dao_config
+    )]
+    pub dao_vault: Account<'info, TokenAccount>,
+
+    pub treasury_mint: Account<'info, Mint>,
+
+    /// Anyone can crank a due renewal
+    pub cranker: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+
+    #[account(
+        mut,
+        constraint = stream.group == Some(stream_group.key()) @ MiraiError::InvalidGroupMember
+    )]
+    pub stream_group: Option<Account<'info, StreamGroup>>,
+}
+
+#[derive(Accounts)]
+#[instruction(name: String)]
+pub struct CreateTemplate<'info> {
+    #[account(
+        seeds = [b"dao_config", authority.key().as_ref()],
+        bump = dao_config.bump,
+        has_one = authority @ MiraiError::UnauthorizedTreasuryModification
+    )]
+    pub dao_config: Account<'info, DaoConfig>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = Template::SIZE,
+        seeds = [b"template", dao_config.key().as_ref(), name.as_bytes()],
+        bump
+    )]
+    pub template: Account<'info, Template>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CreateStreamFromTemplate<'info> {
+    #[account(
+        mut,
+        seeds = [b"dao_config", authority.key().as_ref()],
+        bump = dao_config.bump,
+        has_one = authority @ MiraiError::UnauthorizedStreamCreation,
+        has_one = treasury_mint @ MiraiError::InvalidMint
+    )]
+    pub dao_config: Account<'info, DaoConfig>,
+    #[account(
+        seeds = [b"template", dao_config.key().as_ref(), template.name.as_bytes()],
+        bump = template.bump,
+        has_one = dao_config @ MiraiError::InvalidDaoConfig
+    )]
+    pub template: Account<'info, Template>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    /// CHECK: This is the recipient of the stream
+    pub recipient: UncheckedAccount<'info>,
+    #[account(
+        init,
+        payer = authority,
+        space = Stream::SIZE,
+        seeds = [
+            b"stream",
+            dao_config.key().as_ref(),
+            recipient.key().as_ref()
+        ],
+        bump
+    )]
+    pub stream: Account<'info, Stream>,
+    #[account(
+        init,
+        payer = authority,
+        associated_token::mint = treasury_mint,
+        associated_token::authority = stream
+    )]
+    pub stream_ata: Account<'info, TokenAccount>,
+    pub treasury_mint: Account<'info, Mint>,
+    #[account(
+        mut,
+        constraint = authority_ata.mint == treasury_mint.key() @ MiraiError::InvalidMint,
+        constraint = authority_ata.owner == authority.key() @ MiraiError::UnauthorizedStreamCreation
+    )]
+    pub authority_ata: Account<'info, TokenAccount>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+#[derive(Accounts)]
+pub struct CreateVestingFromTemplate<'info> {
+    #[account(
+        mut,
+        seeds = [b"dao_config", authority.key().as_ref()],
+        bump = dao_config.bump,
+        has_one = authority @ MiraiError::UnauthorizedVestingCreation,
+        has_one = treasury_mint @ MiraiError::InvalidMint
+    )]
+    pub dao_config: Account<'info, DaoConfig>,
+    
+    #[account(
+        seeds = [b"template", dao_config.key().as_ref(), template.name.as_bytes()],
+        bump = template.bump,
+        has_one = dao_config @ MiraiError::InvalidDaoConfig
+    )]
+    pub template: Account<'info, Template>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// CHECK: This is the recipient of the vesting
+    pub recipient: UncheckedAccount<'info>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = Vesting::SIZE,
+        seeds = [
+            b"vesting",
+            dao_config.key().as_ref(),
+            recipient.key().as_ref()
+        ],
+        bump
+    )]
+    pub vesting: Account<'info, Vesting>,
+    
+    #[account(
+        init,
+        payer = authority,
+        associated_token::mint = treasury_mint,
+        associated_token::authority = vesting
+    )]
+    pub vesting_ata: Account<'info, TokenAccount>,
+    
+    pub treasury_mint: Account<'info, Mint>,
+    
+    #[account(
+        mut,
+        constraint = authority_ata.mint == treasury_mint.key() @ MiraiError::InvalidMint,
+        constraint = authority_ata.owner == authority.key() @ MiraiError::UnauthorizedVestingCreation
+    )]
+    pub authority_ata: Account<'info, TokenAccount>,
+    
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+#[derive(Accounts)]
+#[instruction(name: String)]
+pub struct CreateStreamGroup<'info> {
+    #[account(
+        seeds = [b"dao_config", authority.key().as_ref()],
+        bump = dao_config.bump,
+        has_one = authority @ MiraiError::UnauthorizedTreasuryModification
+    )]
+    pub dao_config: Account<'info, DaoConfig>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = StreamGroup::SIZE,
+        seeds = [b"stream_group", dao_config.key().as_ref(), name.as_bytes()],
+        bump
+    )]
+    pub stream_group: Account<'info, StreamGroup>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Member streams are passed as writable `remaining_accounts`
+#[derive(Accounts)]
+pub struct ManageStreamGroup<'info> {
+    #[account(
+        seeds = [b"dao_config", authority.key().as_ref()],
+        bump = dao_config.bump,
+        has_one = authority @ MiraiError::UnauthorizedStreamCreation
+    )]
+    pub dao_config: Account<'info, DaoConfig>,
+
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"stream_group", dao_config.key().as_ref(), stream_group.name.as_bytes()],
+        bump = stream_group.bump,
+        has_one = dao_config @ MiraiError::InvalidDaoConfig
+    )]
+    pub stream_group: Account<'info, StreamGroup>,
+}
+
+/// Member streams are passed as `remaining_accounts` in `[stream, stream_ata, destination, payout_allowlist,
+/// payout_split, withholding_policy]` groups, each followed by the recipient's withholding ledger if the
+/// stream has a policy and then the split's destinations if the recipient configured one.
+/// The destination is the recipient's ATA unless their allowlist names another, or the team itself
+/// for a team stream.
+#[derive(Accounts)]
+pub struct CancelStreamGroup<'info> {
+    #[account(
+        mut,
+        seeds = [b"dao_config", authority.key().as_ref()],
+        bump = dao_config.bump,
+        has_one = authority @ MiraiError::UnauthorizedStreamCreation,
+        has_one = treasury_mint @ MiraiError::InvalidMint
+    )]
+    pub dao_config: Account<'info, DaoConfig>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"stream_group", dao_config.key().as_ref(), stream_group.name.as_bytes()],
+        bump = stream_group.bump,
+        has_one = dao_config @ MiraiError::InvalidDaoConfig
+    )]
+    pub stream_group: Account<'info, StreamGroup>,
+
+    #[account(
+        mut,
+        constraint = authority_ata.mint == treasury_mint.key() @ MiraiError::InvalidMint,
+        constraint = authority_ata.owner == authority.key() @ MiraiError::UnauthorizedStreamCreation
+    )]
+    pub authority_ata: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"withholding_vault", dao_config.key().as_ref()],
+        bump
+    )]
+    pub withholding_vault: Option<Account<'info, TokenAccount>>,
+
+    pub treasury_mint: Account<'info, Mint>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct CreatePayoutAllowlist<'info> {
+    #[account(
+        seeds = [b"dao_config", dao_config.authority.as_ref()],
+        bump = dao_config.bump
+    )]
+    pub dao_config: Account<'info, DaoConfig>,
+
+    #[account(mut)]
+    pub recipient: Signer<'info>,
+
+    #[account(
+        init,
+        payer = recipient,
+        space = PayoutAllowlist::SIZE,
+        seeds = [b"payout_allowlist", dao_config.key().as_ref(), recipient.key().as_ref()],
+        bump
+    )]
+    pub payout_allowlist: Account<'info, PayoutAllowlist>,
+
+    #[account(
+        constraint = destination.mint == dao_config.treasury_mint @ MiraiError::InvalidMint
+    )]
+    pub destination: Account<'info, TokenAccount>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct QueuePayoutDestination<'info> {
+    #[account(
+        seeds = [b"dao_config", dao_config.authority.as_ref()],
+        bump = dao_config.bump
+    )]
+    pub dao_config: Account<'info, DaoConfig>,
+
+    pub recipient: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"payout_allowlist", dao_config.key().as_ref(), recipient.key().as_ref()],
+        bump = payout_allowlist.bump,
+        has_one = recipient @ MiraiError::UnauthorizedWithdrawal
+    )]
+    pub payout_allowlist: Account<'info, PayoutAllowlist>,
+
+    #[account(
+        constraint = destination.mint == dao_config.treasury_mint @ MiraiError::InvalidMint
+    )]
+    pub destination: Account<'info, TokenAccount>,
+}
+
+#[derive(Accounts)]
+pub struct RemovePayoutDestination<'info> {
+    #[account(
+        seeds = [b"dao_config", dao_config.authority.as_ref()],
+        bump = dao_config.bump
+    )]
+    pub dao_config: Account<'info, DaoConfig>,
+
+    pub recipient: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"payout_allowlist", dao_config.key().as_ref(), recipient.key().as_ref()],
+        bump = payout_allowlist.bump,
+        has_one = recipient @ MiraiError::UnauthorizedWithdrawal
+    )]
+    pub payout_allowlist: Account<'info, PayoutAllowlist>,
+}
+
+#[derive(Accounts)]
+pub struct SetStreamPayoutSplit<'info> {
+    #[account(
+        seeds = [
+            b"stream",
+            stream.dao_config.as_ref(),
+            recipient.key().as_ref()
+        ],
+        bump = stream.bump,
+        has_one = recipient @ MiraiError::UnauthorizedWithdrawal
+    )]
+    pub stream: Account<'info, Stream>,
+
+    #[account(
+        init_if_needed,
+        payer = recipient,
+        space = PayoutSplit::SIZE,
+        seeds = [b"payout_split", stream.key().as_ref()],
+        bump
+    )]
+    pub payout_split: Account<'info, PayoutSplit>,
+
+    #[account(mut)]
+    pub recipient: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetVestingPayoutSplit<'info> {
+    #[account(
+        seeds = [
+            b"vesting",
+            vesting.dao_config.as_ref(),
+            recipient.key().as_ref()
+        ],
+        bump = vesting.bump,
+        has_one = recipient @ MiraiError::UnauthorizedVestingClaim
+    )]
+    pub vesting: Account<'info, Vesting>,
+
+    #[account(
+        init_if_needed,
+        payer = recipient,
+        space = PayoutSplit::SIZE,
+        seeds = [b"payout_split", vesting.key().as_ref()],
+        bump
+    )]
+    pub payout_split: Account<'info, PayoutSplit>,
+
+    #[account(mut)]
+    pub recipient: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ClearPayoutSplit<'info> {
+    #[account(
+        mut,
+        close = recipient,
+        seeds = [b"payout_split", payout_split.position.as_ref()],
+        bump = payout_split.bump,
+        has_one = recipient @ MiraiError::UnauthorizedWithdrawal
+    )]
+    pub payout_split: Account<'info, PayoutSplit>,
+
+    #[account(mut)]
+    pub recipient: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(name: String)]
+pub struct CreateTeam<'info> {
+    #[account(
+        seeds = [b"dao_config", dao_config.authority.as_ref()],
+        bump = dao_config.bump
+    )]
+    pub dao_config: Account<'info, DaoConfig>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = Team::SIZE,
+        seeds = [b"team", dao_config.key().as_ref(), name.as_bytes()],
+        bump
+    )]
+    pub team: Account<'info, Team>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetTeamShares<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"team", team.dao_config.as_ref(), team.name.as_bytes()],
+        bump = team.bump,
+        has_one = admin @ MiraiError::UnauthorizedTeamAdmin
+    )]
+    pub team: Account<'info, Team>,
+
+    /// CHECK: The team's stream, checkpointed before the shares change if it exists yet
+    #[account(
+        seeds = [b"stream", team.dao_config.as_ref(), team.key().as_ref()],
+        bump
+    )]
+    pub stream: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawTeamShare<'info> {
+    #[account(
+        mut,
+        seeds = [b"dao_config", dao_config.authority.as_ref()],
+        bump = dao_config.bump
+    )]
+    pub dao_config: Account<'info, DaoConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"team", dao_config.key().as_ref(), team.name.as_bytes()],
+        bump = team.bump,
+        has_one = dao_config @ MiraiError::InvalidDaoConfig
+    )]
+    pub team: Account<'info, Team>,
+
+    #[account(
+        mut,
+        seeds = [b"stream", dao_config.key().as_ref(), team.key().as_ref()],
+        bump = stream.bump,
+        constraint = stream.recipient == team.key() @ MiraiError::UnauthorizedWithdrawal
+    )]
+    pub stream: Account<'info, Stream>,
+
+    #[account(
+        mut,
+        constraint = stream_ata.key() == stream.stream_ata @ MiraiError::InvalidTokenAccount
+    )]
+    pub stream_ata: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub member: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = member,
+        associated_token::mint = treasury_mint,
+        associated_token::authority = member
+    )]
+    pub member_ata: Account<'info, TokenAccount>,
+
+    /// CHECK: Only applied once the DAO has set a withholding rate on this position
+    #[account(
+        seeds = [b"withholding", stream.key().as_ref()],
+        bump
+    )]
+    pub withholding_policy: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"withholding_vault", dao_config.key().as_ref()],
+        bump
+    )]
+    pub withholding_vault: Option<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"withholding_ledger",
+            dao_config.key().as_ref(),
+            member.key().as_ref(),
+            &withholding_ledger.year.to_le_bytes()
+        ],
+        bump = withholding_ledger.bump
+    )]
+    pub withholding_ledger: Option<Account<'info, WithholdingLedger>>,
+
+    #[account(
+        constraint = treasury_mint.key() == dao_config.treasury_mint @ MiraiError::InvalidMint
+    )]
+    pub treasury_mint: Account<'info, Mint>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+#[derive(Accounts)]
+pub struct InitWithholdingVault<'info> {
+    #[account(
+        seeds = [b"dao_config", authority.key().as_ref()],
+        bump = dao_config.bump,
+        has_one = authority @ MiraiError::UnauthorizedTreasuryModification,
+        has_one = treasury_mint @ MiraiError::InvalidMint
+    )]
+    pub dao_config: Account<'info, DaoConfig>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        seeds = [b"withholding_vault", dao_config.key().as_ref()],
+        bump,
+        token::mint = treasury_mint,
+        token::authority = dao_config
+    )]
+    pub withholding_vault: Account<'info, TokenAccount>,
+
+    pub treasury_mint: Account<'info, Mint>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct SetStreamWithholding<'info> {
+    #[account(
+        seeds = [b"dao_config", authority.key().as_ref()],
+        bump = dao_config.bump,
+        has_one = authority @ MiraiError::UnauthorizedTreasuryModification
+    )]
+    pub dao_config: Account<'info, DaoConfig>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        has_one = dao_config @ MiraiError::InvalidDaoConfig
+    )]
+    pub stream: Account<'info, Stream>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = WithholdingPolicy::SIZE,
+        seeds = [b"withholding", stream.key().as_ref()],
+        bump
+    )]
+    pub withholding_policy: Account<'info, WithholdingPolicy>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetVestingWithholding<'info> {
+    #[account(
+        seeds = [b"dao_config", authority.key().as_ref()],
+        bump = dao_config.bump,
+        has_one = authority @ MiraiError::UnauthorizedTreasuryModification
+    )]
+    pub dao_config: Account<'info, DaoConfig>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        has_one = dao_config @ MiraiError::InvalidDaoConfig
+    )]
+    pub vesting: Account<'info, Vesting>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = WithholdingPolicy::SIZE,
+        seeds = [b"withholding", vesting.key().as_ref()],
+        bump
+    )]
+    pub withholding_policy: Account<'info, WithholdingPolicy>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ClearWithholding<'info> {
+    #[account(
+        seeds = [b"dao_config", authority.key().as_ref()],
+        bump = dao_config.bump,
+        has_one = authority @ MiraiError::UnauthorizedTreasuryModification
+    )]
+    pub dao_config: Account<'info, DaoConfig>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"withholding", withholding_policy.position.as_ref()],
+        bump = withholding_policy.bump,
+        has_one = dao_config @ MiraiError::InvalidDaoConfig
+    )]
+    pub withholding_policy: Account<'info, WithholdingPolicy>,
+}
+
+#[derive(Accounts)]
+#[instruction(year: u16)]
+pub struct OpenWithholdingLedger<'info> {
+    #[account(
+        seeds = [b"dao_config", dao_config.authority.as_ref()],
+        bump = dao_config.bump
+    )]
+    pub dao_config: Account<'info, DaoConfig>,
+
+    /// CHECK: Recipient the ledger tracks; any payer may open it
+    pub recipient: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = WithholdingLedger::SIZE,
+        seeds = [
+            b"withholding_ledger",
+            dao_config.key().as_ref(),
+            recipient.key().as_ref(),
+            &year.to_le_bytes()
+        ],
+        bump
+    )]
+    pub withholding_ledger: Account<'info, WithholdingLedger>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RemitWithholding<'info> {
+    #[account(
+        seeds = [b"dao_config", authority.key().as_ref()],
+        bump = dao_config.bump,
+        has_one = authority @ MiraiError::UnauthorizedTreasuryModification
+    )]
+    pub dao_config: Account<'info, DaoConfig>,
+
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"withholding_vault", dao_config.key().as_ref()],
+        bump
+    )]
+    pub withholding_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = destination.mint == dao_config.treasury_mint @ MiraiError::InvalidMint
+    )]
+    pub destination: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct RevokeVesting<'info> {
+    #[account(
+        mut,
+        seeds = [b"dao_config", authority.key().as_ref()],
+        bump = dao_config.bump,
+        has_one = authority @ MiraiError::UnauthorizedVestingCreation
+    )]
+    pub dao_config: Account<'info, DaoConfig>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"vesting",
+            dao_config.key().as_ref(),
+            vesting.recipient.as_ref()
+        ],
+        bump = vesting.bump,
+        has_one = dao_config @ MiraiError::InvalidDaoConfig
+    )]
+    pub vesting: Account<'info, Vesting>,
+
+    #[account(
+        mut,
+        constraint = vesting_ata.key() == vesting.vesting_ata @ MiraiError::InvalidTokenAccount
+    )]
+    pub vesting_ata: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = authority_ata.mint == dao_config.treasury_mint @ MiraiError::InvalidMint,
+        constraint = authority_ata.owner == authority.key() @ MiraiError::UnauthorizedVestingCreation
+    )]
+    pub authority_ata: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+
+    #[account(
+        mut,
+        has_one = dao_config @ MiraiError::InvalidDaoConfig
+    )]
+    pub vesting_pool: Option<Account<'info, VestingPool>>,
+
+    #[account(mut)]
+    pub pool_ata: Option<Account<'info, TokenAccount>>,
+}
+
+#[derive(Accounts)]
+pub struct TerminateVesting<'info> {
+    #[account(
+        mut,
+        seeds = [b"dao_config", authority.key().as_ref()],
+        bump = dao_config.bump,
+        has_one = authority @ MiraiError::UnauthorizedVestingCreation
+    )]
+    pub dao_config: Account<'info, DaoConfig>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"vesting",
+            dao_config.key().as_ref(),
+            vesting.recipient.as_ref()
+        ],
+        bump = vesting.bump,
+        has_one = dao_config @ MiraiError::InvalidDaoConfig
+    )]
+    pub vesting: Account<'info, Vesting>,
+
+    #[account(
+        mut,
+        constraint = vesting_ata.key() == vesting.vesting_ata @ MiraiError::InvalidTokenAccount
+    )]
+    pub vesting_ata: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = authority_ata.mint == dao_config.treasury_mint @ MiraiError::InvalidMint,
+        constraint = authority_ata.owner == authority.key() @ MiraiError::UnauthorizedVestingCreation
+    )]
+    pub authority_ata: Account<'info, TokenAccount>,
+
+    #[account(
+        seeds = [
+            b"acceleration_event",
+            dao_config.key().as_ref(),
+            &acceleration_event.index.to_le_bytes()
+        ],
+        bump = acceleration_event.bump
+    )]
+    pub acceleration_event: Option<Account<'info, AccelerationEvent>>,
+
+    pub token_program: Program<'info, Token>,
+
+    #[account(
+        mut,
+        has_one = dao_config @ MiraiError::InvalidDaoConfig
+    )]
+    pub vesting_pool: Option<Account<'info, VestingPool>>,
+
+    #[account(mut)]
+    pub pool_ata: Option<Account<'info, TokenAccount>>,
+}
+
+#[derive(Accounts)]
+pub struct AddVestingTranche<'info> {
+    #[account(
+        mut,
+        seeds = [b"dao_config", authority.key().as_ref()],
+        bump = dao_config.bump,
+        has_one = authority @ MiraiError::UnauthorizedVestingCreation
+    )]
+    pub dao_config: Account<'info, DaoConfig>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"vesting",
+            dao_config.key().as_ref(),
+            vesting.recipient.as_ref()
+        ],
+        bump = vesting.bump,
+        has_one = dao_config @ MiraiError::InvalidDaoConfig
+    )]
+    pub vesting: Account<'info, Vesting>,
+
+    #[account(
+        mut,
+        constraint = vesting_ata.key() == vesting.vesting_ata @ MiraiError::InvalidTokenAccount
+    )]
+    pub vesting_ata: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = authority_ata.mint == dao_config.treasury_mint @ MiraiError::InvalidMint,
+        constraint = authority_ata.owner == authority.key() @ MiraiError::UnauthorizedVestingCreation
+    )]
+    pub authority_ata: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateDaoConfig<'info> {
+    /// CHECK: Loaded by hand, since a DAO created before the latest layout cannot deserialize
+    #[account(
+        mut,
+        seeds = [b"dao_config", authority.key().as_ref()],
+        bump
+    )]
+    pub dao_config: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetMaxBackdatePeriod<'info> {
+    #[account(
+        mut,
+        seeds = [b"dao_config", authority.key().as_ref()],
+        bump = dao_config.bump,
+        has_one = authority @ MiraiError::UnauthorizedTreasuryModification
+    )]
+    pub dao_config: Account<'info, DaoConfig>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetAccelerationTerms<'info> {
+    #[account(
+        seeds = [b"dao_config", authority.key().as_ref()],
+        bump = dao_config.bump,
+        has_one = authority @ MiraiError::UnauthorizedVestingCreation
+    )]
+    pub dao_config: Account<'info, DaoConfig>,
+
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"vesting",
+            dao_config.key().as_ref(),
+            vesting.recipient.as_ref()
+        ],
+        bump = vesting.bump,
+        has_one = dao_config @ MiraiError::InvalidDaoConfig
+    )]
+    pub vesting: Account<'info, Vesting>,
+}
+
+#[derive(Accounts)]
+pub struct TriggerAcceleration<'info> {
+    #[account(
+        mut,
+        seeds = [b"dao_config", authority.key().as_ref()],
+        bump = dao_config.bump,
+        has_one = authority @ MiraiError::UnauthorizedTreasuryModification
+    )]
+    pub dao_config: Account<'info, DaoConfig>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = AccelerationEvent::SIZE,
+        seeds = [
+            b"acceleration_event",
+            dao_config.key().as_ref(),
+            &dao_config.acceleration_event_count.to_le_bytes()
+        ],
+        bump
+    )]
+    pub acceleration_event: Account<'info, AccelerationEvent>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ApplyAcceleration<'info> {
+    #[account(
+        seeds = [
+            b"acceleration_event",
+            vesting.dao_config.as_ref(),
+            &acceleration_event.index.to_le_bytes()
+        ],
+        bump = acceleration_event.bump
+    )]
+    pub acceleration_event: Account<'info, AccelerationEvent>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"vesting",
+            vesting.dao_config.as_ref(),
+            vesting.recipient.as_ref()
+        ],
+        bump = vesting.bump
+    )]
+    pub vesting: Account<'info, Vesting>,
+}
+
+#[derive(Accounts)]
+pub struct MergeVestings<'info> {
+    #[account(
+        mut,
+        seeds = [b"dao_config", dao_config.authority.as_ref()],
+        bump = dao_config.bump
+    )]
+    pub dao_config: Account<'info, DaoConfig>,
+
+    #[account(
+        mut,
+        close = source_recipient,
+        seeds = [
+            b"vesting",
+            dao_config.key().as_ref(),
+            source_recipient.key().as_ref()
+        ],
+        bump = source_vesting.bump,
+        has_one = dao_config @ MiraiError::InvalidDaoConfig
+    )]
+    pub source_vesting: Account<'info, Vesting>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"vesting",
+            dao_config.key().as_ref(),
+            target_recipient.key().as_ref()
+        ],
+        bump = target_vesting.bump,
+        has_one = dao_config @ MiraiError::InvalidDaoConfig
+    )]
+    pub target_vesting: Account<'info, Vesting>,
+
+    #[account(mut)]
+    pub source_recipient: Signer<'info>,
+
+    pub target_recipient: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = source_vesting_ata.key() == source_vesting.vesting_ata @ MiraiError::InvalidTokenAccount
+    )]
+    pub source_vesting_ata: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = target_vesting_ata.key() == target_vesting.vesting_ata @ MiraiError::InvalidTokenAccount
+    )]
+    pub target_vesting_ata: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct SplitVesting<'info> {
+    #[account(
+        mut,
+        seeds = [b"dao_config", dao_config.authority.as_ref()],
+        bump = dao_config.bump
+    )]
+    pub dao_config: Account<'info, DaoConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"vesting",
+            dao_config.key().as_ref(),
+            recipient.key().as_ref()
+        ],
+        bump = vesting.bump,
+        has_one = recipient @ MiraiError::UnauthorizedVestingClaim,
+        has_one = dao_config @ MiraiError::InvalidDaoConfig
+    )]
+    pub vesting: Account<'info, Vesting>,
+
+    #[account(mut)]
+    pub recipient: Signer<'info>,
+
+    /// CHECK: Any account can receive a carved-out position
+    pub new_recipient: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = recipient,
+        space = Vesting::SIZE,
+        seeds = [
+            b"vesting",
+            dao_config.key().as_ref(),
+            new_recipient.key().as_ref()
+        ],
+        bump
+    )]
+    pub new_vesting: Account<'info, Vesting>,
+
+    #[account(
+        mut,
+        constraint = vesting_ata.key() == vesting.vesting_ata @ MiraiError::InvalidTokenAccount
+    )]
+    pub vesting_ata: Account<'info, TokenAccount>,
+
+    #[account(
+        init,
+        payer = recipient,
+        associated_token::mint = treasury_mint,
+        associated_token::authority = new_vesting
+    )]
+    pub new_vesting_ata: Account<'info, TokenAccount>,
+
+    #[account(
+        constraint = treasury_mint.key() == dao_config.treasury_mint @ MiraiError::InvalidMint
+    )]
+    pub treasury_mint: Account<'info, Mint>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+#[derive(Accounts)]
+#[instruction(name: String)]
+pub struct CreateVestingPool<'info> {
+    #[account(
+        seeds = [b"dao_config", authority.key().as_ref()],
+        bump = dao_config.bump,
+        has_one = authority @ MiraiError::UnauthorizedTreasuryModification,
+        has_one = treasury_mint @ MiraiError::InvalidMint
+    )]
+    pub dao_config: Account<'info, DaoConfig>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = VestingPool::SIZE,
+        seeds = [b"vesting_pool", dao_config.key().as_ref(), name.as_bytes()],
+        bump
+    )]
+    pub vesting_pool: Account<'info, VestingPool>,
+
+    #[account(
+        init,
+        payer = authority,
+        associated_token::mint = treasury_mint,
+        associated_token::authority = vesting_pool
+    )]
+    pub pool_ata: Account<'info, TokenAccount>,
+
+    pub treasury_mint: Account<'info, Mint>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+#[derive(Accounts)]
+pub struct FundVestingPool<'info> {
+    #[account(
+        mut,
+        seeds = [b"dao_config", authority.key().as_ref()],
+        bump = dao_config.bump,
+        has_one = authority @ MiraiError::UnauthorizedTreasuryModification
+    )]
+    pub dao_config: Account<'info, DaoConfig>,
+
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"vesting_pool", dao_config.key().as_ref(), vesting_pool.name.as_bytes()],
+        bump = vesting_pool.bump,
+        has_one = dao_config @ MiraiError::InvalidDaoConfig,
+        has_one = pool_ata @ MiraiError::InvalidVestingPool
+    )]
+    pub vesting_pool: Account<'info, VestingPool>,
+
+    #[account(mut)]
+    pub pool_ata: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = authority_ata.mint == dao_config.treasury_mint @ MiraiError::InvalidMint,
+        constraint = authority_ata.owner == authority.key() @ MiraiError::UnauthorizedTreasuryModification
+    )]
+    pub authority_ata: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct ReportVestingPool<'info> {
+    #[account(
+        seeds = [b"vesting_pool", vesting_pool.dao_config.as_ref(), vesting_pool.name.as_bytes()],
+        bump = vesting_pool.bump
+    )]
+    pub vesting_pool: Account<'info, VestingPool>,
+}
+
+#[derive(Accounts)]
+pub struct ExerciseOption<'info> {
+    #[account(
+        mut,
+        seeds = [b"dao_config", dao_config.authority.as_ref()],
+        bump = dao_config.bump
+    )]
+    pub dao_config: Account<'info, DaoConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"vesting",
+            dao_config.key().as_ref(),
+            recipient.key().as_ref()
+        ],
+        bump = vesting.bump,
+        has_one = recipient @ MiraiError::UnauthorizedVestingClaim,
+        has_one = dao_config @ MiraiError::InvalidDaoConfig
+    )]
+    pub vesting: Account<'info, Vesting>,
+
+    #[account(mut)]
+    pub recipient: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = vesting_ata.key() == vesting.vesting_ata @ MiraiError::InvalidTokenAccount
+    )]
+    pub vesting_ata: Account<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = recipient,
+        associated_token::mint = treasury_mint,
+        associated_token::authority = recipient
+    )]
+    pub recipient_ata: Account<'info, TokenAccount>,
+
+    /// CHECK: Only enforced once the recipient has created a payout allowlist
+    #[account(
+        seeds = [b"payout_allowlist", dao_config.key().as_ref(), recipient.key().as_ref()],
+        bump
+    )]
+    pub payout_allowlist: UncheckedAccount<'info>,
+
+    /// CHECK: Only applied once the recipient has configured a payout split
+    #[account(
+        seeds = [b"payout_split", vesting.key().as_ref()],
+        bump
+    )]
+    pub payout_split: UncheckedAccount<'info>,
+
+    /// CHECK: Only applied once the DAO has set a withholding rate on this position
+    #[account(
+        seeds = [b"withholding", vesting.key().as_ref()],
+        bump
+    )]
+    pub withholding_policy: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"withholding_vault", dao_config.key().as_ref()],
+        bump
+    )]
+    pub withholding_vault: Option<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"withholding_ledger",
+            dao_config.key().as_ref(),
+            recipient.key().as_ref(),
+            &withholding_ledger.year.to_le_bytes()
+        ],
+        bump = withholding_ledger.bump
+    )]
+    pub withholding_ledger: Option<Account<'info, WithholdingLedger>>,
+
+    #[account(
+        mut,
+        constraint = payout_destination.mint == treasury_mint.key() @ MiraiError::InvalidMint
+    )]
+    pub payout_destination: Option<Account<'info, TokenAccount>>,
+
+    #[account(
+        constraint = vesting.option_terms.as_ref().is_some_and(|terms| terms.strike_mint == strike_mint.key()) @ MiraiError::InvalidMint
+    )]
+    pub strike_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        constraint = recipient_strike_ata.mint == strike_mint.key() @ MiraiError::InvalidMint,
+        constraint = recipient_strike_ata.owner == recipient.key() @ MiraiError::InvalidTokenAccount
+    )]
+    pub recipient_strike_ata: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = treasury_strike_ata.mint == strike_mint.key() @ MiraiError::InvalidMint,
+        constraint = treasury_strike_ata.owner == dao_config.authority @ MiraiError::InvalidTokenAccount
+    )]
+    pub treasury_strike_ata: Account<'info, TokenAccount>,
+
+    #[account(
+        constraint = treasury_mint.key() == dao_config.treasury_mint @ MiraiError::InvalidMint
+    )]
+    pub treasury_mint: Account<'info, Mint>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+#[derive(Accounts)]
+#[instruction(name: String)]
+pub struct CreateMilestone<'info> {
+    #[account(
+        seeds = [b"dao_config", authority.key().as_ref()],
+        bump = dao_config.bump,
+        has_one = authority @ MiraiError::UnauthorizedTreasuryModification
+    )]
+    pub dao_config: Account<'info, DaoConfig>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = Milestone::SIZE,
+        seeds = [b"milestone", dao_config.key().as_ref(), name.as_bytes()],
+        bump
+    )]
+    pub milestone: Account<'info, Milestone>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AttestMilestone<'info> {
+    #[account(
+        mut,
+        seeds = [b"milestone", milestone.dao_config.as_ref(), milestone.name.as_bytes()],
+        bump = milestone.bump,
+        has_one = attester @ MiraiError::UnauthorizedAttester
+    )]
+    pub milestone: Account<'info, Milestone>,
+
+    pub attester: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetVestingMilestones<'info> {
+    #[account(
+        seeds = [b"dao_config", authority.key().as_ref()],
+        bump = dao_config.bump,
+        has_one = authority @ MiraiError::UnauthorizedVestingCreation
+    )]
+    pub dao_config: Account<'info, DaoConfig>,
+
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"vesting",
+            dao_config.key().as_ref(),
+            vesting.recipient.as_ref()
+        ],
+        bump = vesting.bump,
+        has_one = dao_config @ MiraiError::InvalidDaoConfig
+    )]
+    pub vesting: Account<'info, Vesting>,
+}
+
+#[derive(Accounts)]
+pub struct SyncVestingMilestones<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"vesting",
+            vesting.dao_config.as_ref(),
+            vesting.recipient.as_ref()
+        ],
+        bump = vesting.bump
+    )]
+    pub vesting: Account<'info, Vesting>,
+}
+
+#[derive(Accounts)]
+pub struct ReclaimMissedMilestone<'info> {
+    #[account(
+        mut,
+        seeds = [b"dao_config", authority.key().as_ref()],
+        bump = dao_config.bump,
+        has_one = authority @ MiraiError::UnauthorizedVestingCreation
+    )]
+    pub dao_config: Account<'info, DaoConfig>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"vesting",
+            dao_config.key().as_ref(),
+            vesting.recipient.as_ref()
+        ],
+        bump = vesting.bump,
+        has_one = dao_config @ MiraiError::InvalidDaoConfig
+    )]
+    pub vesting: Account<'info, Vesting>,
+
+    #[account(
+        has_one = dao_config @ MiraiError::InvalidMilestone
+    )]
+    pub milestone: Account<'info, Milestone>,
+
+    #[account(
+        mut,
+        constraint = vesting_ata.key() == vesting.vesting_ata @ MiraiError::InvalidTokenAccount
+    )]
+    pub vesting_ata: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = authority_ata.mint == dao_config.treasury_mint @ MiraiError::InvalidMint,
+        constraint = authority_ata.owner == authority.key() @ MiraiError::UnauthorizedVestingCreation
+    )]
+    pub authority_ata: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+
+    #[account(
+        mut,
+        has_one = dao_config @ MiraiError::InvalidDaoConfig
+    )]
+    pub vesting_pool: Option<Account<'info, VestingPool>>,
+
+    #[account(mut)]
+    pub pool_ata: Option<Account<'info, TokenAccount>>,
+}
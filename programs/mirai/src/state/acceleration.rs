@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+/// Change of control declared by the DAO, which unlocks acceleration on affected vestings
+#[account]
+pub struct AccelerationEvent {
+    /// DAO configuration that declared the event
+    pub dao_config: Pubkey,
+    /// Position among the DAO's events, which seeds the PDA
+    pub index: u32,
+    /// Timestamp when the event was triggered
+    pub triggered_at: i64,
+    /// Bump seed for the PDA
+    pub bump: u8,
+}
+
+impl AccelerationEvent {
+    /// Size of the account in bytes
+    pub const SIZE: usize = 8 + // discriminator
+        32 +
+        4 +
+        8 +
+        1;
+}
@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+use crate::errors::MiraiError;
+
+/// Destinations a recipient has registered for their payouts. New destinations only
+/// become usable after `change_delay`, so a compromised key cannot redirect funds at once.
+#[account]
+pub struct PayoutAllowlist {
+    /// DAO configuration this allowlist applies to
+    pub dao_config: Pubkey,
+    /// Recipient whose payouts are restricted
+    pub recipient: Pubkey,
+    /// Token accounts payouts may be sent to
+    pub destinations: Vec<Pubkey>,
+    /// Destination waiting for the change delay to pass
+    pub pending_destination: Option<Pubkey>,
+    /// Timestamp from which the pending destination may be used
+    pub pending_effective_at: i64,
+    /// Seconds a new destination waits before it may be used, fixed at creation
+    pub change_delay: i64,
+    /// Bump seed for the PDA
+    pub bump: u8,
+    /// Timestamp when the allowlist was created
+    pub created_at: i64,
+}
+
+impl PayoutAllowlist {
+    /// Maximum number of registered destinations
+    pub const MAX_DESTINATIONS: usize = 4;
+
+    /// Size of the account in bytes
+    pub const SIZE: usize = 8 + // discriminator
+        32 +
+        32 +
+        4 + 32 * Self::MAX_DESTINATIONS +
+        1 + 32 +
+        8 +
+        8 +
+        1 +
+        8;
+
+    /// Load the allowlist at `account_info`, or `None` if the recipient never created one
+    pub fn load(account_info: &AccountInfo) -> Result<Option<PayoutAllowlist>> {
+        if account_info.owner != &crate::ID || account_info.data_is_empty() {
+            return Ok(None);
+        }
+        let data = account_info.try_borrow_data()?;
+        Ok(Some(PayoutAllowlist::try_deserialize(&mut &data[..])?))
+    }
+
+    /// Check if payouts may be sent to `destination` at `current_time`
+    pub fn is_allowed(&self, destination: &Pubkey, current_time: i64) -> bool {
+        self.destinations.contains(destination) ||
+            (self.pending_destination == Some(*destination) && current_time >= self.pending_effective_at)
+    }
+
+    /// Move the pending destination into the allowlist once its delay has passed
+    pub fn promote_pending(&mut self, current_time: i64) -> Result<()> {
+        if let Some(destination) = self.pending_destination {
+            if current_time >= self.pending_effective_at {
+                require!(self.destinations.len() < Self::MAX_DESTINATIONS, MiraiError::AllowlistFull);
+                self.destinations.push(destination);
+                self.pending_destination = None;
+            }
+        }
+        Ok(())
+    }
+
+    /// Queue `destination`, replacing any destination still waiting for its delay
+    pub fn queue(&mut self, destination: Pubkey, current_time: i64) -> Result<()> {
+        self.promote_pending(current_time)?;
+        require!(!self.destinations.contains(&destination), MiraiError::DestinationAlreadyAllowed);
+        require!(self.destinations.len() < Self::MAX_DESTINATIONS, MiraiError::AllowlistFull);
+
+        self.pending_destination = Some(destination);
+        self.pending_effective_at = current_time.saturating_add(self.change_delay);
+        Ok(())
+    }
+
+    /// Remove `destination` from the allowlist or cancel it if still pending
+    pub fn remove(&mut self, destination: &Pubkey) -> Result<()> {
+        if self.pending_destination == Some(*destination) {
+            self.pending_destination = None;
+            return Ok(());
+        }
+        let index = self.destinations
+            .iter()
+            .position(|allowed| allowed == destination)
+            .ok_or(MiraiError::DestinationNotFound)?;
+        self.destinations.remove(index);
+        Ok(())
+    }
+}
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use crate::errors::MiraiError;
 
 /// Payment category for organizing treasury streams
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
@@ -11,6 +12,83 @@ pub enum PaymentCategory {
     Other,
 }
 
+/// Kind of payment position an account-agnostic instruction is acting on
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub enum PositionKind {
+    Stream,
+    Vesting,
+}
+
+/// Cancellation and transfer rights fixed when a stream or vesting is created
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, PartialEq, Eq, Debug)]
+pub struct PositionTerms {
+    /// Whether the DAO authority can cancel the position
+    pub cancelable_by_authority: bool,
+    /// Whether the recipient can cancel the position
+    pub cancelable_by_recipient: bool,
+    /// Whether the recipient can transfer the position to another recipient
+    pub transferable: bool,
+}
+
+impl PositionTerms {
+    /// Size of the struct in bytes
+    pub const SIZE: usize = 1 + 1 + 1;
+
+    /// Require that the DAO authority is allowed to cancel
+    pub fn require_cancelable_by_authority(&self) -> Result<()> {
+        require!(self.cancelable_by_authority, MiraiError::NotCancelableByAuthority);
+        Ok(())
+    }
+
+    /// Require that the recipient is allowed to cancel
+    pub fn require_cancelable_by_recipient(&self) -> Result<()> {
+        require!(self.cancelable_by_recipient, MiraiError::NotCancelableByRecipient);
+        Ok(())
+    }
+
+    /// Require that the position can be transferred
+    pub fn require_transferable(&self) -> Result<()> {
+        require!(self.transferable, MiraiError::NotTransferable);
+        Ok(())
+    }
+}
+
+/// Conditions under which unclaimed funds can be swept back to the treasury
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, PartialEq, Eq, Debug)]
+pub struct ExpiryPolicy {
+    /// Timestamp after which the unclaimed balance can be swept
+    pub claim_deadline: Option<i64>,
+    /// Seconds without a withdrawal, counted from the end of the schedule at the earliest,
+    /// after which the unclaimed balance can be swept
+    pub inactivity_period: Option<i64>,
+}
+
+impl ExpiryPolicy {
+    /// Size of the struct in bytes
+    pub const SIZE: usize = 1 + 8 + 1 + 8;
+
+    /// Validate the policy against the schedule it applies to
+    pub fn validate(&self, end_time: i64) -> Result<()> {
+        if let Some(claim_deadline) = self.claim_deadline {
+            require!(claim_deadline > end_time, MiraiError::InvalidExpiryPolicy);
+        }
+        if let Some(inactivity_period) = self.inactivity_period {
+            require!(inactivity_period > 0, MiraiError::InvalidExpiryPolicy);
+        }
+        Ok(())
+    }
+
+    /// Check if the unclaimed balance can be swept at `current_time`
+    pub fn has_expired(&self, last_activity_at: i64, current_time: i64) -> bool {
+        let past_deadline = matches!(self.claim_deadline, Some(deadline) if current_time > deadline);
+        let inactive = matches!(
+            self.inactivity_period,
+            Some(period) if current_time > last_activity_at.saturating_add(period)
+        );
+        past_deadline || inactive
+    }
+}
+
 /// Governance settings for the DAO treasury
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct GovernanceSettings {
@@ -43,6 +121,13 @@ pub struct DaoConfig {
     pub bump: u8,
     /// Timestamp when the DAO was created
     pub created_at: i64,
+    /// Maximum look-back for backdated vesting start times, zero disables backdating.
+    /// Kept last so DAOs created before it can grow into it with `migrate_dao_config`.
+    pub max_backdate_period: i64,
+    /// Number of changes of control the DAO has declared, which seeds the next event
+    pub acceleration_event_count: u32,
+    /// Timestamp of the latest change of control, if any
+    pub last_acceleration_at: Option<i64>,
 }
 
 impl DaoConfig {
@@ -58,7 +143,10 @@ impl DaoConfig {
         8 +  
         8 +  
         1 +  
-        8;   
+        8 +  
+        8 +  
+        4 +  
+        1 + 8;   
 
     /// Initialize governance settings with default values
     pub fn init_governance_settings() -> GovernanceSettings {
@@ -85,12 +173,38 @@ impl DaoConfig {
         self.total_allocated.saturating_add(new_allocation) <= self.governance_settings.max_total_allocation
     }
 
+    /// Validate a start time at or before now against the backdating look-back
+    pub fn validate_backdate(&self, start_time: i64, current_time: i64) -> bool {
+        self.max_backdate_period > 0 && start_time >= current_time.saturating_sub(self.max_backdate_period)
+    }
+
     /// Update treasury statistics when creating a stream
     pub fn add_stream(&mut self, amount: u64) {
         self.total_streams = self.total_streams.saturating_add(1);
         self.total_allocated = self.total_allocated.saturating_add(amount);
     }
 
+    /// Update treasury statistics when a position is folded into another
+    pub fn remove_stream(&mut self) {
+        self.total_streams = self.total_streams.saturating_sub(1);
+    }
+
+    /// Reserve allocation committed outside of a single position
+    pub fn reserve_allocation(&mut self, amount: u64) {
+        self.total_allocated = self.total_allocated.saturating_add(amount);
+    }
+
+    /// Record a change of control declared at `triggered_at`
+    pub fn record_acceleration_event(&mut self, triggered_at: i64) {
+        self.acceleration_event_count = self.acceleration_event_count.saturating_add(1);
+        self.last_acceleration_at = Some(triggered_at);
+    }
+
+    /// Release allocation returned to the authority
+    pub fn release_allocation(&mut self, amount: u64) {
+        self.total_allocated = self.total_allocated.saturating_sub(amount);
+    }
+
     /// Update treasury statistics when redeeming from a stream
     pub fn add_payment(&mut self, amount: u64) {
         self.total_paid = self.total_paid.saturating_add(amount);
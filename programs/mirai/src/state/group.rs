@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+use crate::errors::MiraiError;
+use crate::state::stream::Stream;
+
+/// Group of streams managed together, such as a grants round or a fellowship cohort
+#[account]
+pub struct StreamGroup {
+    /// DAO configuration this group belongs to
+    pub dao_config: Pubkey,
+    /// Name used to derive the group address
+    pub name: String,
+    /// Number of streams attached to the group
+    pub member_count: u32,
+    /// Number of member streams cancelled through the group
+    pub cancelled_count: u32,
+    /// Total amount funded into member streams, including renewals and rate changes
+    pub total_amount: u64,
+    /// Total amount settled to recipients when members were cancelled
+    pub total_settled: u64,
+    /// Total amount refunded to the treasury when members were cancelled
+    pub total_refunded: u64,
+    /// Bump seed for the PDA
+    pub bump: u8,
+    /// Timestamp when the group was created
+    pub created_at: i64,
+}
+
+impl StreamGroup {
+    /// Maximum length of a group name, bounded by the PDA seed limit
+    pub const MAX_NAME_LEN: usize = 32;
+
+    /// Size of the account in bytes
+    pub const SIZE: usize = 8 + // discriminator
+        32 +
+        4 + Self::MAX_NAME_LEN +
+        4 +
+        4 +
+        8 +
+        8 +
+        8 +
+        1 +
+        8;
+
+    /// Record a stream attached to the group at creation
+    pub fn add_member(&mut self, amount: u64) {
+        self.member_count = self.member_count.saturating_add(1);
+        self.total_amount = self.total_amount.saturating_add(amount);
+    }
+
+    /// Record a change in a member stream's funding after it was attached
+    pub fn record_funding_change(&mut self, old_amount: u64, new_amount: u64) {
+        self.total_amount = self.total_amount.saturating_add(new_amount).saturating_sub(old_amount);
+    }
+
+    /// Record a funding change on the group of a stream, which must be passed if it has one
+    pub fn sync_member_funding(
+        stream_group: Option<&mut Account<StreamGroup>>,
+        group: Option<Pubkey>,
+        old_amount: u64,
+        new_amount: u64,
+    ) -> Result<()> {
+        match stream_group {
+            Some(stream_group) => stream_group.record_funding_change(old_amount, new_amount),
+            None => require!(group.is_none(), MiraiError::InvalidGroupMember),
+        }
+        Ok(())
+    }
+
+    /// Record a member stream cancelled through the group
+    pub fn record_cancellation(&mut self, settled_amount: u64, refunded_amount: u64) {
+        self.cancelled_count = self.cancelled_count.saturating_add(1);
+        self.total_settled = self.total_settled.saturating_add(settled_amount);
+        self.total_refunded = self.total_refunded.saturating_add(refunded_amount);
+    }
+
+    /// Load a member stream passed through `remaining_accounts`
+    pub fn load_member<'info>(
+        group_key: &Pubkey,
+        account_info: &'info AccountInfo<'info>,
+    ) -> Result<Account<'info, Stream>> {
+        require!(account_info.is_writable, MiraiError::InvalidGroupMember);
+        let stream: Account<'info, Stream> = Account::try_from(account_info)?;
+        require!(stream.group == Some(*group_key), MiraiError::InvalidGroupMember);
+        Ok(stream)
+    }
+}
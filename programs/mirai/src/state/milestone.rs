@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+use crate::errors::MiraiError;
+
+/// Milestone a vesting is gated on, such as a mainnet launch, marked achieved by its attester
+#[account]
+pub struct Milestone {
+    /// DAO configuration this milestone belongs to
+    pub dao_config: Pubkey,
+    /// Name used to derive the milestone address
+    pub name: String,
+    /// Account allowed to attest that the milestone was achieved
+    pub attester: Pubkey,
+    /// Last timestamp at which the milestone can be achieved
+    pub deadline: i64,
+    /// Timestamp when the attester marked the milestone achieved
+    pub achieved_at: Option<i64>,
+    /// Bump seed for the PDA
+    pub bump: u8,
+    /// Timestamp when the milestone was created
+    pub created_at: i64,
+}
+
+impl Milestone {
+    /// Maximum length of a milestone name, bounded by the PDA seed limit
+    pub const MAX_NAME_LEN: usize = 32;
+
+    /// Size of the account in bytes
+    pub const SIZE: usize = 8 + // discriminator
+        32 +
+        4 + Self::MAX_NAME_LEN +
+        32 +
+        8 +
+        1 + 8 +
+        1 +
+        8;
+
+    /// Mark the milestone achieved at `current_time`
+    pub fn attest(&mut self, current_time: i64) -> Result<()> {
+        require!(self.achieved_at.is_none(), MiraiError::MilestoneAlreadyAchieved);
+        require!(current_time <= self.deadline, MiraiError::MilestoneDeadlinePassed);
+        self.achieved_at = Some(current_time);
+        Ok(())
+    }
+
+    /// Whether the deadline passed without the milestone being achieved
+    pub fn is_missed(&self, current_time: i64) -> bool {
+        self.achieved_at.is_none() && current_time > self.deadline
+    }
+}
+
+/// Share of a vesting gated on a milestone, as requested by the DAO
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct MilestoneShare {
+    /// Milestone gating the share
+    pub milestone: Pubkey,
+    /// Share of the time-vested amount unlocked by the milestone, in basis points
+    pub unlock_bps: u16,
+}
+
+/// Milestone gate stored on a vesting
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct MilestoneGate {
+    /// Milestone gating the share
+    pub milestone: Pubkey,
+    /// Share of the time-vested amount unlocked by the milestone, in basis points
+    pub unlock_bps: u16,
+    /// Whether the milestone has been achieved, synced from the milestone account
+    pub achieved: bool,
+}
+
+impl MilestoneGate {
+    /// Size of the struct in bytes
+    pub const SIZE: usize = 32 + 2 + 1;
+}
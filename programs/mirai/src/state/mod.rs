@@ -1,7 +1,29 @@
 pub mod config;
 pub mod stream;
 pub mod vesting;
+pub mod operator;
+pub mod template;
+pub mod group;
+pub mod allowlist;
+pub mod split;
+pub mod team;
+pub mod withholding;
+pub mod acceleration;
+pub mod pool;
+pub mod milestone;
+pub mod payout;
 
 pub use config::*;
 pub use stream::*;
-pub use vesting::*; 
\ No newline at end of file
+pub use vesting::*;
+pub use operator::*;
+pub use template::*;
+pub use group::*;
+pub use allowlist::*;
+pub use split::*;
+pub use team::*;
+pub use withholding::*;
+pub use acceleration::*;
+pub use pool::*;
+pub use milestone::*;
+pub use payout::*; 
\ No newline at end of file
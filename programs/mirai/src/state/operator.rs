@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use crate::errors::MiraiError;
+
+/// Withdrawal operator registered by a recipient for a single stream or vesting
+#[account]
+pub struct OperatorDelegation {
+    /// Stream or vesting account this delegation applies to
+    pub position: Pubkey,
+    /// Recipient that registered the operator
+    pub recipient: Pubkey,
+    /// Key allowed to withdraw on the recipient's behalf
+    pub operator: Pubkey,
+    /// Maximum amount per withdrawal, unlimited when unset
+    pub max_amount_per_claim: Option<u64>,
+    /// Timestamp after which the delegation is no longer valid
+    pub expires_at: Option<i64>,
+    /// Whether the operator may pay out to accounts other than the recipient's ATA
+    pub allow_any_destination: bool,
+    /// Bump seed for the PDA
+    pub bump: u8,
+    /// Timestamp when the delegation was registered
+    pub created_at: i64,
+}
+
+impl OperatorDelegation {
+    /// Size of the account in bytes
+    pub const SIZE: usize = 8 + // discriminator
+        32 +
+        32 +
+        32 +
+        1 + 8 +
+        1 + 8 +
+        1 +
+        1 +
+        8;
+
+    /// Check if the delegation has expired
+    pub fn is_expired(&self, current_time: i64) -> bool {
+        matches!(self.expires_at, Some(expires_at) if current_time >= expires_at)
+    }
+
+    /// Validate a withdrawal of `amount` made by the operator at `current_time`
+    pub fn validate_withdrawal(&self, amount: u64, current_time: i64) -> Result<()> {
+        require!(!self.is_expired(current_time), MiraiError::OperatorExpired);
+        if let Some(max_amount) = self.max_amount_per_claim {
+            require!(amount <= max_amount, MiraiError::OperatorLimitExceeded);
+        }
+        Ok(())
+    }
+}
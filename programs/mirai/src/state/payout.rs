@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{TokenAccount, Transfer};
+use crate::errors::MiraiError;
+use crate::state::allowlist::PayoutAllowlist;
+use crate::state::split::PayoutSplit;
+use crate::state::withholding::{WithholdingLedger, WithholdingPolicy};
+
+/// Accounts a payout from a stream or vesting escrow is settled through. Every path that
+/// pays a recipient goes through here, so the DAO's withholding and the recipient's split
+/// and allowlist apply however the payout was triggered.
+pub struct PayoutRoute<'a, 'info> {
+    /// Escrow the payout is drawn from
+    pub escrow: AccountInfo<'info>,
+    /// Position that owns the escrow and signs the transfers
+    pub position: AccountInfo<'info>,
+    /// Token program used for the transfers
+    pub token_program: AccountInfo<'info>,
+    /// Mint of the position's escrow
+    pub mint: Pubkey,
+    /// Account paid when the recipient has no split
+    pub destination: AccountInfo<'info>,
+    /// Recipient's ATA, the only destination allowed without an allowlist
+    pub recipient_ata: Pubkey,
+    /// Whether any destination is allowed when the recipient has no allowlist
+    pub allow_any_destination: bool,
+    /// Recipient's payout allowlist
+    pub payout_allowlist: &'a AccountInfo<'info>,
+    /// Recipient's payout split for the position
+    pub payout_split: &'a AccountInfo<'info>,
+    /// Split destination token accounts, in share order
+    pub split_destinations: &'info [AccountInfo<'info>],
+    /// DAO's withholding policy for the position
+    pub withholding_policy: &'a AccountInfo<'info>,
+    /// DAO's withholding vault, required when the position has a policy
+    pub withholding_vault: Option<&'a Account<'info, TokenAccount>>,
+    /// Recipient's withholding ledger for the current year, required when the position has a policy
+    pub withholding_ledger: Option<&'a mut Account<'info, WithholdingLedger>>,
+}
+
+impl<'a, 'info> PayoutRoute<'a, 'info> {
+    /// Pay `amount` out of the escrow. The withheld share goes to the DAO's vault first; the
+    /// rest is divided by the recipient's split if they configured one and sent to the
+    /// destination otherwise. Returns the amount paid to the recipient.
+    pub fn settle(self, amount: u64, current_time: i64, signer_seeds: &[&[&[u8]]]) -> Result<u64> {
+        if amount == 0 {
+            return Ok(0);
+        }
+
+        let amount = WithholdingPolicy::apply(
+            self.withholding_policy,
+            self.withholding_vault,
+            self.withholding_ledger,
+            amount,
+            self.escrow.clone(),
+            self.position.clone(),
+            self.token_program.clone(),
+            current_time,
+            signer_seeds,
+        )?;
+
+        let allowlist = PayoutAllowlist::load(self.payout_allowlist)?;
+        if let Some(payout_split) = PayoutSplit::load(self.payout_split)? {
+            payout_split.transfer(
+                amount,
+                self.escrow,
+                self.position,
+                self.token_program,
+                self.split_destinations,
+                &self.mint,
+                allowlist.as_ref(),
+                current_time,
+                signer_seeds,
+            )?;
+            return Ok(amount);
+        }
+
+        match allowlist {
+            Some(allowlist) => require!(
+                allowlist.is_allowed(&self.destination.key(), current_time),
+                MiraiError::PayoutDestinationNotAllowed
+            ),
+            None => require!(
+                self.allow_any_destination || self.destination.key() == self.recipient_ata,
+                MiraiError::InvalidPayoutDestination
+            ),
+        }
+
+        let transfer_ctx = CpiContext::new_with_signer(
+            self.token_program,
+            Transfer {
+                from: self.escrow,
+                to: self.destination,
+                authority: self.position,
+            },
+            signer_seeds,
+        );
+        anchor_spl::token::transfer(transfer_ctx, amount)?;
+        Ok(amount)
+    }
+}
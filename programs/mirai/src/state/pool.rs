@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use crate::errors::MiraiError;
+use crate::state::config::DaoConfig;
+use crate::state::vesting::Vesting;
+
+/// Pool funded once by the DAO that vesting grants are carved out of, such as an equity pool
+#[account]
+pub struct VestingPool {
+    /// DAO configuration this pool belongs to
+    pub dao_config: Pubkey,
+    /// Name used to derive the pool address
+    pub name: String,
+    /// Token account holding the unallocated balance
+    pub pool_ata: Pubkey,
+    /// Total amount ever deposited into the pool
+    pub total_funded: u64,
+    /// Amount granted out of the pool and not since forfeited
+    pub allocated_amount: u64,
+    /// Total amount recycled back from revoked or terminated grants
+    pub forfeited_amount: u64,
+    /// Number of grants made from the pool
+    pub grant_count: u32,
+    /// Bump seed for the PDA
+    pub bump: u8,
+    /// Timestamp when the pool was created
+    pub created_at: i64,
+}
+
+impl VestingPool {
+    /// Maximum length of a pool name, bounded by the PDA seed limit
+    pub const MAX_NAME_LEN: usize = 32;
+
+    /// Size of the account in bytes
+    pub const SIZE: usize = 8 + // discriminator
+        32 +
+        4 + Self::MAX_NAME_LEN +
+        32 +
+        8 +
+        8 +
+        8 +
+        4 +
+        1 +
+        8;
+
+    /// Balance not yet granted out
+    pub fn get_available_amount(&self) -> u64 {
+        self.total_funded.saturating_sub(self.allocated_amount)
+    }
+
+    /// Record a deposit into the pool
+    pub fn fund(&mut self, amount: u64) {
+        self.total_funded = self.total_funded.saturating_add(amount);
+    }
+
+    /// Reserve `amount` of the unallocated balance for a new grant
+    pub fn allocate(&mut self, amount: u64) -> Result<()> {
+        require!(amount <= self.get_available_amount(), MiraiError::InsufficientPoolBalance);
+        self.allocated_amount = self.allocated_amount.saturating_add(amount);
+        self.grant_count = self.grant_count.saturating_add(1);
+        Ok(())
+    }
+
+    /// Return `amount` forfeited by a grant to the unallocated balance
+    pub fn record_forfeiture(&mut self, amount: u64) {
+        self.allocated_amount = self.allocated_amount.saturating_sub(amount);
+        self.forfeited_amount = self.forfeited_amount.saturating_add(amount);
+    }
+
+    /// Token account that `amount` forfeited by `vesting` goes back to: the pool the grant
+    /// was carved from, or the authority's account for grants funded directly. The amount is
+    /// released from whichever of the two counted it as allocated.
+    pub fn get_forfeiture_destination<'info>(
+        vesting: &Vesting,
+        dao_config: &mut DaoConfig,
+        vesting_pool: Option<&mut Account<'info, VestingPool>>,
+        pool_ata: Option<&Account<'info, TokenAccount>>,
+        authority_ata: &Account<'info, TokenAccount>,
+        amount: u64,
+    ) -> Result<AccountInfo<'info>> {
+        let Some(pool_key) = vesting.pool else {
+            dao_config.release_allocation(amount);
+            return Ok(authority_ata.to_account_info());
+        };
+
+        let vesting_pool = vesting_pool.ok_or(MiraiError::InvalidVestingPool)?;
+        let pool_ata = pool_ata.ok_or(MiraiError::InvalidVestingPool)?;
+        require_keys_eq!(vesting_pool.key(), pool_key, MiraiError::InvalidVestingPool);
+        require_keys_eq!(pool_ata.key(), vesting_pool.pool_ata, MiraiError::InvalidVestingPool);
+
+        vesting_pool.record_forfeiture(amount);
+        Ok(pool_ata.to_account_info())
+    }
+}
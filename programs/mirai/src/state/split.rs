@@ -0,0 +1,149 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{TokenAccount, Transfer};
+use crate::errors::MiraiError;
+use crate::state::allowlist::PayoutAllowlist;
+
+/// Share of each payout sent to one destination
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct SplitShare {
+    /// Token account receiving this share
+    pub destination: Pubkey,
+    /// Share of each payout in basis points
+    pub bps: u16,
+}
+
+impl SplitShare {
+    /// Size of the struct in bytes
+    pub const SIZE: usize = 32 + 2;
+}
+
+/// Split configuration a recipient applies to payouts from a single stream or vesting
+#[account]
+pub struct PayoutSplit {
+    /// Stream or vesting account this split applies to
+    pub position: Pubkey,
+    /// Recipient that configured the split
+    pub recipient: Pubkey,
+    /// Destinations and their shares, summing to 10,000 basis points
+    pub shares: Vec<SplitShare>,
+    /// Destination receiving the rounding remainder
+    pub primary: Pubkey,
+    /// Bump seed for the PDA
+    pub bump: u8,
+    /// Timestamp when the split was last configured
+    pub updated_at: i64,
+}
+
+impl PayoutSplit {
+    /// Maximum number of destinations in a split
+    pub const MAX_SHARES: usize = 4;
+
+    /// Basis points a split must add up to
+    pub const TOTAL_BPS: u16 = 10_000;
+
+    /// Size of the account in bytes
+    pub const SIZE: usize = 8 + // discriminator
+        32 +
+        32 +
+        4 + SplitShare::SIZE * Self::MAX_SHARES +
+        32 +
+        1 +
+        8;
+
+    /// Validate a split configuration before storing it
+    pub fn validate_shares(shares: &[SplitShare], primary: &Pubkey) -> Result<()> {
+        require!(
+            !shares.is_empty() && shares.len() <= Self::MAX_SHARES,
+            MiraiError::InvalidPayoutSplit
+        );
+
+        let mut total_bps: u16 = 0;
+        for (index, share) in shares.iter().enumerate() {
+            require!(share.bps > 0, MiraiError::InvalidPayoutSplit);
+            require!(
+                !shares[..index].iter().any(|other| other.destination == share.destination),
+                MiraiError::InvalidPayoutSplit
+            );
+            total_bps = total_bps.checked_add(share.bps).ok_or(MiraiError::InvalidPayoutSplit)?;
+        }
+        require!(total_bps == Self::TOTAL_BPS, MiraiError::InvalidPayoutSplit);
+        require!(
+            shares.iter().any(|share| share.destination == *primary),
+            MiraiError::InvalidPayoutSplit
+        );
+        Ok(())
+    }
+
+    /// Load the split at `account_info`, or `None` if the recipient never configured one
+    pub fn load(account_info: &AccountInfo) -> Result<Option<PayoutSplit>> {
+        if account_info.owner != &crate::ID || account_info.data_is_empty() {
+            return Ok(None);
+        }
+        let data = account_info.try_borrow_data()?;
+        Ok(Some(PayoutSplit::try_deserialize(&mut &data[..])?))
+    }
+
+    /// Divide `amount` by share, adding the rounding remainder to the primary destination
+    pub fn allocate(&self, amount: u64) -> Vec<u64> {
+        let mut amounts: Vec<u64> = self.shares
+            .iter()
+            .map(|share| {
+                ((amount as u128) * (share.bps as u128) / (Self::TOTAL_BPS as u128)) as u64
+            })
+            .collect();
+
+        let remainder = amount.saturating_sub(amounts.iter().sum());
+        if let Some(index) = self.shares.iter().position(|share| share.destination == self.primary) {
+            amounts[index] = amounts[index].saturating_add(remainder);
+        }
+        amounts
+    }
+
+    /// Transfer `amount` out of an escrow according to the split. Destination token accounts
+    /// are passed in share order; each must still be allowed if the recipient has an allowlist.
+    #[allow(clippy::too_many_arguments)]
+    pub fn transfer<'info>(
+        &self,
+        amount: u64,
+        from: AccountInfo<'info>,
+        authority: AccountInfo<'info>,
+        token_program: AccountInfo<'info>,
+        destinations: &'info [AccountInfo<'info>],
+        mint: &Pubkey,
+        allowlist: Option<&PayoutAllowlist>,
+        current_time: i64,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()> {
+        require!(destinations.len() == self.shares.len(), MiraiError::PayoutSplitMismatch);
+
+        for ((share, destination_info), share_amount) in self.shares
+            .iter()
+            .zip(destinations.iter())
+            .zip(self.allocate(amount))
+        {
+            require!(destination_info.key() == share.destination, MiraiError::PayoutSplitMismatch);
+            let destination: Account<'info, TokenAccount> = Account::try_from(destination_info)?;
+            require!(destination.mint == *mint, MiraiError::InvalidMint);
+            if let Some(allowlist) = allowlist {
+                require!(
+                    allowlist.is_allowed(&share.destination, current_time),
+                    MiraiError::PayoutDestinationNotAllowed
+                );
+            }
+
+            if share_amount > 0 {
+                let transfer_ctx = CpiContext::new_with_signer(
+                    token_program.clone(),
+                    Transfer {
+                        from: from.clone(),
+                        to: destination_info.clone(),
+                        authority: authority.clone(),
+                    },
+                    signer_seeds,
+                );
+                anchor_spl::token::transfer(transfer_ctx, share_amount)?;
+            }
+        }
+        Ok(())
+    }
+}
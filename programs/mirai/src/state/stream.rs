@@ -1,5 +1,38 @@
 use anchor_lang::prelude::*;
-use crate::state::config::PaymentCategory;
+use crate::state::config::{ExpiryPolicy, PaymentCategory, PositionTerms};
+use crate::errors::MiraiError;
+
+/// Policy for automatically starting a new period when the current one ends
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct RenewalPolicy {
+    /// Renewals left, renewing indefinitely when unset
+    pub remaining_renewals: Option<u32>,
+    /// Amount for each new period, reusing the previous period's amount when unset
+    pub period_amount: Option<u64>,
+}
+
+impl RenewalPolicy {
+    /// Size of the struct in bytes
+    pub const SIZE: usize = 1 + 4 + 1 + 8;
+
+    /// Check if another renewal is allowed
+    pub fn can_renew(&self) -> bool {
+        self.remaining_renewals != Some(0)
+    }
+}
+
+/// Why a stream was cancelled
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CancellationReason {
+    /// Terminated by the DAO authority
+    Terminated,
+    /// Recipient resigned from the stream
+    Resigned,
+    /// Offer withdrawn before the recipient accepted it
+    OfferWithdrawn,
+    /// Unclaimed balance swept back to the treasury
+    Swept,
+}
 
 /// Status of a stream
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
@@ -7,7 +40,17 @@ pub enum StreamStatus {
     Active,
     Paused,
     Completed,
-    Cancelled,
+    Cancelled { reason: CancellationReason },
+    Pending,
+}
+
+/// Rate change scheduled to take effect on a live stream
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct RateChange {
+    /// Timestamp from which the new rate applies
+    pub effective_time: i64,
+    /// Total amount of the stream once the change takes effect
+    pub new_total_amount: u64,
 }
 
 /// Stream account representing a linear token stream
@@ -41,6 +84,34 @@ pub struct Stream {
     pub bump: u8,
     /// Timestamp when the stream was created
     pub created_at: i64,
+    /// Start time of the current accrual segment
+    pub segment_start_time: i64,
+    /// Amount accrued by all segments before the current one
+    pub segment_base_amount: u64,
+    /// Rate change waiting for its effective time
+    pub pending_rate_change: Option<RateChange>,
+    /// Deadline for the recipient to accept a pending stream
+    pub acceptance_deadline: Option<i64>,
+    /// Timestamp when the recipient accepted the stream
+    pub accepted_at: Option<i64>,
+    /// Hash of the off-chain agreement the recipient committed to
+    pub agreement_hash: Option<[u8; 32]>,
+    /// Conditions under which the unclaimed balance can be swept
+    pub expiry_policy: ExpiryPolicy,
+    /// Timestamp of the most recent withdrawal
+    pub last_withdrawal_at: i64,
+    /// Cancellation and transfer rights
+    pub terms: PositionTerms,
+    /// Start time of the current renewal period
+    pub period_start_time: i64,
+    /// Total amount accrued by all periods before the current one
+    pub period_base_amount: u64,
+    /// Auto-renewal policy, if the stream renews
+    pub renewal_policy: Option<RenewalPolicy>,
+    /// Stream group this stream was attached to at creation
+    pub group: Option<Pubkey>,
+    /// Timestamp the stream was paused at, freezing accrual until it resumes
+    pub paused_at: Option<i64>,
 }
 
 impl Stream {
@@ -51,7 +122,7 @@ impl Stream {
         32 + 
         32 + 
         1 +  
-        64 + 
+        4 + 64 + 
         8 +  
         8 +  
         8 +  
@@ -59,38 +130,194 @@ impl Stream {
         32 + 
         1 +  
         1 + 
-        8;   
+        8 +
+        8 +
+        8 +
+        1 + 8 + 8 +
+        1 + 8 +
+        1 + 8 +
+        1 + 32 +
+        ExpiryPolicy::SIZE +
+        8 +
+        PositionTerms::SIZE +
+        8 +
+        8 +
+        1 + RenewalPolicy::SIZE +
+        1 + 32 +
+        1 + 8;
 
     /// Calculate the amount of tokens that can be withdrawn at the current time
     pub fn get_withdrawable_amount(&self, current_time: i64) -> u64 {
-        // If stream is paused or cancelled, no withdrawals allowed
-        if self.status == StreamStatus::Paused || self.status == StreamStatus::Cancelled {
+        // If stream is paused, cancelled or awaiting acceptance, no withdrawals allowed
+        if self.status == StreamStatus::Paused ||
+            self.is_cancelled() ||
+            self.status == StreamStatus::Pending {
             return 0;
         }
 
+        self.get_unlocked_amount(current_time).saturating_sub(self.withdrawn_amount)
+    }
+
+    /// Total amount the recipient has earned by `current_time`: everything unlocked, or
+    /// what was settled to them if the stream was cancelled
+    pub fn get_earned_amount(&self, current_time: i64) -> u64 {
+        if self.is_cancelled() {
+            return self.withdrawn_amount;
+        }
+        self.get_unlocked_amount(current_time)
+    }
+
+    /// Calculate the total amount unlocked at `current_time`, including a due rate change
+    pub fn get_unlocked_amount(&self, current_time: i64) -> u64 {
+        let current_time = self.get_accrual_time(current_time);
+        match &self.pending_rate_change {
+            Some(change) if current_time >= change.effective_time => {
+                let base_amount = self.get_segment_unlocked_amount(change.effective_time);
+                Self::interpolate(base_amount, change.effective_time, self.end_time, change.new_total_amount, current_time)
+            }
+            _ => self.get_segment_unlocked_amount(current_time),
+        }
+    }
+
+    /// Time accrual has reached at `current_time`, which stands still while the stream is paused
+    fn get_accrual_time(&self, current_time: i64) -> i64 {
+        match self.paused_at {
+            Some(paused_at) => current_time.min(paused_at),
+            None => current_time,
+        }
+    }
+
+    /// Calculate the amount unlocked at `current_time` under the current segment only
+    fn get_segment_unlocked_amount(&self, current_time: i64) -> u64 {
+        let current_time = self.get_accrual_time(current_time);
         if current_time < self.start_time {
             return 0;
         }
 
-        if current_time >= self.end_time {
-            return self.total_amount.saturating_sub(self.withdrawn_amount);
+        Self::interpolate(
+            self.segment_base_amount,
+            self.segment_start_time,
+            self.end_time,
+            self.total_amount,
+            current_time,
+        )
+    }
+
+    /// Linear interpolation from `base_amount` at `from_time` to `total_amount` at `end_time`
+    fn interpolate(base_amount: u64, from_time: i64, end_time: i64, total_amount: u64, current_time: i64) -> u64 {
+        if current_time >= end_time {
+            return total_amount;
         }
 
-        // Linear interpolation: (current_time - start_time) / (end_time - start_time) * total_amount
-        let time_elapsed = current_time.saturating_sub(self.start_time);
-        let total_duration = self.end_time.saturating_sub(self.start_time);
-        
+        if current_time <= from_time {
+            return base_amount;
+        }
+
+        let time_elapsed = current_time.saturating_sub(from_time);
+        let total_duration = end_time.saturating_sub(from_time);
+
         if total_duration == 0 {
-            return 0;
+            return base_amount;
         }
 
-        let unlocked_amount = (self.total_amount as u128)
+        // base + (current_time - from_time) / (end_time - from_time) * (total_amount - base)
+        let segment_amount = total_amount.saturating_sub(base_amount);
+        let unlocked_amount = (segment_amount as u128)
             .checked_mul(time_elapsed as u128)
             .unwrap_or(0)
             .checked_div(total_duration as u128)
             .unwrap_or(0) as u64;
 
-        unlocked_amount.saturating_sub(self.withdrawn_amount)
+        base_amount.saturating_add(unlocked_amount)
+    }
+
+    /// Close the current segment and start a new one once a scheduled rate change is due
+    pub fn apply_due_rate_change(&mut self, current_time: i64) {
+        if let Some(change) = self.pending_rate_change.clone() {
+            if self.get_accrual_time(current_time) >= change.effective_time {
+                self.segment_base_amount = self.get_segment_unlocked_amount(change.effective_time);
+                self.segment_start_time = change.effective_time;
+                self.total_amount = change.new_total_amount;
+                self.pending_rate_change = None;
+            }
+        }
+    }
+
+    /// Record a withdrawal after validating it against the unlocked amount
+    pub fn record_withdrawal(&mut self, amount: u64, current_time: i64) -> Result<()> {
+        require!(amount > 0, MiraiError::InvalidWithdrawalAmount);
+        require!(current_time >= self.start_time, MiraiError::StreamNotStarted);
+        require!(self.status == StreamStatus::Active, MiraiError::StreamNotActive);
+
+        self.apply_due_rate_change(current_time);
+        let available_amount = self.get_withdrawable_amount(current_time);
+        require!(amount <= available_amount, MiraiError::InsufficientUnlockedTokens);
+
+        self.withdrawn_amount = self.withdrawn_amount.checked_add(amount)
+            .ok_or(MiraiError::InsufficientUnlockedTokens)?;
+
+        self.last_withdrawal_at = current_time;
+
+        if self.withdrawn_amount >= self.total_amount {
+            self.complete();
+        }
+
+        Ok(())
+    }
+
+    /// Accept a pending stream, shifting its schedule so nothing accrues before acceptance
+    pub fn accept(&mut self, current_time: i64, agreement_hash: Option<[u8; 32]>) -> Result<()> {
+        require!(self.status == StreamStatus::Pending, MiraiError::StreamNotPending);
+        if let Some(deadline) = self.acceptance_deadline {
+            require!(current_time <= deadline, MiraiError::AcceptanceDeadlinePassed);
+        }
+
+        if current_time > self.start_time {
+            let delay = current_time - self.start_time;
+            self.start_time = current_time;
+            self.end_time = self.end_time.saturating_add(delay);
+            self.segment_start_time = current_time;
+            self.period_start_time = current_time;
+        }
+
+        self.status = StreamStatus::Active;
+        self.accepted_at = Some(current_time);
+        self.agreement_hash = agreement_hash;
+        Ok(())
+    }
+
+    /// Check if the unclaimed balance can be swept back to the treasury. The inactivity clock
+    /// only starts once the stream has fully accrued, and a paused stream has not.
+    pub fn is_sweepable(&self, current_time: i64) -> bool {
+        let last_activity_at = self.last_withdrawal_at.max(self.end_time);
+        !self.is_finished() &&
+            self.status != StreamStatus::Pending &&
+            self.paused_at.is_none() &&
+            self.expiry_policy.has_expired(last_activity_at, current_time)
+    }
+
+    /// Amount streamed over the current renewal period
+    pub fn get_period_amount(&self) -> u64 {
+        self.total_amount.saturating_sub(self.period_base_amount)
+    }
+
+    /// Start the next period right where the current one ends, adding `amount` to the stream
+    pub fn renew(&mut self, amount: u64) {
+        let period_duration = self.end_time.saturating_sub(self.period_start_time);
+
+        self.period_start_time = self.end_time;
+        self.period_base_amount = self.total_amount;
+        self.segment_start_time = self.end_time;
+        self.segment_base_amount = self.total_amount;
+        self.end_time = self.end_time.saturating_add(period_duration);
+        self.total_amount = self.total_amount.saturating_add(amount);
+        self.status = StreamStatus::Active;
+
+        if let Some(policy) = self.renewal_policy.as_mut() {
+            if let Some(remaining) = policy.remaining_renewals.as_mut() {
+                *remaining = remaining.saturating_sub(1);
+            }
+        }
     }
 
     /// Check if the stream is active (between start and end time)
@@ -110,17 +337,60 @@ impl Stream {
         self.status == StreamStatus::Active
     }
 
-    /// Pause the stream
-    pub fn pause(&mut self) {
+    /// Push the end time back by `extension` seconds. Everything unlocked so far stays
+    /// unlocked and the remaining amount is spread over the longer schedule.
+    pub fn extend(&mut self, extension: i64, current_time: i64) -> Result<()> {
+        require!(extension > 0, MiraiError::InvalidStreamDuration);
+        require!(
+            self.status == StreamStatus::Active || self.status == StreamStatus::Paused,
+            MiraiError::StreamModificationNotAllowed
+        );
+
+        self.apply_due_rate_change(current_time);
+        require!(self.pending_rate_change.is_none(), MiraiError::RateChangeAlreadyPending);
+
+        if current_time > self.segment_start_time {
+            self.segment_base_amount = self.get_segment_unlocked_amount(current_time);
+            self.segment_start_time = current_time;
+        }
+        self.end_time = self.end_time.saturating_add(extension);
+        Ok(())
+    }
+
+    /// Pause the stream, freezing accrual at `current_time`
+    pub fn pause(&mut self, current_time: i64) {
         if self.status == StreamStatus::Active {
             self.status = StreamStatus::Paused;
+            self.paused_at = Some(current_time);
         }
     }
 
-    /// Resume the stream
-    pub fn resume(&mut self) {
-        if self.status == StreamStatus::Paused {
-            self.status = StreamStatus::Active;
+    /// Resume the stream, shifting the rest of the schedule back by the time spent paused
+    /// so nothing accrues for the pause itself
+    pub fn resume(&mut self, current_time: i64) {
+        if self.status != StreamStatus::Paused {
+            return;
+        }
+        self.status = StreamStatus::Active;
+        let Some(paused_at) = self.paused_at.take() else {
+            return;
+        };
+        let paused_duration = current_time.saturating_sub(paused_at);
+
+        if self.start_time > paused_at {
+            // Paused before it started, so the whole schedule moves
+            self.start_time = self.start_time.saturating_add(paused_duration);
+            self.segment_start_time = self.segment_start_time.saturating_add(paused_duration);
+        } else {
+            // Continue from what had accrued when the stream was paused
+            self.apply_due_rate_change(paused_at);
+            self.segment_base_amount = self.get_segment_unlocked_amount(paused_at);
+            self.segment_start_time = current_time;
+        }
+        self.end_time = self.end_time.saturating_add(paused_duration);
+        self.period_start_time = self.period_start_time.saturating_add(paused_duration);
+        if let Some(change) = self.pending_rate_change.as_mut() {
+            change.effective_time = change.effective_time.saturating_add(paused_duration);
         }
     }
 
@@ -130,8 +400,39 @@ impl Stream {
     }
 
     /// Cancel the stream
-    pub fn cancel(&mut self) {
-        self.status = StreamStatus::Cancelled;
+    pub fn cancel(&mut self, reason: CancellationReason) {
+        self.status = StreamStatus::Cancelled { reason };
+    }
+
+    /// Check if the stream was cancelled, for any reason
+    pub fn is_cancelled(&self) -> bool {
+        matches!(self.status, StreamStatus::Cancelled { .. })
+    }
+
+    /// Cancel the stream, recording everything unlocked so far as owed to the recipient.
+    /// Returns the amount the recipient must still be paid.
+    pub fn settle_and_cancel(&mut self, current_time: i64, reason: CancellationReason) -> Result<u64> {
+        require!(
+            self.status == StreamStatus::Active || self.status == StreamStatus::Paused,
+            MiraiError::StreamModificationNotAllowed
+        );
+
+        self.apply_due_rate_change(current_time);
+        let owed_amount = self.get_unlocked_amount(current_time).saturating_sub(self.withdrawn_amount);
+        self.withdrawn_amount = self.withdrawn_amount.saturating_add(owed_amount);
+
+        // The escrow was already funded for a change that will now never take effect
+        if let Some(change) = self.pending_rate_change.take() {
+            self.total_amount = change.new_total_amount;
+        }
+        self.cancel(reason);
+
+        Ok(owed_amount)
+    }
+
+    /// Check if the stream has reached a terminal status and can be closed
+    pub fn is_finished(&self) -> bool {
+        self.status == StreamStatus::Completed || self.is_cancelled()
     }
 
     /// Get the remaining amount in the stream
@@ -0,0 +1,169 @@
+use anchor_lang::prelude::*;
+use crate::errors::MiraiError;
+use crate::state::stream::Stream;
+
+/// Share of a team stream requested by the team admin
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct TeamShare {
+    /// Team member receiving the share
+    pub member: Pubkey,
+    /// Share of newly accrued tokens in basis points
+    pub bps: u16,
+}
+
+/// Team member with their share and running balances
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct TeamMember {
+    /// Team member wallet
+    pub member: Pubkey,
+    /// Share of newly accrued tokens in basis points
+    pub bps: u16,
+    /// Amount accrued to this member so far
+    pub accrued_amount: u64,
+    /// Amount this member has withdrawn
+    pub withdrawn_amount: u64,
+}
+
+impl TeamMember {
+    /// Size of the struct in bytes
+    pub const SIZE: usize = 32 + 2 + 8 + 8;
+
+    /// Amount the member can withdraw right now
+    pub fn get_available_amount(&self) -> u64 {
+        self.accrued_amount.saturating_sub(self.withdrawn_amount)
+    }
+}
+
+/// Team account used as the recipient of a stream, splitting accruals between members
+#[account]
+pub struct Team {
+    /// DAO configuration this team is paid by
+    pub dao_config: Pubkey,
+    /// Admin allowed to rebalance shares
+    pub admin: Pubkey,
+    /// Name used to derive the team address
+    pub name: String,
+    /// Members, their shares and balances
+    pub members: Vec<TeamMember>,
+    /// Stream unlocked amount already distributed to members
+    pub accounted_amount: u64,
+    /// Bump seed for the PDA
+    pub bump: u8,
+    /// Timestamp when the team was created
+    pub created_at: i64,
+}
+
+impl Team {
+    /// Maximum length of a team name, bounded by the PDA seed limit
+    pub const MAX_NAME_LEN: usize = 32;
+
+    /// Maximum number of members, including departed members with unpaid balances
+    pub const MAX_MEMBERS: usize = 8;
+
+    /// Basis points member shares must add up to
+    pub const TOTAL_BPS: u16 = 10_000;
+
+    /// Size of the account in bytes
+    pub const SIZE: usize = 8 + // discriminator
+        32 +
+        32 +
+        4 + Self::MAX_NAME_LEN +
+        4 + TeamMember::SIZE * Self::MAX_MEMBERS +
+        8 +
+        1 +
+        8;
+
+    /// Validate requested shares before applying them
+    pub fn validate_shares(shares: &[TeamShare]) -> Result<()> {
+        require!(!shares.is_empty(), MiraiError::InvalidTeamShares);
+
+        let mut total_bps: u16 = 0;
+        for (index, share) in shares.iter().enumerate() {
+            require!(share.bps > 0, MiraiError::InvalidTeamShares);
+            require!(
+                !shares[..index].iter().any(|other| other.member == share.member),
+                MiraiError::InvalidTeamShares
+            );
+            total_bps = total_bps.checked_add(share.bps).ok_or(MiraiError::InvalidTeamShares)?;
+        }
+        require!(total_bps == Self::TOTAL_BPS, MiraiError::InvalidTeamShares);
+        Ok(())
+    }
+
+    /// Check if `account_info` holds a team, which cannot sign for itself
+    pub fn is_team(account_info: &AccountInfo) -> bool {
+        account_info.owner == &crate::ID &&
+            account_info
+                .try_borrow_data()
+                .is_ok_and(|data| data.starts_with(Team::DISCRIMINATOR))
+    }
+
+    /// Distribute everything the team stream earned since the last checkpoint using the
+    /// current shares. The rounding remainder goes to the member with the largest share.
+    pub fn checkpoint(&mut self, stream: &Stream, current_time: i64) {
+        let unlocked_amount = stream.get_earned_amount(current_time);
+        let new_amount = unlocked_amount.saturating_sub(self.accounted_amount);
+        if new_amount == 0 {
+            return;
+        }
+
+        let mut distributed_amount: u64 = 0;
+        for member in self.members.iter_mut() {
+            let share_amount = ((new_amount as u128) * (member.bps as u128) / (Self::TOTAL_BPS as u128)) as u64;
+            member.accrued_amount = member.accrued_amount.saturating_add(share_amount);
+            distributed_amount = distributed_amount.saturating_add(share_amount);
+        }
+
+        if let Some(largest) = self.members.iter_mut().max_by_key(|member| member.bps) {
+            largest.accrued_amount = largest
+                .accrued_amount
+                .saturating_add(new_amount.saturating_sub(distributed_amount));
+        }
+        self.accounted_amount = unlocked_amount;
+    }
+
+    /// Replace the shares going forward. Departed members keep their unpaid balances
+    /// with a zero share until they withdraw them.
+    pub fn set_shares(&mut self, shares: &[TeamShare]) -> Result<()> {
+        Self::validate_shares(shares)?;
+
+        let mut members: Vec<TeamMember> = shares
+            .iter()
+            .map(|share| {
+                let existing = self.members.iter().find(|member| member.member == share.member);
+                TeamMember {
+                    member: share.member,
+                    bps: share.bps,
+                    accrued_amount: existing.map_or(0, |member| member.accrued_amount),
+                    withdrawn_amount: existing.map_or(0, |member| member.withdrawn_amount),
+                }
+            })
+            .collect();
+
+        for departed in self.members.iter() {
+            if departed.get_available_amount() > 0 && !shares.iter().any(|share| share.member == departed.member) {
+                members.push(TeamMember { bps: 0, ..departed.clone() });
+            }
+        }
+
+        require!(members.len() <= Self::MAX_MEMBERS, MiraiError::InvalidTeamShares);
+        self.members = members;
+        Ok(())
+    }
+
+    /// Record a withdrawal of `amount` by `member` against their accrued balance
+    pub fn record_member_withdrawal(&mut self, member: &Pubkey, amount: u64) -> Result<()> {
+        require!(amount > 0, MiraiError::InvalidWithdrawalAmount);
+        let entry = self.members
+            .iter_mut()
+            .find(|entry| entry.member == *member)
+            .ok_or(MiraiError::NotTeamMember)?;
+        require!(amount <= entry.get_available_amount(), MiraiError::InsufficientTeamShare);
+
+        entry.withdrawn_amount = entry.withdrawn_amount.saturating_add(amount);
+
+        // Departed members are dropped once they have withdrawn everything owed to them
+        self.members.retain(|entry| entry.bps > 0 || entry.get_available_amount() > 0);
+        Ok(())
+    }
+}
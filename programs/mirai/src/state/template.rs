@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+use crate::errors::MiraiError;
+use crate::state::config::{PaymentCategory, PositionKind, PositionTerms};
+use crate::state::vesting::VestingType;
+
+/// Reusable shape for streams and vestings created by a DAO
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct TemplateParams {
+    /// Whether the template creates streams or vestings
+    pub kind: PositionKind,
+    /// Vesting type, ignored for stream templates
+    pub vesting_type: VestingType,
+    /// Seconds from start to end
+    pub duration: i64,
+    /// Seconds from start to cliff, ignored unless the vesting type is cliff
+    pub cliff_offset: i64,
+    /// Payment category applied to created positions
+    pub category: PaymentCategory,
+    /// Description applied to created positions
+    pub description: String,
+    /// Cancellation and transfer rights applied to created positions
+    pub terms: PositionTerms,
+    /// Largest amount a position created from the template may hold
+    pub max_amount: Option<u64>,
+}
+
+/// Template account storing a reusable stream or vesting shape
+#[account]
+pub struct Template {
+    /// DAO configuration this template belongs to
+    pub dao_config: Pubkey,
+    /// Name used to derive the template address
+    pub name: String,
+    /// Whether the template creates streams or vestings
+    pub kind: PositionKind,
+    /// Vesting type, ignored for stream templates
+    pub vesting_type: VestingType,
+    /// Seconds from start to end
+    pub duration: i64,
+    /// Seconds from start to cliff
+    pub cliff_offset: i64,
+    /// Payment category applied to created positions
+    pub category: PaymentCategory,
+    /// Description applied to created positions
+    pub description: String,
+    /// Cancellation and transfer rights applied to created positions
+    pub terms: PositionTerms,
+    /// Largest amount a position created from the template may hold
+    pub max_amount: Option<u64>,
+    /// Bump seed for the PDA
+    pub bump: u8,
+    /// Timestamp when the template was created
+    pub created_at: i64,
+}
+
+impl Template {
+    /// Maximum length of a template name, bounded by the PDA seed limit
+    pub const MAX_NAME_LEN: usize = 32;
+
+    /// Size of the account in bytes
+    pub const SIZE: usize = 8 + // discriminator
+        32 +
+        4 + Self::MAX_NAME_LEN +
+        1 +
+        1 +
+        8 +
+        8 +
+        1 +
+        4 + 64 +
+        PositionTerms::SIZE +
+        1 + 8 +
+        1 +
+        8;
+
+    /// Validate template parameters before storing them
+    pub fn validate_params(name: &str, params: &TemplateParams) -> Result<()> {
+        require!(!name.is_empty() && name.len() <= Self::MAX_NAME_LEN, MiraiError::InvalidTemplateName);
+        require!(params.description.len() <= 64, MiraiError::DescriptionTooLong);
+        require!(params.duration > 0, MiraiError::InvalidStreamDuration);
+        if params.kind == PositionKind::Vesting && params.vesting_type == VestingType::Cliff {
+            require!(
+                params.cliff_offset >= 0 && params.cliff_offset <= params.duration,
+                MiraiError::InvalidCliffTiming
+            );
+        }
+        if let Some(max_amount) = params.max_amount {
+            require!(max_amount > 0, MiraiError::InvalidTotalAmount);
+        }
+        Ok(())
+    }
+
+    /// Validate a position about to be created from this template
+    pub fn validate_position(&self, kind: PositionKind, amount: u64) -> Result<()> {
+        require!(self.kind == kind, MiraiError::TemplateKindMismatch);
+        require!(amount > 0, MiraiError::InvalidTotalAmount);
+        if let Some(max_amount) = self.max_amount {
+            require!(amount <= max_amount, MiraiError::TemplateAmountExceeded);
+        }
+        Ok(())
+    }
+
+    /// End time for a position starting at `start_time`
+    pub fn end_time(&self, start_time: i64) -> i64 {
+        start_time.saturating_add(self.duration)
+    }
+
+    /// Cliff time for a position starting at `start_time`
+    pub fn cliff_time(&self, start_time: i64) -> i64 {
+        start_time.saturating_add(self.cliff_offset)
+    }
+}
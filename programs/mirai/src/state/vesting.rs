@@ -12,6 +12,185 @@ pub enum VestingStatus {
     Paused,
     Completed,
     Cancelled,
+    Revoked,     // Frozen at the revoke time; the vested portion stays claimable
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub enum TerminationReason {
+    Voluntary,       // Recipient left of their own accord
+    WithoutCause,    // DAO ended the engagement without cause
+    ForCause,        // DAO ended the engagement for cause
+}
+
+// Fractions of the vested and unvested amounts a leaver keeps, in basis points
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct LeaverTerms {
+    pub vested_keep_bps: u16,
+    pub unvested_keep_bps: u16,
+}
+
+impl LeaverTerms {
+    pub const SIZE: usize = 2 + 2;
+
+    // Keep everything vested, forfeit everything unvested
+    pub const STANDARD: LeaverTerms = LeaverTerms { vested_keep_bps: 10_000, unvested_keep_bps: 0 };
+
+    // Amount kept out of `vested_amount` and `unvested_amount`
+    pub fn get_retained_amount(&self, vested_amount: u64, unvested_amount: u64) -> u64 {
+        let kept_vested = (vested_amount as u128) * (self.vested_keep_bps as u128) / 10_000;
+        let kept_unvested = (unvested_amount as u128) * (self.unvested_keep_bps as u128) / 10_000;
+        (kept_vested + kept_unvested) as u64
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct TerminationPolicy {
+    pub voluntary: LeaverTerms,
+    pub without_cause: LeaverTerms,
+    pub for_cause: LeaverTerms,
+    pub claim_window: Option<i64>,   // Seconds after termination before the remainder can be swept
+}
+
+impl Default for TerminationPolicy {
+    fn default() -> Self {
+        TerminationPolicy {
+            voluntary: LeaverTerms::STANDARD,
+            without_cause: LeaverTerms::STANDARD,
+            for_cause: LeaverTerms::STANDARD,
+            claim_window: None,
+        }
+    }
+}
+
+impl TerminationPolicy {
+    pub const SIZE: usize = LeaverTerms::SIZE * 3 + 1 + 8;
+
+    pub fn validate(&self) -> Result<()> {
+        for terms in [&self.voluntary, &self.without_cause, &self.for_cause] {
+            require!(
+                terms.vested_keep_bps <= 10_000 && terms.unvested_keep_bps <= 10_000,
+                MiraiError::InvalidTerminationPolicy
+            );
+        }
+        if let Some(claim_window) = self.claim_window {
+            require!(claim_window > 0, MiraiError::InvalidTerminationPolicy);
+        }
+        Ok(())
+    }
+
+    pub fn get_leaver_terms(&self, reason: &TerminationReason) -> &LeaverTerms {
+        match reason {
+            TerminationReason::Voluntary => &self.voluntary,
+            TerminationReason::WithoutCause => &self.without_cause,
+            TerminationReason::ForCause => &self.for_cause,
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub enum AccelerationTrigger {
+    SingleTrigger,   // Change of control alone
+    DoubleTrigger,   // Change of control followed by termination without cause
+}
+
+// Share of the unvested amount that vests immediately when triggered
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct AccelerationTerms {
+    pub trigger: AccelerationTrigger,
+    pub accelerate_bps: u16,
+    pub termination_window: i64,     // Seconds after a change of control that a termination still counts, double-trigger only
+}
+
+impl AccelerationTerms {
+    pub const SIZE: usize = 1 + 2 + 8;
+
+    pub fn validate(&self) -> Result<()> {
+        require!(self.accelerate_bps > 0 && self.accelerate_bps <= 10_000, MiraiError::InvalidAccelerationTerms);
+        let window_valid = match self.trigger {
+            AccelerationTrigger::SingleTrigger => self.termination_window == 0,
+            AccelerationTrigger::DoubleTrigger => self.termination_window > 0,
+        };
+        require!(window_valid, MiraiError::InvalidAccelerationTerms);
+        Ok(())
+    }
+
+    // Whether a change of control at `triggered_at` accelerates, given how the engagement ended (if it has)
+    pub fn is_triggered(&self, reason: Option<&TerminationReason>, triggered_at: i64, current_time: i64) -> bool {
+        match self.trigger {
+            AccelerationTrigger::SingleTrigger => true,
+            AccelerationTrigger::DoubleTrigger => {
+                reason == Some(&TerminationReason::WithoutCause)
+                    && current_time <= triggered_at.saturating_add(self.termination_window)
+            }
+        }
+    }
+
+    // Whether these terms accelerate at least as much, as often, as `other`
+    pub fn covers(&self, other: &AccelerationTerms) -> bool {
+        let trigger_covers = match (&self.trigger, &other.trigger) {
+            (AccelerationTrigger::SingleTrigger, _) => true,
+            (AccelerationTrigger::DoubleTrigger, AccelerationTrigger::DoubleTrigger) => {
+                self.termination_window >= other.termination_window
+            }
+            (AccelerationTrigger::DoubleTrigger, AccelerationTrigger::SingleTrigger) => false,
+        };
+        trigger_covers && self.accelerate_bps >= other.accelerate_bps
+    }
+
+    // Vested amount once `accelerate_bps` of what remains unvested has accelerated
+    pub fn get_accelerated_amount(&self, total_amount: u64, vested_amount: u64) -> u64 {
+        let unvested_amount = total_amount.saturating_sub(vested_amount);
+        let accelerated = (unvested_amount as u128) * (self.accelerate_bps as u128) / 10_000;
+        vested_amount.saturating_add(accelerated as u64)
+    }
+}
+
+// Terms that make a vesting an option grant: vested units are bought at the strike price
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct OptionTerms {
+    pub strike_mint: Pubkey,
+    pub strike_price: u64,           // Strike-mint base units per whole token exercised
+    pub exercise_window: i64,        // Seconds after the schedule ends that vested units stay exercisable
+}
+
+impl OptionTerms {
+    pub const SIZE: usize = 32 + 8 + 8;
+
+    pub fn validate(&self) -> Result<()> {
+        require!(self.strike_price > 0 && self.exercise_window > 0, MiraiError::InvalidOptionTerms);
+        Ok(())
+    }
+
+    // Strike owed for exercising `amount` base units of a mint with `decimals`, rounded up
+    pub fn get_strike_amount(&self, amount: u64, decimals: u8) -> Result<u64> {
+        let whole_unit = 10u128.pow(decimals as u32);
+        let strike_amount = ((amount as u128) * (self.strike_price as u128)).div_ceil(whole_unit);
+        u64::try_from(strike_amount).map_err(|_| error!(MiraiError::StrikePaymentOverflow))
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct VestingTranche {
+    pub amount: u64,
+    pub start_time: i64,
+    pub cliff_time: i64,             // Only used by cliff vestings
+    pub end_time: i64,
+}
+
+impl VestingTranche {
+    pub const SIZE: usize = 8 + 8 + 8 + 8;
+
+    pub fn validate(&self, vesting_type: &VestingType) -> Result<()> {
+        require!(self.amount > 0, MiraiError::InvalidTotalAmount);
+        require!(self.start_time < self.end_time, MiraiError::InvalidVestingTiming);
+        if *vesting_type == VestingType::Cliff {
+            require!(
+                self.cliff_time >= self.start_time && self.cliff_time <= self.end_time,
+                MiraiError::InvalidCliffTiming
+            );
+        }
+        Ok(())
+    }
 }
 
 #[account]
@@ -32,78 +211,435 @@ pub struct Vesting {
     pub description: String,        
     pub bump: u8,                    
     pub created_at: i64,           
+    pub expiry_policy: ExpiryPolicy, // Conditions for sweeping unclaimed tokens
+    pub last_claim_at: i64,
+    pub terms: PositionTerms,        // Cancellation and transfer rights
+    pub revoked_at: Option<i64>,     // Set when the DAO revokes the vesting
+    pub termination_policy: Option<TerminationPolicy>, // Leaver terms, standard when unset
+    pub termination_reason: Option<TerminationReason>,
+    pub tranches: Vec<VestingTranche>, // Tranches on top of the base schedule
+    pub tge_unlock_bps: u16,         // Share of the base schedule unlocked at start time
+    pub backdated: bool,             // Start time predates creation
+    pub acceleration_terms: Option<AccelerationTerms>,
+    pub accelerated_at: Option<i64>, // Set once acceleration has been applied
+    pub pool: Option<Pubkey>,        // Vesting pool the grant was carved from
+    pub option_terms: Option<OptionTerms>, // Set for option grants, which are exercised rather than claimed
+    pub milestones: Vec<MilestoneGate>, // Milestones gating the time-vested amount, if any
 }
 
 impl Vesting {
-    pub const SIZE: usize = 32 + 32 + 32 + 32 + 32 + 1 + 8 + 8 + 8 + 8 + 8 + 1 + 1 + 64 + 1 + 8;
+    // Validate the share unlocked at start time
+    pub fn validate_tge_unlock(tge_unlock_bps: u16) -> Result<()> {
+        require!(tge_unlock_bps <= 10000, MiraiError::InvalidTgeUnlock);
+        Ok(())
+    }
+
+    pub const SIZE: usize = 8 + 32 + 32 + 32 + 32 + 32 + 1 + 8 + 8 + 8 + 8 + 8 + 1 + 1 + 4 + 64 + 1 + 8 + ExpiryPolicy::SIZE + 8 + PositionTerms::SIZE + 1 + 8 + 1 + TerminationPolicy::SIZE + 1 + 1 + 4 + VestingTranche::SIZE * Self::MAX_TRANCHES + 2 + 1 + 1 + AccelerationTerms::SIZE + 1 + 8 + 1 + 32 + 1 + OptionTerms::SIZE + 4 + MilestoneGate::SIZE * Self::MAX_MILESTONES;
+
+    pub const MAX_TRANCHES: usize = 8;
+
+    pub const MAX_MILESTONES: usize = 4;
 
     pub fn get_claimable_amount(&self, current_time: i64) -> u64 {
-        if self.status != VestingStatus::Active {
+        if !self.accepts_claims() {
             return 0;
         }
 
-        if current_time < self.start_time {
+        if current_time < self.get_earliest_start_time() {
             return 0;
         }
 
-        match self.vesting_type {
-            VestingType::Linear => self.get_linear_claimable(current_time),
-            VestingType::Cliff => self.get_cliff_claimable(current_time),
+        self.get_vested_amount(current_time).saturating_sub(self.claimed_amount)
+    }
+
+    // Total vested at `current_time` across the base schedule and every tranche, regardless of status
+    pub fn get_vested_amount(&self, current_time: i64) -> u64 {
+
+        // Acceleration brings the schedule forward, but milestone gates still hold back their share,
+        // so vesting never runs ahead of what a missed milestone could hand back to the DAO
+        let scheduled_amount = self.get_scheduled_amount(current_time);
+        let scheduled_amount = match (&self.acceleration_terms, self.accelerated_at) {
+            (Some(terms), Some(_)) => terms.get_accelerated_amount(self.total_amount, scheduled_amount),
+            _ => scheduled_amount,
+        };
+
+        self.get_milestone_unlocked(scheduled_amount)
+    }
+
+    // Amount the base schedule and every tranche have reached by `current_time`, before milestone gates
+    fn get_scheduled_amount(&self, current_time: i64) -> u64 {
+        let base_amount = self.total_amount.saturating_sub(self.get_tranche_total());
+        let base_vested = self.get_schedule_vested(
+            base_amount,
+            self.tge_unlock_bps,
+            self.start_time,
+            self.cliff_time,
+            self.end_time,
+            current_time,
+        );
+
+        self.tranches.iter().fold(base_vested, |vested_amount, tranche| {
+            vested_amount.saturating_add(self.get_schedule_vested(
+                tranche.amount,
+                0,
+                tranche.start_time,
+                tranche.cliff_time,
+                tranche.end_time,
+                current_time,
+            ))
+        })
+    }
+
+    // Part of `scheduled_amount` unlocked by achieved milestones; ungated vestings unlock it all
+    fn get_milestone_unlocked(&self, scheduled_amount: u64) -> u64 {
+        if self.milestones.is_empty() {
+            return scheduled_amount;
         }
+
+        let achieved_bps: u64 = self
+            .milestones
+            .iter()
+            .filter(|gate| gate.achieved)
+            .map(|gate| gate.unlock_bps as u64)
+            .sum();
+        ((scheduled_amount as u128) * (achieved_bps as u128) / 10_000) as u64
     }
 
-    fn get_linear_claimable(&self, current_time: i64) -> u64 {
-        if current_time >= self.end_time {
-            return self.total_amount - self.claimed_amount;
+    // Gate the vesting on `shares`, which must add up to 10,000 basis points. `milestones`
+    // holds the matching accounts in the same order; an empty list removes the gating.
+    pub fn set_milestones(&mut self, shares: &[MilestoneShare], milestones: &[Milestone], current_time: i64) -> Result<()> {
+        require!(self.is_active() && self.claimed_amount == 0, MiraiError::VestingNotActive);
+        require!(shares.len() <= Self::MAX_MILESTONES, MiraiError::InvalidMilestoneGates);
+
+        // A missed gate hands its share back to the DAO, so only a grant the authority could
+        // cancel anyway may be gated, and only before any of it has vested
+        if !shares.is_empty() {
+            self.terms.require_cancelable_by_authority()?;
+            require!(
+                self.get_scheduled_amount(current_time) == 0 && self.get_vested_amount(current_time) == 0,
+                MiraiError::MilestoneGatesLocked
+            );
         }
 
-        let elapsed = current_time - self.start_time;
-        let total_duration = self.end_time - self.start_time;
-        
-        if total_duration <= 0 {
-            return 0;
+        let mut total_bps: u16 = 0;
+        for (index, share) in shares.iter().enumerate() {
+            require!(share.unlock_bps > 0, MiraiError::InvalidMilestoneGates);
+            require!(
+                !shares[..index].iter().any(|other| other.milestone == share.milestone),
+                MiraiError::InvalidMilestoneGates
+            );
+            total_bps = total_bps.checked_add(share.unlock_bps).ok_or(MiraiError::InvalidMilestoneGates)?;
         }
+        require!(shares.is_empty() || total_bps == 10_000, MiraiError::InvalidMilestoneGates);
 
-        let vested_amount = (self.total_amount as f64 * elapsed as f64 / total_duration as f64) as u64;
-        let claimable = if vested_amount > self.claimed_amount {
-            vested_amount - self.claimed_amount
-        } else {
-            0
-        };
+        self.milestones = shares
+            .iter()
+            .zip(milestones.iter())
+            .map(|(share, milestone)| MilestoneGate {
+                milestone: share.milestone,
+                unlock_bps: share.unlock_bps,
+                achieved: milestone.achieved_at.is_some(),
+            })
+            .collect();
+        Ok(())
+    }
+
+    // Record that `milestone_key` was achieved. Returns false if it does not gate this vesting.
+    pub fn record_milestone_achieved(&mut self, milestone_key: &Pubkey) -> bool {
+        match self.milestones.iter_mut().find(|gate| gate.milestone == *milestone_key) {
+            Some(gate) => {
+                gate.achieved = true;
+                true
+            }
+            None => false,
+        }
+    }
+
+    // Forfeit the share gated on a missed milestone. The schedule is scaled down by that share
+    // and the remaining gates renormalized, so what unlocks from them is unchanged.
+    // Returns the amount to return to the treasury.
+    pub fn forfeit_milestone(&mut self, milestone_key: &Pubkey, current_time: i64) -> Result<u64> {
+        require!(self.status == VestingStatus::Active || self.status == VestingStatus::Paused, MiraiError::VestingCannotCancel);
+
+        let index = self
+            .milestones
+            .iter()
+            .position(|gate| gate.milestone == *milestone_key)
+            .ok_or(MiraiError::InvalidMilestone)?;
+        require!(!self.milestones[index].achieved, MiraiError::MilestoneAlreadyAchieved);
+        let forfeited_bps = self.milestones.remove(index).unlock_bps;
+
+        // Nothing else could ever unlock; close the vesting out on what was claimed
+        if self.milestones.is_empty() {
+            return Ok(self.freeze(current_time, self.claimed_amount));
+        }
+
+        let forfeit = |amount: u64| ((amount as u128) * (forfeited_bps as u128) / 10_000) as u64;
+        let base_amount = self.total_amount.saturating_sub(self.get_tranche_total());
+        let mut forfeited_amount = forfeit(base_amount);
+        for tranche in self.tranches.iter_mut() {
+            let tranche_forfeit = forfeit(tranche.amount);
+            tranche.amount -= tranche_forfeit;
+            forfeited_amount += tranche_forfeit;
+        }
+        self.total_amount -= forfeited_amount;
+
+        // Renormalize the remaining gates to 10,000 bps, the first gate taking the rounding
+        let remaining_bps = 10_000 - forfeited_bps as u32;
+        for gate in self.milestones.iter_mut() {
+            gate.unlock_bps = (gate.unlock_bps as u32 * 10_000 / remaining_bps) as u16;
+        }
+        let assigned_bps: u16 = self.milestones.iter().map(|gate| gate.unlock_bps).sum();
+        self.milestones[0].unlock_bps += 10_000 - assigned_bps;
+
+        if self.claimed_amount >= self.total_amount {
+            self.status = VestingStatus::Completed;
+        }
 
-        claimable
+        Ok(forfeited_amount)
     }
 
-    fn get_cliff_claimable(&self, current_time: i64) -> u64 {
-        if current_time < self.cliff_time {
+    // Amount held by tranches added on top of the base schedule
+    pub fn get_tranche_total(&self) -> u64 {
+        self.tranches.iter().map(|tranche| tranche.amount).sum()
+    }
+
+    // Unlock `unlock_bps` at start, then vest the rest linearly from start. Cliff vestings
+    // hold the linear part back until the cliff and release what accrued as a lump sum.
+    fn get_schedule_vested(
+        &self,
+        amount: u64,
+        unlock_bps: u16,
+        start_time: i64,
+        cliff_time: i64,
+        end_time: i64,
+        current_time: i64,
+    ) -> u64 {
+        if current_time < start_time {
             return 0;
         }
 
-        if current_time >= self.end_time {
-            return self.total_amount - self.claimed_amount;
+        let unlocked_amount = ((amount as u128) * (unlock_bps as u128) / 10000) as u64;
+        let linear_amount = amount.saturating_sub(unlocked_amount);
+
+        if self.vesting_type == VestingType::Cliff && current_time < cliff_time {
+            return unlocked_amount;
         }
 
-        let elapsed = current_time - self.cliff_time;
-        let total_duration = self.end_time - self.cliff_time;
-        
+        unlocked_amount.saturating_add(Self::interpolate(linear_amount, start_time, end_time, current_time))
+    }
+
+    fn interpolate(amount: u64, start_time: i64, end_time: i64, current_time: i64) -> u64 {
+        if current_time >= end_time {
+            return amount;
+        }
+
+        let elapsed = current_time - start_time;
+        let total_duration = end_time - start_time;
+
         if total_duration <= 0 {
             return 0;
         }
 
-        let vested_amount = (self.total_amount as f64 * elapsed as f64 / total_duration as f64) as u64;
-        let claimable = if vested_amount > self.claimed_amount {
-            vested_amount - self.claimed_amount
-        } else {
-            0
+        ((amount as u128) * (elapsed as u128) / (total_duration as u128)) as u64
+    }
+
+    // Start of whichever schedule begins first, the base or a tranche
+    pub fn get_earliest_start_time(&self) -> i64 {
+        self.tranches.iter().fold(self.start_time, |earliest, tranche| earliest.min(tranche.start_time))
+    }
+
+    // End of whichever schedule finishes last, the base or a tranche
+    pub fn get_latest_end_time(&self) -> i64 {
+        self.tranches.iter().fold(self.end_time, |latest, tranche| latest.max(tranche.end_time))
+    }
+
+    // Add a tranche vesting on its own schedule, e.g. an annual refresher
+    pub fn add_tranche(&mut self, tranche: VestingTranche) -> Result<()> {
+        require!(self.status == VestingStatus::Active || self.status == VestingStatus::Paused, MiraiError::VestingNotActive);
+        require!(self.tranches.len() < Self::MAX_TRANCHES, MiraiError::TooManyTranches);
+        require!(self.accelerated_at.is_none(), MiraiError::VestingAlreadyAccelerated);
+        tranche.validate(&self.vesting_type)?;
+        self.expiry_policy.validate(tranche.end_time)?;
+
+        self.total_amount = self.total_amount.saturating_add(tranche.amount);
+        self.tranches.push(tranche);
+        Ok(())
+    }
+
+    // Terms a change of control already covers are fixed, and only a cancelable grant's can be cut
+    pub fn set_acceleration_terms(&mut self, terms: Option<AccelerationTerms>, last_acceleration_at: Option<i64>) -> Result<()> {
+        require!(self.status == VestingStatus::Active || self.status == VestingStatus::Paused, MiraiError::VestingNotActive);
+        require!(self.accelerated_at.is_none(), MiraiError::VestingAlreadyAccelerated);
+        require!(
+            !matches!(last_acceleration_at, Some(triggered_at) if self.created_at <= triggered_at),
+            MiraiError::AccelerationTermsLocked
+        );
+        if let Some(terms) = &terms {
+            terms.validate()?;
+        }
+
+        let reduces = match (&self.acceleration_terms, &terms) {
+            (Some(current), Some(new)) => !new.covers(current),
+            (Some(_), None) => true,
+            (None, _) => false,
         };
+        if reduces {
+            self.terms.require_cancelable_by_authority()?;
+        }
 
-        claimable
+        self.acceleration_terms = terms;
+        Ok(())
+    }
+
+    // Whether the change of control declared at `triggered_at` accelerates this vesting now.
+    // Grants made after the change of control are not covered by it.
+    pub fn can_accelerate(&self, reason: Option<&TerminationReason>, triggered_at: i64, current_time: i64) -> bool {
+        self.accelerated_at.is_none()
+            && self.created_at <= triggered_at
+            && matches!(&self.acceleration_terms, Some(terms) if terms.is_triggered(reason, triggered_at, current_time))
+    }
+
+    // Accelerate the unvested share agreed in the terms. Returns the amount that accelerated.
+    pub fn accelerate(&mut self, current_time: i64) -> Result<u64> {
+        require!(self.status == VestingStatus::Active || self.status == VestingStatus::Paused, MiraiError::VestingNotActive);
+        require!(self.accelerated_at.is_none(), MiraiError::VestingAlreadyAccelerated);
+        require!(self.acceleration_terms.is_some(), MiraiError::AccelerationNotTriggered);
+
+        let vested_amount = self.get_vested_amount(current_time);
+        self.accelerated_at = Some(current_time);
+        Ok(self.get_vested_amount(current_time).saturating_sub(vested_amount))
+    }
+
+    // Whether `source` can be folded into this vesting without changing either side's terms
+    pub fn is_mergeable_with(&self, source: &Vesting) -> bool {
+        self.is_active()
+            && source.is_active()
+            && self.dao_config == source.dao_config
+            && self.treasury_mint == source.treasury_mint
+            && self.pool == source.pool
+            && self.option_terms == source.option_terms
+            && self.milestones == source.milestones
+            && self.vesting_type == source.vesting_type
+            && self.terms == source.terms
+            && self.expiry_policy == source.expiry_policy
+            && self.termination_policy == source.termination_policy
+            && self.acceleration_terms == source.acceleration_terms
+            && self.accelerated_at.is_none()
+            && source.accelerated_at.is_none()
+    }
+
+    // Fold `source` into this vesting. A base schedule matching ours is pooled with our base;
+    // otherwise it becomes a tranche, so each part keeps vesting on its own dates.
+    pub fn merge(&mut self, source: &Vesting) -> Result<()> {
+        require!(self.is_mergeable_with(source), MiraiError::IncompatibleVestings);
+
+        let same_schedule = self.start_time == source.start_time
+            && self.cliff_time == source.cliff_time
+            && self.end_time == source.end_time
+            && self.tge_unlock_bps == source.tge_unlock_bps;
+        let source_base_amount = source.total_amount.saturating_sub(source.get_tranche_total());
+
+        let mut tranches = self.tranches.clone();
+        if !same_schedule && source_base_amount > 0 {
+            // Tranches carry no TGE unlock of their own
+            require!(source.tge_unlock_bps == 0, MiraiError::IncompatibleVestings);
+            tranches.push(VestingTranche {
+                amount: source_base_amount,
+                start_time: source.start_time,
+                cliff_time: source.cliff_time,
+                end_time: source.end_time,
+            });
+        }
+        tranches.extend(source.tranches.iter().cloned());
+        require!(tranches.len() <= Self::MAX_TRANCHES, MiraiError::TooManyTranches);
+
+        self.tranches = tranches;
+        self.total_amount = self.total_amount.saturating_add(source.total_amount);
+        self.claimed_amount = self.claimed_amount.saturating_add(source.claimed_amount);
+        self.last_claim_at = self.last_claim_at.max(source.last_claim_at);
+        self.backdated = self.backdated || source.backdated;
+        Ok(())
+    }
+
+    // Carve `split_bps` of the base schedule, every tranche and the claimed amount into a new
+    // position on the same dates. The two halves always add back up to the original.
+    pub fn split(&mut self, split_bps: u16) -> Result<Vesting> {
+        require!(self.is_active(), MiraiError::VestingNotActive);
+        require!(split_bps > 0 && split_bps < 10_000, MiraiError::InvalidSplitShare);
+
+        let carve = |amount: u64| ((amount as u128) * (split_bps as u128) / 10_000) as u64;
+        let base_amount = self.total_amount.saturating_sub(self.get_tranche_total());
+
+        let mut carved = self.clone();
+        for (carved_tranche, tranche) in carved.tranches.iter_mut().zip(self.tranches.iter_mut()) {
+            carved_tranche.amount = carve(tranche.amount);
+            tranche.amount -= carved_tranche.amount;
+        }
+        carved.total_amount = carve(base_amount) + carved.get_tranche_total();
+        carved.claimed_amount = carve(self.claimed_amount);
+        require!(carved.total_amount > 0, MiraiError::InvalidSplitShare);
+
+        self.total_amount -= carved.total_amount;
+        self.claimed_amount -= carved.claimed_amount;
+        Ok(carved)
+    }
+
+    // Last moment an option grant can be exercised; a termination pulls it forward with the end time
+    pub fn get_exercise_deadline(&self) -> Option<i64> {
+        self.option_terms
+            .as_ref()
+            .map(|terms| self.end_time.saturating_add(terms.exercise_window))
+    }
+
+    pub fn is_option_expired(&self, current_time: i64) -> bool {
+        matches!(self.get_exercise_deadline(), Some(deadline) if current_time > deadline)
+    }
+
+    // Record `amount` of vested options exercised; the strike is collected by the caller
+    pub fn record_exercise(&mut self, amount: u64, current_time: i64) -> Result<()> {
+        require!(self.option_terms.is_some(), MiraiError::NotAnOptionGrant);
+        require!(!self.is_option_expired(current_time), MiraiError::OptionExpired);
+        self.record_claim(amount, current_time)
+    }
+
+    pub fn record_claim(&mut self, amount: u64, current_time: i64) -> Result<()> {
+        require!(amount > 0, MiraiError::InvalidClaimAmount);
+        require!(self.accepts_claims(), MiraiError::VestingNotActive);
+        require!(current_time >= self.get_earliest_start_time(), MiraiError::VestingNotStarted);
+
+        let claimable_amount = self.get_claimable_amount(current_time);
+        require!(amount <= claimable_amount, MiraiError::InsufficientVestedTokens);
+
+        self.claimed_amount += amount;
+        self.last_claim_at = current_time;
+
+        if self.claimed_amount >= self.total_amount {
+            self.status = VestingStatus::Completed;
+        }
+
+        Ok(())
     }
 
     pub fn is_active(&self) -> bool {
         self.status == VestingStatus::Active
     }
 
+    pub fn accepts_claims(&self) -> bool {
+        self.status == VestingStatus::Active || self.status == VestingStatus::Revoked
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.status == VestingStatus::Completed || self.status == VestingStatus::Cancelled
+    }
+
+    // The inactivity clock only starts once the whole schedule has vested
+    pub fn is_sweepable(&self, current_time: i64) -> bool {
+        let last_activity_at = self.last_claim_at.max(self.get_latest_end_time());
+        !self.is_finished()
+            && (self.expiry_policy.has_expired(last_activity_at, current_time) || self.is_option_expired(current_time))
+    }
+
     pub fn can_claim(&self, current_time: i64) -> bool {
         self.is_active() && self.get_claimable_amount(current_time) > 0
     }
@@ -136,8 +672,61 @@ impl Vesting {
         self.status = VestingStatus::Cancelled;
         Ok(())
     }
+
+    // Freeze the schedule at `current_time`: whatever has vested stays claimable and the
+    // rest is released. Returns the unvested amount to return to the treasury.
+    pub fn revoke(&mut self, current_time: i64) -> Result<u64> {
+        require!(self.status == VestingStatus::Active || self.status == VestingStatus::Paused, MiraiError::VestingCannotCancel);
+
+        // Paused vestings keep accruing on schedule, so measure the schedule directly
+        let vested_amount = self.get_vested_amount(current_time);
+        Ok(self.freeze(current_time, vested_amount))
+    }
+
+    // Apply the leaver terms for `reason` at `current_time`. Tokens already claimed are never
+    // clawed back. Returns the amount to return to the treasury.
+    pub fn terminate(&mut self, current_time: i64, reason: TerminationReason) -> Result<u64> {
+        require!(self.status == VestingStatus::Active || self.status == VestingStatus::Paused, MiraiError::VestingCannotCancel);
+
+        let policy = self.termination_policy.clone().unwrap_or_default();
+        let vested_amount = self.get_vested_amount(current_time);
+        let unvested_amount = self.total_amount.saturating_sub(vested_amount);
+        let retained_amount = policy
+            .get_leaver_terms(&reason)
+            .get_retained_amount(vested_amount, unvested_amount)
+            .max(self.claimed_amount);
+
+        let returned_amount = self.freeze(current_time, retained_amount);
+        self.termination_reason = Some(reason);
+        if let Some(claim_window) = policy.claim_window {
+            self.expiry_policy.claim_deadline = Some(current_time.saturating_add(claim_window));
+        }
+
+        Ok(returned_amount)
+    }
+
+    // End the schedule at `current_time` with `retained_amount` fully vested
+    fn freeze(&mut self, current_time: i64, retained_amount: u64) -> u64 {
+        let returned_amount = self.total_amount.saturating_sub(retained_amount);
+
+        self.tranches.clear();
+        self.milestones.clear();
+        self.total_amount = retained_amount;
+        self.start_time = self.start_time.min(current_time);
+        self.end_time = self.end_time.min(current_time);
+        self.cliff_time = self.cliff_time.min(current_time);
+        self.revoked_at = Some(current_time);
+        self.status = if self.claimed_amount >= self.total_amount {
+            VestingStatus::Completed
+        } else {
+            VestingStatus::Revoked
+        };
+
+        returned_amount
+    }
 }
 
 
-use crate::state::config::PaymentCategory;
+use crate::state::config::{ExpiryPolicy, PaymentCategory, PositionTerms};
+use crate::state::milestone::{Milestone, MilestoneGate, MilestoneShare};
 use crate::errors::MiraiError; 
\ No newline at end of file
@@ -0,0 +1,209 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Allocate, Assign};
+use anchor_spl::token::{TokenAccount, Transfer};
+use crate::errors::MiraiError;
+use crate::utils::close_if_exists;
+
+/// Withholding rate the DAO applies to payouts from a single stream or vesting
+#[account]
+pub struct WithholdingPolicy {
+    /// Stream or vesting account this policy applies to
+    pub position: Pubkey,
+    /// DAO configuration that set the policy
+    pub dao_config: Pubkey,
+    /// Recipient whose payouts are withheld from
+    pub recipient: Pubkey,
+    /// Share of each payout withheld, in basis points
+    pub rate_bps: u16,
+    /// Bump seed for the PDA
+    pub bump: u8,
+    /// Timestamp when the rate was last set
+    pub updated_at: i64,
+}
+
+impl WithholdingPolicy {
+    /// Basis points in a whole payout
+    pub const TOTAL_BPS: u16 = 10_000;
+
+    /// Size of the account in bytes
+    pub const SIZE: usize = 8 + // discriminator
+        32 +
+        32 +
+        32 +
+        2 +
+        1 +
+        8;
+
+    /// Validate a withholding rate before storing it
+    pub fn validate_rate(rate_bps: u16) -> Result<()> {
+        require!(rate_bps > 0 && rate_bps < Self::TOTAL_BPS, MiraiError::InvalidWithholdingRate);
+        Ok(())
+    }
+
+    /// Load the policy at `account_info`, or `None` if the DAO never set one
+    pub fn load(account_info: &AccountInfo) -> Result<Option<WithholdingPolicy>> {
+        if account_info.owner != &crate::ID || account_info.data_is_empty() {
+            return Ok(None);
+        }
+        let data = account_info.try_borrow_data()?;
+        Ok(Some(WithholdingPolicy::try_deserialize(&mut &data[..])?))
+    }
+
+    /// Recreate the policy at `policy_info` for a position that moved to `new_position`,
+    /// so withholding follows a transferred position. The old policy's rent goes back to
+    /// `authority`, who paid it. Does nothing if the position has no policy.
+    #[allow(clippy::too_many_arguments)]
+    pub fn migrate<'info>(
+        policy_info: &AccountInfo<'info>,
+        new_policy_info: &AccountInfo<'info>,
+        new_policy_bump: u8,
+        new_position: Pubkey,
+        new_recipient: Pubkey,
+        payer: &AccountInfo<'info>,
+        authority: &AccountInfo<'info>,
+        system_program: &AccountInfo<'info>,
+    ) -> Result<()> {
+        let Some(mut policy) = Self::load(policy_info)? else {
+            return Ok(());
+        };
+        policy.position = new_position;
+        policy.recipient = new_recipient;
+        policy.bump = new_policy_bump;
+
+        // Top up, allocate and assign rather than create, so lamports sent to the address
+        // ahead of time cannot block the transfer
+        let shortfall = Rent::get()?
+            .minimum_balance(Self::SIZE)
+            .saturating_sub(new_policy_info.lamports());
+        if shortfall > 0 {
+            let transfer_ctx = CpiContext::new(
+                system_program.clone(),
+                system_program::Transfer {
+                    from: payer.clone(),
+                    to: new_policy_info.clone(),
+                },
+            );
+            system_program::transfer(transfer_ctx, shortfall)?;
+        }
+
+        let seeds: &[&[u8]] = &[b"withholding", new_position.as_ref(), &[new_policy_bump]];
+        let signer_seeds = &[seeds];
+        let allocate_ctx = CpiContext::new_with_signer(
+            system_program.clone(),
+            Allocate {
+                account_to_allocate: new_policy_info.clone(),
+            },
+            signer_seeds,
+        );
+        system_program::allocate(allocate_ctx, Self::SIZE as u64)?;
+        let assign_ctx = CpiContext::new_with_signer(
+            system_program.clone(),
+            Assign {
+                account_to_assign: new_policy_info.clone(),
+            },
+            signer_seeds,
+        );
+        system_program::assign(assign_ctx, &crate::ID)?;
+        policy.try_serialize(&mut &mut new_policy_info.try_borrow_mut_data()?[..])?;
+
+        close_if_exists(policy_info, authority)
+    }
+
+    /// Amount withheld from a payout of `amount`
+    pub fn get_withheld_amount(&self, amount: u64) -> u64 {
+        ((amount as u128) * (self.rate_bps as u128) / (Self::TOTAL_BPS as u128)) as u64
+    }
+
+    /// Withhold from a payout of `amount` if the position has a policy, moving the withheld
+    /// share into the vault and recording it on the ledger. Returns the amount left to pay out.
+    #[allow(clippy::too_many_arguments)]
+    pub fn apply<'info>(
+        policy_info: &AccountInfo<'info>,
+        vault: Option<&Account<'info, TokenAccount>>,
+        ledger: Option<&mut Account<'info, WithholdingLedger>>,
+        amount: u64,
+        from: AccountInfo<'info>,
+        authority: AccountInfo<'info>,
+        token_program: AccountInfo<'info>,
+        current_time: i64,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<u64> {
+        let Some(policy) = Self::load(policy_info)? else {
+            return Ok(amount);
+        };
+
+        let vault = vault.ok_or(MiraiError::WithholdingAccountsMissing)?;
+        let ledger = ledger.ok_or(MiraiError::WithholdingAccountsMissing)?;
+        let withheld_amount = policy.get_withheld_amount(amount);
+        ledger.record(amount, withheld_amount, current_time)?;
+
+        if withheld_amount > 0 {
+            let transfer_ctx = CpiContext::new_with_signer(
+                token_program,
+                Transfer {
+                    from,
+                    to: vault.to_account_info(),
+                    authority,
+                },
+                signer_seeds,
+            );
+            anchor_spl::token::transfer(transfer_ctx, withheld_amount)?;
+        }
+
+        Ok(amount.saturating_sub(withheld_amount))
+    }
+}
+
+/// Cumulative withholding for one recipient over one calendar year, for year-end reporting
+#[account]
+pub struct WithholdingLedger {
+    /// DAO configuration the payouts came from
+    pub dao_config: Pubkey,
+    /// Recipient the amounts were withheld from
+    pub recipient: Pubkey,
+    /// Calendar year (UTC) the ledger covers
+    pub year: u16,
+    /// Total paid out before withholding
+    pub gross_amount: u64,
+    /// Total withheld into the vault
+    pub withheld_amount: u64,
+    /// Number of payouts recorded
+    pub payout_count: u32,
+    /// Bump seed for the PDA
+    pub bump: u8,
+}
+
+impl WithholdingLedger {
+    /// Size of the account in bytes
+    pub const SIZE: usize = 8 + // discriminator
+        32 +
+        32 +
+        2 +
+        8 +
+        8 +
+        4 +
+        1;
+
+    /// Calendar year (UTC) containing `timestamp`
+    pub fn year_of(timestamp: i64) -> u16 {
+        // Civil-from-days conversion on a calendar that starts each era on March 1st
+        let days = timestamp.div_euclid(86_400);
+        let shifted_days = days + 719_468;
+        let era = shifted_days.div_euclid(146_097);
+        let day_of_era = shifted_days - era * 146_097;
+        let year_of_era = (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let shifted_month = (5 * day_of_year + 2) / 153;
+        let year = year_of_era + era * 400 + if shifted_month >= 10 { 1 } else { 0 };
+        year as u16
+    }
+
+    /// Record a payout of `gross_amount` with `withheld_amount` withheld
+    pub fn record(&mut self, gross_amount: u64, withheld_amount: u64, current_time: i64) -> Result<()> {
+        require!(self.year == Self::year_of(current_time), MiraiError::WithholdingLedgerYearMismatch);
+        self.gross_amount = self.gross_amount.saturating_add(gross_amount);
+        self.withheld_amount = self.withheld_amount.saturating_add(withheld_amount);
+        self.payout_count = self.payout_count.saturating_add(1);
+        Ok(())
+    }
+}
@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+
+/// Close a program-owned account at `account_info` if it exists, refunding its rent to
+/// `destination`. Used for optional PDAs that are only ever created on demand.
+pub fn close_if_exists<'info>(
+    account_info: &AccountInfo<'info>,
+    destination: &AccountInfo<'info>,
+) -> Result<()> {
+    if account_info.owner != &crate::ID || account_info.data_is_empty() {
+        return Ok(());
+    }
+
+    let lamports = account_info.lamports();
+    destination.add_lamports(lamports)?;
+    account_info.sub_lamports(lamports)?;
+    account_info.assign(&anchor_lang::system_program::ID);
+    account_info.realloc(0, false)?;
+    Ok(())
+}
@@ -13,6 +13,7 @@ import {
   TOKEN_PROGRAM_ID,
   ASSOCIATED_TOKEN_PROGRAM_ID,
   getAssociatedTokenAddress,
+  getAssociatedTokenAddressSync,
   createMint,
   createAccount,
   mintTo,
@@ -70,6 +71,13 @@ describe("mirai", () => {
     }
   };
 
+  // Position terms used by tests that don't exercise cancellation or transfer rules
+  const defaultTerms = {
+    cancelableByAuthority: true,
+    cancelableByRecipient: true,
+    transferable: false,
+  };
+
   // Helper function to get current timestamp
   const getCurrentTimestamp = (): number => {
     return Math.floor(Date.now() / 1000);
@@ -214,7 +222,10 @@ describe("mirai", () => {
             new anchor.BN(endTime), 
             new anchor.BN(totalAmount),
             { contributors: {} }, // V2: PaymentCategory
-            "Test contributor stream" // V2: description
+            "Test contributor stream", // V2: description
+            null,
+            null,
+            defaultTerms
           )
           .accounts({
             daoConfig,
@@ -281,7 +292,10 @@ describe("mirai", () => {
             new anchor.BN(endTime), 
             new anchor.BN(totalAmount),
             { contributors: {} },
-            "Invalid timing test"
+            "Invalid timing test",
+            null,
+            null,
+            defaultTerms
           )
           .accounts({
             daoConfig,
@@ -325,7 +339,10 @@ describe("mirai", () => {
             new anchor.BN(endTime), 
             new anchor.BN(totalAmount),
             { contributors: {} },
-            "Zero amount test"
+            "Zero amount test",
+            null,
+            null,
+            defaultTerms
           )
           .accounts({
             daoConfig,
@@ -598,7 +615,10 @@ describe("mirai", () => {
           new anchor.BN(endTime), 
           new anchor.BN(totalAmount),
           { grants: {} },
-          "Test calculation stream"
+          "Test calculation stream",
+          null,
+          null,
+          defaultTerms
         )
         .accounts({
           daoConfig,
@@ -692,7 +712,10 @@ describe("mirai", () => {
               new anchor.BN(endTime + i * 10), 
               new anchor.BN(totalAmount),
               category,
-              description
+              description,
+              null,
+              null,
+              defaultTerms
             )
             .accounts({
               daoConfig,
@@ -746,7 +769,10 @@ describe("mirai", () => {
             new anchor.BN(endTime), 
             new anchor.BN(totalAmount),
             { contributors: {} },
-            longDescription
+            longDescription,
+            null,
+            null,
+            defaultTerms
           )
           .accounts({
             daoConfig,
@@ -804,7 +830,10 @@ describe("mirai", () => {
           new anchor.BN(endTime), 
           new anchor.BN(totalAmount),
           { operations: {} },
-          "Treasury tracking test"
+          "Treasury tracking test",
+          null,
+          null,
+          defaultTerms
         )
         .accounts({
           daoConfig,
@@ -854,7 +883,10 @@ describe("mirai", () => {
           new anchor.BN(endTime), 
           new anchor.BN(totalAmount),
           { marketing: {} },
-          "Redemption tracking test"
+          "Redemption tracking test",
+          null,
+          null,
+          defaultTerms
         )
         .accounts({
           daoConfig,
@@ -928,7 +960,10 @@ describe("mirai", () => {
           new anchor.BN(endTime), 
           new anchor.BN(totalAmount),
           { development: {} },
-          "Status completion test"
+          "Status completion test",
+          null,
+          null,
+          defaultTerms
         )
         .accounts({
           daoConfig,
@@ -1001,7 +1036,10 @@ describe("mirai", () => {
           new anchor.BN(endTime), 
           new anchor.BN(totalAmount),
           { other: {} },
-          "Completed stream test"
+          "Completed stream test",
+          null,
+          null,
+          defaultTerms
         )
         .accounts({
           daoConfig,
@@ -1127,7 +1165,10 @@ describe("mirai", () => {
             new anchor.BN(endTime), 
             new anchor.BN(totalAmount),
             { contributors: {} },
-            "Error handling test"
+            "Error handling test",
+            null,
+            null,
+            defaultTerms
           )
           .accounts({
             daoConfig,
@@ -1197,7 +1238,12 @@ describe("mirai", () => {
             new anchor.BN(endTime),
             new anchor.BN(cliffTime),
             { contributors: {} },
-            "Linear vesting for contributor"
+            "Linear vesting for contributor",
+            null,
+            defaultTerms,
+            null,
+            null,
+            null
           )
           .accounts({
             daoConfig: daoConfig,
@@ -1249,7 +1295,12 @@ describe("mirai", () => {
             new anchor.BN(endTime),
             new anchor.BN(cliffTime),
             { grants: {} },
-            "Cliff vesting for grant recipient"
+            "Cliff vesting for grant recipient",
+            null,
+            defaultTerms,
+            null,
+            null,
+            null
           )
           .accounts({
             daoConfig: daoConfig,
@@ -1294,7 +1345,12 @@ describe("mirai", () => {
             new anchor.BN(endTime),
             new anchor.BN(startTime),
             { contributors: {} },
-            "Invalid timing vesting"
+            "Invalid timing vesting",
+            null,
+            defaultTerms,
+            null,
+            null,
+            null
           )
           .accounts({
             daoConfig: daoConfig,
@@ -1333,7 +1389,12 @@ describe("mirai", () => {
             new anchor.BN(endTime),
             new anchor.BN(startTime),
             { contributors: {} },
-            longDescription
+            longDescription,
+            null,
+            defaultTerms,
+            null,
+            null,
+            null
           )
           .accounts({
             daoConfig: daoConfig,
@@ -1403,7 +1464,12 @@ describe("mirai", () => {
           new anchor.BN(endTime),
           new anchor.BN(startTime),
           { contributors: {} },
-          "Future vesting"
+          "Future vesting",
+          null,
+          defaultTerms,
+          null,
+          null,
+          null
         )
         .accounts({
           daoConfig: daoConfig,
@@ -1467,7 +1533,12 @@ describe("mirai", () => {
           new anchor.BN(endTime),
           new anchor.BN(startTime),
           { contributors: {} },
-          "Short linear vesting"
+          "Short linear vesting",
+          null,
+          defaultTerms,
+          null,
+          null,
+          null
         )
         .accounts({
           daoConfig: daoConfig,
@@ -1528,7 +1599,12 @@ describe("mirai", () => {
           new anchor.BN(endTime),
           new anchor.BN(startTime),
           { contributors: {} },
-          "Complete vesting test"
+          "Complete vesting test",
+          null,
+          defaultTerms,
+          null,
+          null,
+          null
         )
         .accounts({
           daoConfig: daoConfig,
@@ -1607,7 +1683,12 @@ describe("mirai", () => {
           new anchor.BN(endTime),
           new anchor.BN(startTime),
           { contributors: {} },
-          "Treasury analytics test"
+          "Treasury analytics test",
+          null,
+          defaultTerms,
+          null,
+          null,
+          null
         )
         .accounts({
           daoConfig: daoConfig,
@@ -1633,4 +1714,3933 @@ describe("mirai", () => {
       console.log("✅ Treasury analytics tracking vesting correctly");
     });
   });
+
+  describe("Account Closure", () => {
+    it("Should close a completed stream and reclaim rent", async () => {
+      // The primary stream was fully redeemed in "Stream Redemption"
+      const streamAccount = await program.account.stream.fetch(stream);
+      assert.deepEqual(streamAccount.status, { completed: {} });
+
+      const balanceBefore = await provider.connection.getBalance(daoAuthority.publicKey);
+
+      await program.methods
+        .closeStream()
+        .accounts({
+          daoConfig,
+          stream,
+          authority: daoAuthority.publicKey,
+          streamAta,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([daoAuthority])
+        .rpc();
+
+      assert.isNull(await program.account.stream.fetchNullable(stream));
+      assert.isNull(await provider.connection.getAccountInfo(streamAta));
+
+      const balanceAfter = await provider.connection.getBalance(daoAuthority.publicKey);
+      assert.isAbove(balanceAfter, balanceBefore);
+
+      console.log("✅ Completed stream closed and rent returned to authority");
+    });
+
+    it("Should fail to close an active vesting", async () => {
+      const testVestingRecipient = Keypair.generate();
+      const [testVesting] = getPda([
+        Buffer.from("vesting"),
+        daoConfig.toBuffer(),
+        testVestingRecipient.publicKey.toBuffer(),
+      ]);
+      const testVestingAta = await getAssociatedTokenAddress(treasuryMint, testVesting, true);
+
+      const startTime = getCurrentTimestamp() + 10;
+      await program.methods
+        .createVesting(
+          { linear: {} },
+          new anchor.BN(1000),
+          new anchor.BN(startTime),
+          new anchor.BN(startTime + 60),
+          new anchor.BN(startTime),
+          { contributors: {} },
+          "Close rejection test",
+          null,
+          defaultTerms,
+          null,
+          null,
+          null
+        )
+        .accounts({
+          daoConfig,
+          authority: daoAuthority.publicKey,
+          recipient: testVestingRecipient.publicKey,
+          vesting: testVesting,
+          vestingAta: testVestingAta,
+          treasuryMint,
+          authorityAta,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([daoAuthority])
+        .rpc();
+
+      try {
+        await program.methods
+          .closeVesting()
+          .accounts({
+            daoConfig,
+            vesting: testVesting,
+            authority: daoAuthority.publicKey,
+            vestingAta: testVestingAta,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([daoAuthority])
+          .rpc();
+
+        assert.fail("Should have thrown an error");
+      } catch (error) {
+        assert.include(error.toString(), "VestingNotFinished");
+        console.log("✅ Correctly rejected closing an active vesting");
+      }
+    });
+  });
+
+  describe("Metadata Updates", () => {
+    it("Should update stream category and description", async () => {
+      const currentTime = getCurrentTimestamp();
+      const testRecipient = Keypair.generate();
+      const [testStream] = getPda([
+        Buffer.from("stream"),
+        daoConfig.toBuffer(),
+        testRecipient.publicKey.toBuffer(),
+      ]);
+
+      await program.methods
+        .createStream(
+          new anchor.BN(currentTime + 10),
+          new anchor.BN(currentTime + 100),
+          new anchor.BN(10000000),
+          { contributors: {} },
+          "Frontend contributor",
+          null,
+          null,
+          defaultTerms
+        )
+        .accounts({
+          daoConfig,
+          authority: daoAuthority.publicKey,
+          recipient: testRecipient.publicKey,
+          stream: testStream,
+          treasuryMint,
+          authorityAta,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([daoAuthority])
+        .rpc();
+
+      await program.methods
+        .updateMetadata({ development: {} }, "Protocol engineering")
+        .accounts({
+          daoConfig,
+          authority: daoAuthority.publicKey,
+          stream: testStream,
+          vesting: null,
+        })
+        .signers([daoAuthority])
+        .rpc();
+
+      const streamAccount = await program.account.stream.fetch(testStream);
+      assert.deepEqual(streamAccount.category, { development: {} });
+      assert.equal(streamAccount.description, "Protocol engineering");
+
+      console.log("✅ Stream metadata updated");
+    });
+
+    it("Should fail to update metadata without a target position", async () => {
+      try {
+        await program.methods
+          .updateMetadata(null, "No target")
+          .accounts({
+            daoConfig,
+            authority: daoAuthority.publicKey,
+            stream: null,
+            vesting: null,
+          })
+          .signers([daoAuthority])
+          .rpc();
+
+        assert.fail("Should have thrown an error");
+      } catch (error) {
+        assert.include(error.toString(), "InvalidPositionTarget");
+        console.log("✅ Correctly rejected metadata update without target");
+      }
+    });
+  });
+
+  describe("Delegated Withdrawal Operators", () => {
+    let coldRecipient: Keypair;
+    let hotOperator: Keypair;
+    let operatorStream: PublicKey;
+    let operatorStreamAta: PublicKey;
+    let operatorDelegation: PublicKey;
+    let coldRecipientAta: PublicKey;
+
+    before(async () => {
+      coldRecipient = Keypair.generate();
+      hotOperator = Keypair.generate();
+
+      for (const keypair of [coldRecipient, hotOperator]) {
+        await provider.connection.confirmTransaction(
+          await provider.connection.requestAirdrop(keypair.publicKey, LAMPORTS_PER_SOL)
+        );
+      }
+
+      [operatorStream] = getPda([
+        Buffer.from("stream"),
+        daoConfig.toBuffer(),
+        coldRecipient.publicKey.toBuffer(),
+      ]);
+      [operatorDelegation] = getPda([Buffer.from("operator"), operatorStream.toBuffer()]);
+
+      const currentTime = getCurrentTimestamp();
+      await program.methods
+        .createStream(
+          new anchor.BN(currentTime + 5),
+          new anchor.BN(currentTime + 25),
+          new anchor.BN(20000000),
+          { contributors: {} },
+          "Cold wallet contributor",
+          null,
+          null,
+          defaultTerms
+        )
+        .accounts({
+          daoConfig,
+          authority: daoAuthority.publicKey,
+          recipient: coldRecipient.publicKey,
+          stream: operatorStream,
+          treasuryMint,
+          authorityAta,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([daoAuthority])
+        .rpc();
+
+      operatorStreamAta = (await program.account.stream.fetch(operatorStream)).streamAta;
+      coldRecipientAta = await createTokenAccount(treasuryMint, coldRecipient.publicKey);
+    });
+
+    it("Should register an operator with limits", async () => {
+      await program.methods
+        .setStreamOperator(
+          hotOperator.publicKey,
+          new anchor.BN(5000000),
+          new anchor.BN(getCurrentTimestamp() + 3600),
+          false
+        )
+        .accounts({
+          stream: operatorStream,
+          delegation: operatorDelegation,
+          recipient: coldRecipient.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([coldRecipient])
+        .rpc();
+
+      const delegation = await program.account.operatorDelegation.fetch(operatorDelegation);
+      assert.equal(delegation.operator.toString(), hotOperator.publicKey.toString());
+      assert.equal(delegation.maxAmountPerClaim.toNumber(), 5000000);
+      assert.equal(delegation.allowAnyDestination, false);
+
+      console.log("✅ Operator registered for stream");
+    });
+
+    it("Should let the operator redeem into the recipient's ATA", async () => {
+      // Wait for the stream to finish so the full balance is unlocked
+      await new Promise(resolve => setTimeout(resolve, 30000));
+
+      await program.methods
+        .operatorRedeemStream(new anchor.BN(5000000))
+        .accounts({
+          daoConfig,
+          stream: operatorStream,
+          recipient: coldRecipient.publicKey,
+          operator: hotOperator.publicKey,
+          delegation: operatorDelegation,
+          streamAta: operatorStreamAta,
+          destination: coldRecipientAta,
+          treasuryMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([hotOperator])
+        .rpc();
+
+      const recipientBalance = await getAccount(provider.connection, coldRecipientAta);
+      assert.equal(Number(recipientBalance.amount), 5000000);
+
+      console.log("✅ Operator redeemed on behalf of recipient");
+    });
+
+    it("Should reject operator withdrawals above the per-claim limit", async () => {
+      try {
+        await program.methods
+          .operatorRedeemStream(new anchor.BN(6000000))
+          .accounts({
+            daoConfig,
+            stream: operatorStream,
+            recipient: coldRecipient.publicKey,
+            operator: hotOperator.publicKey,
+            delegation: operatorDelegation,
+            streamAta: operatorStreamAta,
+            destination: coldRecipientAta,
+            treasuryMint,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([hotOperator])
+          .rpc();
+
+        assert.fail("Should have thrown an error");
+      } catch (error) {
+        assert.include(error.toString(), "OperatorLimitExceeded");
+        console.log("✅ Correctly rejected withdrawal above operator limit");
+      }
+    });
+
+    it("Should reject operator payouts to other accounts", async () => {
+      const operatorAta = await createTokenAccount(treasuryMint, hotOperator.publicKey);
+
+      try {
+        await program.methods
+          .operatorRedeemStream(new anchor.BN(1000000))
+          .accounts({
+            daoConfig,
+            stream: operatorStream,
+            recipient: coldRecipient.publicKey,
+            operator: hotOperator.publicKey,
+            delegation: operatorDelegation,
+            streamAta: operatorStreamAta,
+            destination: operatorAta,
+            treasuryMint,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([hotOperator])
+          .rpc();
+
+        assert.fail("Should have thrown an error");
+      } catch (error) {
+        assert.include(error.toString(), "InvalidPayoutDestination");
+        console.log("✅ Correctly rejected operator payout to foreign account");
+      }
+    });
+  });
+
+  describe("Scheduled Rate Changes", () => {
+    it("Should schedule a raise and deposit the funding difference", async () => {
+      const currentTime = getCurrentTimestamp();
+      const startTime = currentTime + 5;
+      const endTime = currentTime + 1005;
+      const totalAmount = 100000000;
+
+      const testRecipient = Keypair.generate();
+      const [testStream] = getPda([
+        Buffer.from("stream"),
+        daoConfig.toBuffer(),
+        testRecipient.publicKey.toBuffer(),
+      ]);
+
+      await program.methods
+        .createStream(
+          new anchor.BN(startTime),
+          new anchor.BN(endTime),
+          new anchor.BN(totalAmount),
+          { contributors: {} },
+          "Salary stream",
+          null,
+          null,
+          defaultTerms
+        )
+        .accounts({
+          daoConfig,
+          authority: daoAuthority.publicKey,
+          recipient: testRecipient.publicKey,
+          stream: testStream,
+          treasuryMint,
+          authorityAta,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([daoAuthority])
+        .rpc();
+
+      const testStreamAta = (await program.account.stream.fetch(testStream)).streamAta;
+
+      // Halfway through, stream 80 tokens over the remaining half instead of 50
+      const effectiveTime = startTime + 500;
+      const newSegmentAmount = 80000000;
+
+      await program.methods
+        .scheduleRateChange(new anchor.BN(effectiveTime), new anchor.BN(newSegmentAmount))
+        .accounts({
+          daoConfig,
+          authority: daoAuthority.publicKey,
+          stream: testStream,
+          streamAta: testStreamAta,
+          treasuryMint,
+          authorityAta,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([daoAuthority])
+        .rpc();
+
+      const streamAccount = await program.account.stream.fetch(testStream);
+      assert.equal(streamAccount.pendingRateChange.effectiveTime.toNumber(), effectiveTime);
+      assert.equal(
+        streamAccount.pendingRateChange.newTotalAmount.toNumber(),
+        totalAmount / 2 + newSegmentAmount
+      );
+      assert.equal(streamAccount.totalAmount.toNumber(), totalAmount);
+
+      const streamTokenAccount = await getAccount(provider.connection, testStreamAta);
+      assert.equal(Number(streamTokenAccount.amount), totalAmount / 2 + newSegmentAmount);
+
+      console.log("✅ Rate change scheduled and funded");
+    });
+  });
+
+  describe("Stream Acceptance", () => {
+    const createOffer = async (offerRecipient: Keypair, deadline: number): Promise<PublicKey> => {
+      const [offerStream] = getPda([
+        Buffer.from("stream"),
+        daoConfig.toBuffer(),
+        offerRecipient.publicKey.toBuffer(),
+      ]);
+      const currentTime = getCurrentTimestamp();
+
+      await program.methods
+        .createStream(
+          new anchor.BN(currentTime + 5),
+          new anchor.BN(currentTime + 105),
+          new anchor.BN(10000000),
+          { contributors: {} },
+          "Offer awaiting acceptance",
+          new anchor.BN(deadline),
+          null,
+          defaultTerms
+        )
+        .accounts({
+          daoConfig,
+          authority: daoAuthority.publicKey,
+          recipient: offerRecipient.publicKey,
+          stream: offerStream,
+          treasuryMint,
+          authorityAta,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([daoAuthority])
+        .rpc();
+
+      return offerStream;
+    };
+
+    it("Should accept a pending stream with an agreement hash", async () => {
+      const offerRecipient = Keypair.generate();
+      await provider.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(offerRecipient.publicKey, LAMPORTS_PER_SOL)
+      );
+      const offerStream = await createOffer(offerRecipient, getCurrentTimestamp() + 600);
+
+      const pendingStream = await program.account.stream.fetch(offerStream);
+      assert.deepEqual(pendingStream.status, { pending: {} });
+
+      const agreementHash = Array.from(Buffer.alloc(32, 7));
+      await program.methods
+        .acceptStream(agreementHash)
+        .accounts({
+          stream: offerStream,
+          recipient: offerRecipient.publicKey,
+        })
+        .signers([offerRecipient])
+        .rpc();
+
+      const acceptedStream = await program.account.stream.fetch(offerStream);
+      assert.deepEqual(acceptedStream.status, { active: {} });
+      assert.deepEqual(acceptedStream.agreementHash, agreementHash);
+      assert.isNotNull(acceptedStream.acceptedAt);
+
+      console.log("✅ Pending stream accepted by recipient");
+    });
+
+    it("Should let the authority withdraw an expired offer", async () => {
+      const offerRecipient = Keypair.generate();
+      const offerStream = await createOffer(offerRecipient, getCurrentTimestamp() + 3);
+      const offerStreamAta = (await program.account.stream.fetch(offerStream)).streamAta;
+      const authorityBalanceBefore = await getAccount(provider.connection, authorityAta);
+
+      await new Promise(resolve => setTimeout(resolve, 6000));
+
+      await program.methods
+        .withdrawStreamOffer()
+        .accounts({
+          daoConfig,
+          authority: daoAuthority.publicKey,
+          stream: offerStream,
+          streamAta: offerStreamAta,
+          authorityAta,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([daoAuthority])
+        .rpc();
+
+      const cancelledStream = await program.account.stream.fetch(offerStream);
+      assert.deepEqual(cancelledStream.status, { cancelled: { reason: { offerWithdrawn: {} } } });
+
+      const authorityBalanceAfter = await getAccount(provider.connection, authorityAta);
+      assert.equal(
+        Number(authorityBalanceAfter.amount),
+        Number(authorityBalanceBefore.amount) + 10000000
+      );
+
+      console.log("✅ Expired offer withdrawn and deposit recovered");
+    });
+  });
+
+  describe("Unclaimed Fund Sweeps", () => {
+    it("Should sweep an unclaimed vesting after its claim deadline", async () => {
+      const lostKeyRecipient = Keypair.generate();
+      const [testVesting] = getPda([
+        Buffer.from("vesting"),
+        daoConfig.toBuffer(),
+        lostKeyRecipient.publicKey.toBuffer(),
+      ]);
+      const testVestingAta = await getAssociatedTokenAddress(treasuryMint, testVesting, true);
+
+      const startTime = getCurrentTimestamp() + 2;
+      const endTime = startTime + 5;
+      const claimDeadline = endTime + 2;
+
+      await program.methods
+        .createVesting(
+          { linear: {} },
+          new anchor.BN(1000),
+          new anchor.BN(startTime),
+          new anchor.BN(endTime),
+          new anchor.BN(startTime),
+          { contributors: {} },
+          "Lost key vesting",
+          { claimDeadline: new anchor.BN(claimDeadline), inactivityPeriod: null },
+          defaultTerms,
+          null,
+          null,
+          null
+        )
+        .accounts({
+          daoConfig,
+          authority: daoAuthority.publicKey,
+          recipient: lostKeyRecipient.publicKey,
+          vesting: testVesting,
+          vestingAta: testVestingAta,
+          treasuryMint,
+          authorityAta,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([daoAuthority])
+        .rpc();
+
+      const daoConfigBefore = await program.account.daoConfig.fetch(daoConfig);
+      const authorityBalanceBefore = await getAccount(provider.connection, authorityAta);
+
+      await new Promise(resolve => setTimeout(resolve, (claimDeadline - getCurrentTimestamp() + 3) * 1000));
+
+      await program.methods
+        .sweepVesting()
+        .accounts({
+          daoConfig,
+          authority: daoAuthority.publicKey,
+          vesting: testVesting,
+          vestingAta: testVestingAta,
+          authorityAta,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([daoAuthority])
+        .rpc();
+
+      const vestingAccount = await program.account.vesting.fetch(testVesting);
+      assert.deepEqual(vestingAccount.status, { cancelled: {} });
+
+      const authorityBalanceAfter = await getAccount(provider.connection, authorityAta);
+      assert.equal(Number(authorityBalanceAfter.amount), Number(authorityBalanceBefore.amount) + 1000);
+
+      const daoConfigAfter = await program.account.daoConfig.fetch(daoConfig);
+      assert.equal(
+        daoConfigAfter.totalAllocated.toNumber(),
+        daoConfigBefore.totalAllocated.toNumber() - 1000
+      );
+
+      console.log("✅ Unclaimed vesting swept back to treasury");
+    });
+
+    it("Should fail to sweep a stream without an expired policy", async () => {
+      const testRecipient = Keypair.generate();
+      const [testStream] = getPda([
+        Buffer.from("stream"),
+        daoConfig.toBuffer(),
+        testRecipient.publicKey.toBuffer(),
+      ]);
+      const currentTime = getCurrentTimestamp();
+
+      await program.methods
+        .createStream(
+          new anchor.BN(currentTime + 5),
+          new anchor.BN(currentTime + 100),
+          new anchor.BN(1000000),
+          { contributors: {} },
+          "Inactivity sweep test",
+          null,
+          { claimDeadline: null, inactivityPeriod: new anchor.BN(365 * 24 * 60 * 60) },
+          defaultTerms
+        )
+        .accounts({
+          daoConfig,
+          authority: daoAuthority.publicKey,
+          recipient: testRecipient.publicKey,
+          stream: testStream,
+          treasuryMint,
+          authorityAta,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([daoAuthority])
+        .rpc();
+
+      const testStreamAta = (await program.account.stream.fetch(testStream)).streamAta;
+
+      try {
+        await program.methods
+          .sweepStream()
+          .accounts({
+            daoConfig,
+            authority: daoAuthority.publicKey,
+            stream: testStream,
+            streamAta: testStreamAta,
+            authorityAta,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([daoAuthority])
+          .rpc();
+
+        assert.fail("Should have thrown an error");
+      } catch (error) {
+        assert.include(error.toString(), "SweepNotAllowed");
+        console.log("✅ Correctly rejected premature sweep");
+      }
+    });
+
+    it("Should not start the inactivity clock before the schedule ends", async () => {
+      const idleRecipient = Keypair.generate();
+      const [idleStream] = getPda([
+        Buffer.from("stream"),
+        daoConfig.toBuffer(),
+        idleRecipient.publicKey.toBuffer(),
+      ]);
+      const currentTime = getCurrentTimestamp();
+
+      await program.methods
+        .createStream(
+          new anchor.BN(currentTime + 1),
+          new anchor.BN(currentTime + 3600),
+          new anchor.BN(1000000),
+          { contributors: {} },
+          "Idle mid-schedule",
+          null,
+          { claimDeadline: null, inactivityPeriod: new anchor.BN(1) },
+          defaultTerms
+        )
+        .accounts({
+          daoConfig,
+          authority: daoAuthority.publicKey,
+          recipient: idleRecipient.publicKey,
+          stream: idleStream,
+          treasuryMint,
+          authorityAta,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([daoAuthority])
+        .rpc();
+
+      await new Promise(resolve => setTimeout(resolve, 4000));
+
+      try {
+        await program.methods
+          .sweepStream()
+          .accounts({
+            daoConfig,
+            authority: daoAuthority.publicKey,
+            stream: idleStream,
+            streamAta: (await program.account.stream.fetch(idleStream)).streamAta,
+            authorityAta,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([daoAuthority])
+          .rpc();
+        assert.fail("Should have thrown an error");
+      } catch (error) {
+        assert.include(error.toString(), "SweepNotAllowed");
+      }
+    });
+  });
+
+  describe("Position Terms", () => {
+    const createTermsStream = async (termsRecipient: Keypair, terms: any): Promise<PublicKey> => {
+      const [termsStream] = getPda([
+        Buffer.from("stream"),
+        daoConfig.toBuffer(),
+        termsRecipient.publicKey.toBuffer(),
+      ]);
+      const currentTime = getCurrentTimestamp();
+
+      await program.methods
+        .createStream(
+          new anchor.BN(currentTime + 5),
+          new anchor.BN(currentTime + 105),
+          new anchor.BN(10000000),
+          { grants: {} },
+          "Terms test stream",
+          null,
+          null,
+          terms
+        )
+        .accounts({
+          daoConfig,
+          authority: daoAuthority.publicKey,
+          recipient: termsRecipient.publicKey,
+          stream: termsStream,
+          treasuryMint,
+          authorityAta,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([daoAuthority])
+        .rpc();
+
+      return termsStream;
+    };
+
+    it("Should refuse to cancel an irrevocable stream", async () => {
+      const termsRecipient = Keypair.generate();
+      const termsStream = await createTermsStream(termsRecipient, {
+        cancelableByAuthority: false,
+        cancelableByRecipient: false,
+        transferable: false,
+      });
+
+      const streamAccount = await program.account.stream.fetch(termsStream);
+      assert.equal(streamAccount.terms.cancelableByAuthority, false);
+
+      try {
+        await program.methods
+          .cancelStream()
+          .accounts({
+            daoConfig,
+            authority: daoAuthority.publicKey,
+            stream: termsStream,
+            recipient: termsRecipient.publicKey,
+            streamAta: streamAccount.streamAta,
+            recipientAta: await getAssociatedTokenAddress(treasuryMint, termsRecipient.publicKey),
+            authorityAta,
+            treasuryMint,
+            systemProgram: SystemProgram.programId,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          })
+          .signers([daoAuthority])
+          .rpc();
+
+        assert.fail("Should have thrown an error");
+      } catch (error) {
+        assert.include(error.toString(), "NotCancelableByAuthority");
+        console.log("✅ Correctly refused to cancel irrevocable stream");
+      }
+    });
+
+    it("Should refuse to cut the rate of an irrevocable stream", async () => {
+      const termsRecipient = Keypair.generate();
+      const termsStream = await createTermsStream(termsRecipient, {
+        cancelableByAuthority: false,
+        cancelableByRecipient: false,
+        transferable: false,
+      });
+      const streamAccount = await program.account.stream.fetch(termsStream);
+
+      try {
+        await program.methods
+          .scheduleRateChange(new anchor.BN(streamAccount.startTime.toNumber() + 50), new anchor.BN(0))
+          .accounts({
+            daoConfig,
+            authority: daoAuthority.publicKey,
+            stream: termsStream,
+            streamAta: streamAccount.streamAta,
+            treasuryMint,
+            authorityAta,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([daoAuthority])
+          .rpc();
+
+        assert.fail("Should have thrown an error");
+      } catch (error) {
+        assert.include(error.toString(), "NotCancelableByAuthority");
+        console.log("✅ Correctly refused to cut the rate of irrevocable stream");
+      }
+    });
+    it("Should transfer a transferable stream to a new recipient", async () => {
+      const termsRecipient = Keypair.generate();
+      const newRecipient = Keypair.generate();
+      await provider.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(termsRecipient.publicKey, LAMPORTS_PER_SOL)
+      );
+
+      const termsStream = await createTermsStream(termsRecipient, {
+        cancelableByAuthority: true,
+        cancelableByRecipient: false,
+        transferable: true,
+      });
+      const termsStreamAta = (await program.account.stream.fetch(termsStream)).streamAta;
+
+      const [newStream] = getPda([
+        Buffer.from("stream"),
+        daoConfig.toBuffer(),
+        newRecipient.publicKey.toBuffer(),
+      ]);
+      const newStreamAta = await getAssociatedTokenAddress(treasuryMint, newStream, true);
+
+      await program.methods
+        .transferStream()
+        .accounts({
+          daoConfig,
+          stream: termsStream,
+          recipient: termsRecipient.publicKey,
+          authority: daoAuthority.publicKey,
+          newRecipient: newRecipient.publicKey,
+          newStream,
+          streamAta: termsStreamAta,
+          newStreamAta,
+          treasuryMint,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        })
+        .signers([termsRecipient])
+        .rpc();
+
+      assert.isNull(await program.account.stream.fetchNullable(termsStream));
+      assert.isNull(await provider.connection.getAccountInfo(termsStreamAta));
+      const transferred = await program.account.stream.fetch(newStream);
+      assert.equal(transferred.recipient.toString(), newRecipient.publicKey.toString());
+      assert.equal(transferred.totalAmount.toNumber(), 10000000);
+
+      const newEscrow = await getAccount(provider.connection, newStreamAta);
+      assert.equal(Number(newEscrow.amount), 10000000);
+
+      console.log("✅ Stream transferred to new recipient");
+    });
+  });
+
+  describe("Stream Resignation", () => {
+    it("Should settle accrued funds and mark the stream resigned", async () => {
+      const resigningRecipient = Keypair.generate();
+      await provider.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(resigningRecipient.publicKey, LAMPORTS_PER_SOL)
+      );
+      const [resignStream] = getPda([
+        Buffer.from("stream"),
+        daoConfig.toBuffer(),
+        resigningRecipient.publicKey.toBuffer(),
+      ]);
+
+      const currentTime = getCurrentTimestamp();
+      const totalAmount = 100000000;
+      await program.methods
+        .createStream(
+          new anchor.BN(currentTime + 2),
+          new anchor.BN(currentTime + 102),
+          new anchor.BN(totalAmount),
+          { contributors: {} },
+          "Resignation test",
+          null,
+          null,
+          defaultTerms
+        )
+        .accounts({
+          daoConfig,
+          authority: daoAuthority.publicKey,
+          recipient: resigningRecipient.publicKey,
+          stream: resignStream,
+          treasuryMint,
+          authorityAta,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([daoAuthority])
+        .rpc();
+
+      const resignStreamAta = (await program.account.stream.fetch(resignStream)).streamAta;
+      const resigningRecipientAta = await getAssociatedTokenAddress(
+        treasuryMint,
+        resigningRecipient.publicKey
+      );
+      const authorityBalanceBefore = await getAccount(provider.connection, authorityAta);
+
+      await new Promise(resolve => setTimeout(resolve, 10000));
+
+      await program.methods
+        .resignStream()
+        .accounts({
+          daoConfig,
+          stream: resignStream,
+          recipient: resigningRecipient.publicKey,
+          streamAta: resignStreamAta,
+          recipientAta: resigningRecipientAta,
+          authorityAta,
+          treasuryMint,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        })
+        .signers([resigningRecipient])
+        .rpc();
+
+      const resigned = await program.account.stream.fetch(resignStream);
+      assert.deepEqual(resigned.status, { cancelled: { reason: { resigned: {} } } });
+
+      const recipientBalance = Number((await getAccount(provider.connection, resigningRecipientAta)).amount);
+      const authorityBalanceAfter = Number((await getAccount(provider.connection, authorityAta)).amount);
+      assert.isAbove(recipientBalance, 0);
+      assert.equal(recipientBalance, resigned.withdrawnAmount.toNumber());
+      assert.equal(
+        authorityBalanceAfter - Number(authorityBalanceBefore.amount),
+        totalAmount - recipientBalance
+      );
+      assert.equal(Number((await getAccount(provider.connection, resignStreamAta)).amount), 0);
+
+      console.log("✅ Recipient resigned with accrued funds settled");
+    });
+  });
+
+  describe("Auto-Renewing Streams", () => {
+    let daoVault: PublicKey;
+
+    before(async () => {
+      // The DAO vault is the dao_config PDA's associated token account
+      daoVault = await getAssociatedTokenAddress(treasuryMint, daoConfig, true);
+      const transaction = new anchor.web3.Transaction().add(
+        createAssociatedTokenAccountInstruction(
+          daoAuthority.publicKey,
+          daoVault,
+          daoConfig,
+          treasuryMint
+        )
+      );
+      await provider.sendAndConfirm(transaction, [daoAuthority]);
+      await mintTo(provider.connection, daoAuthority, treasuryMint, daoVault, daoAuthority, 50000000);
+    });
+
+    it("Should renew a retainer from the DAO vault when its period ends", async () => {
+      const retainerRecipient = Keypair.generate();
+      const [retainerStream] = getPda([
+        Buffer.from("stream"),
+        daoConfig.toBuffer(),
+        retainerRecipient.publicKey.toBuffer(),
+      ]);
+
+      const currentTime = getCurrentTimestamp();
+      const startTime = currentTime + 2;
+      const endTime = startTime + 5;
+      const periodAmount = 10000000;
+
+      await program.methods
+        .createStream(
+          new anchor.BN(startTime),
+          new anchor.BN(endTime),
+          new anchor.BN(periodAmount),
+          { operations: {} },
+          "Monthly retainer",
+          null,
+          null,
+          defaultTerms
+        )
+        .accounts({
+          daoConfig,
+          authority: daoAuthority.publicKey,
+          recipient: retainerRecipient.publicKey,
+          stream: retainerStream,
+          treasuryMint,
+          authorityAta,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([daoAuthority])
+        .rpc();
+
+      await program.methods
+        .setRenewalPolicy({ remainingRenewals: 1, periodAmount: null })
+        .accounts({
+          daoConfig,
+          authority: daoAuthority.publicKey,
+          stream: retainerStream,
+        })
+        .signers([daoAuthority])
+        .rpc();
+
+      const retainerStreamAta = (await program.account.stream.fetch(retainerStream)).streamAta;
+      await new Promise(resolve => setTimeout(resolve, (endTime - getCurrentTimestamp() + 2) * 1000));
+
+      // Any account can crank the renewal
+      await program.methods
+        .renewStream()
+        .accounts({
+          daoConfig,
+          stream: retainerStream,
+          streamAta: retainerStreamAta,
+          daoVault,
+          treasuryMint,
+          cranker: provider.wallet.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc();
+
+      const renewed = await program.account.stream.fetch(retainerStream);
+      assert.equal(renewed.totalAmount.toNumber(), periodAmount * 2);
+      assert.equal(renewed.periodStartTime.toNumber(), endTime);
+      assert.equal(renewed.endTime.toNumber(), endTime + (endTime - startTime));
+      assert.equal(renewed.renewalPolicy.remainingRenewals, 0);
+
+      const escrow = await getAccount(provider.connection, retainerStreamAta);
+      assert.equal(Number(escrow.amount), periodAmount * 2);
+
+      console.log("✅ Stream renewed from DAO vault");
+    });
+  });
+
+  describe("Templates", () => {
+    const standardTerms = { cancelableByAuthority: true, cancelableByRecipient: false, transferable: false };
+
+    it("Should create a stream from a template", async () => {
+      const name = "contributor-monthly";
+      const [template] = getPda([Buffer.from("template"), daoConfig.toBuffer(), Buffer.from(name)]);
+
+      await program.methods
+        .createTemplate(name, {
+          kind: { stream: {} },
+          vestingType: { linear: {} },
+          duration: new anchor.BN(30 * 24 * 60 * 60),
+          cliffOffset: new anchor.BN(0),
+          category: { development: {} },
+          description: "Standard contributor stream",
+          terms: standardTerms,
+          maxAmount: new anchor.BN(20000000),
+        })
+        .accounts({
+          daoConfig,
+          authority: daoAuthority.publicKey,
+          template,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([daoAuthority])
+        .rpc();
+
+      const contributor = Keypair.generate();
+      const [contributorStream] = getPda([
+        Buffer.from("stream"),
+        daoConfig.toBuffer(),
+        contributor.publicKey.toBuffer(),
+      ]);
+      const startTime = getCurrentTimestamp() + 10;
+
+      await program.methods
+        .createStreamFromTemplate(new anchor.BN(10000000), new anchor.BN(startTime))
+        .accounts({
+          daoConfig,
+          template,
+          authority: daoAuthority.publicKey,
+          recipient: contributor.publicKey,
+          stream: contributorStream,
+          treasuryMint,
+          authorityAta,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        })
+        .signers([daoAuthority])
+        .rpc();
+
+      const streamAccount = await program.account.stream.fetch(contributorStream);
+      assert.equal(streamAccount.totalAmount.toNumber(), 10000000);
+      assert.equal(streamAccount.endTime.toNumber(), startTime + 30 * 24 * 60 * 60);
+      assert.deepEqual(streamAccount.category, { development: {} });
+      assert.equal(streamAccount.description, "Standard contributor stream");
+      assert.deepEqual(streamAccount.terms, standardTerms);
+    });
+
+    it("Should reject amounts above the template maximum", async () => {
+      const name = "contributor-monthly";
+      const [template] = getPda([Buffer.from("template"), daoConfig.toBuffer(), Buffer.from(name)]);
+      const contributor = Keypair.generate();
+      const [contributorStream] = getPda([
+        Buffer.from("stream"),
+        daoConfig.toBuffer(),
+        contributor.publicKey.toBuffer(),
+      ]);
+
+      try {
+        await program.methods
+          .createStreamFromTemplate(new anchor.BN(30000000), new anchor.BN(getCurrentTimestamp() + 10))
+          .accounts({
+            daoConfig,
+            template,
+            authority: daoAuthority.publicKey,
+            recipient: contributor.publicKey,
+            stream: contributorStream,
+            treasuryMint,
+            authorityAta,
+            systemProgram: SystemProgram.programId,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          })
+          .signers([daoAuthority])
+          .rpc();
+        assert.fail("Should have failed");
+      } catch (error) {
+        assert.include(error.message, "TemplateAmountExceeded");
+      }
+    });
+
+    it("Should create a cliff vesting from a template", async () => {
+      const name = "advisor-cliff";
+      const [template] = getPda([Buffer.from("template"), daoConfig.toBuffer(), Buffer.from(name)]);
+      const year = 365 * 24 * 60 * 60;
+
+      await program.methods
+        .createTemplate(name, {
+          kind: { vesting: {} },
+          vestingType: { cliff: {} },
+          duration: new anchor.BN(2 * year),
+          cliffOffset: new anchor.BN(year),
+          category: { marketing: {} },
+          description: "Advisor grant",
+          terms: standardTerms,
+          maxAmount: null,
+        })
+        .accounts({
+          daoConfig,
+          authority: daoAuthority.publicKey,
+          template,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([daoAuthority])
+        .rpc();
+
+      const advisor = Keypair.generate();
+      const [advisorVesting] = getPda([
+        Buffer.from("vesting"),
+        daoConfig.toBuffer(),
+        advisor.publicKey.toBuffer(),
+      ]);
+      const startTime = getCurrentTimestamp() + 60;
+
+      await program.methods
+        .createVestingFromTemplate(new anchor.BN(5000000), new anchor.BN(startTime))
+        .accounts({
+          daoConfig,
+          template,
+          authority: daoAuthority.publicKey,
+          recipient: advisor.publicKey,
+          vesting: advisorVesting,
+          treasuryMint,
+          authorityAta,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        })
+        .signers([daoAuthority])
+        .rpc();
+
+      const vestingAccount = await program.account.vesting.fetch(advisorVesting);
+      assert.deepEqual(vestingAccount.vestingType, { cliff: {} });
+      assert.equal(vestingAccount.cliffTime.toNumber(), startTime + year);
+      assert.equal(vestingAccount.endTime.toNumber(), startTime + 2 * year);
+    });
+  });
+
+  describe("Stream Groups", () => {
+    const groupName = "fellowship-batch-1";
+    let streamGroup: PublicKey;
+    const members: { recipient: Keypair; stream: PublicKey }[] = [];
+
+    before(async () => {
+      [streamGroup] = getPda([Buffer.from("stream_group"), daoConfig.toBuffer(), Buffer.from(groupName)]);
+
+      await program.methods
+        .createStreamGroup(groupName)
+        .accounts({
+          daoConfig,
+          authority: daoAuthority.publicKey,
+          streamGroup,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([daoAuthority])
+        .rpc();
+
+      const startTime = getCurrentTimestamp() + 2;
+      for (let i = 0; i < 2; i++) {
+        const memberRecipient = Keypair.generate();
+        const [memberStream] = getPda([
+          Buffer.from("stream"),
+          daoConfig.toBuffer(),
+          memberRecipient.publicKey.toBuffer(),
+        ]);
+
+        await program.methods
+          .createStream(
+            new anchor.BN(startTime),
+            new anchor.BN(startTime + 1000),
+            new anchor.BN(5000000),
+            { grants: {} },
+            `Fellow ${i + 1}`,
+            null,
+            null,
+            defaultTerms
+          )
+          .accounts({
+            daoConfig,
+            authority: daoAuthority.publicKey,
+            recipient: memberRecipient.publicKey,
+            stream: memberStream,
+            treasuryMint,
+            authorityAta,
+            systemProgram: SystemProgram.programId,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            rent: SYSVAR_RENT_PUBKEY,
+            streamGroup,
+          })
+          .signers([daoAuthority])
+          .rpc();
+
+        members.push({ recipient: memberRecipient, stream: memberStream });
+      }
+    });
+
+    it("Should keep aggregate totals for attached streams", async () => {
+      const group = await program.account.streamGroup.fetch(streamGroup);
+      assert.equal(group.memberCount, 2);
+      assert.equal(group.totalAmount.toNumber(), 10000000);
+
+      const memberAccount = await program.account.stream.fetch(members[0].stream);
+      assert.equal(memberAccount.group.toString(), streamGroup.toString());
+    });
+
+    it("Should pause and resume every member stream", async () => {
+      const remainingAccounts = members.map(({ stream }) => ({ pubkey: stream, isWritable: true, isSigner: false }));
+      const before = await program.account.stream.fetch(members[0].stream);
+
+      await program.methods
+        .pauseStreamGroup()
+        .accounts({ daoConfig, authority: daoAuthority.publicKey, streamGroup })
+        .remainingAccounts(remainingAccounts)
+        .signers([daoAuthority])
+        .rpc();
+
+      for (const { stream } of members) {
+        const paused = await program.account.stream.fetch(stream);
+        assert.deepEqual(paused.status, { paused: {} });
+        assert.isNotNull(paused.pausedAt);
+      }
+      await new Promise(resolve => setTimeout(resolve, 2000));
+
+      await program.methods
+        .resumeStreamGroup()
+        .accounts({ daoConfig, authority: daoAuthority.publicKey, streamGroup })
+        .remainingAccounts(remainingAccounts)
+        .signers([daoAuthority])
+        .rpc();
+
+      for (const { stream } of members) {
+        const resumed = await program.account.stream.fetch(stream);
+        assert.deepEqual(resumed.status, { active: {} });
+        assert.isNull(resumed.pausedAt);
+      }
+
+      // The rest of the schedule moves back by the time spent paused
+      const after = await program.account.stream.fetch(members[0].stream);
+      assert.isAbove(after.endTime.toNumber(), before.endTime.toNumber());
+    });
+
+    it("Should extend every member stream", async () => {
+      const before = await program.account.stream.fetch(members[0].stream);
+
+      await program.methods
+        .extendStreamGroup(new anchor.BN(500))
+        .accounts({ daoConfig, authority: daoAuthority.publicKey, streamGroup })
+        .remainingAccounts(members.map(({ stream }) => ({ pubkey: stream, isWritable: true, isSigner: false })))
+        .signers([daoAuthority])
+        .rpc();
+
+      const after = await program.account.stream.fetch(members[0].stream);
+      assert.equal(after.endTime.toNumber(), before.endTime.toNumber() + 500);
+    });
+
+    it("Should count a member's rate change in the group totals", async () => {
+      const memberAccount = await program.account.stream.fetch(members[0].stream);
+      const groupBefore = await program.account.streamGroup.fetch(streamGroup);
+      const scheduleRateChange = (group: PublicKey | null) =>
+        program.methods
+          .scheduleRateChange(new anchor.BN(getCurrentTimestamp() + 100), new anchor.BN(6000000))
+          .accounts({
+            daoConfig,
+            authority: daoAuthority.publicKey,
+            stream: members[0].stream,
+            streamAta: memberAccount.streamAta,
+            treasuryMint,
+            authorityAta,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            streamGroup: group,
+          })
+          .signers([daoAuthority])
+          .rpc();
+
+      try {
+        await scheduleRateChange(null);
+        assert.fail("Should have failed");
+      } catch (error) {
+        assert.include(error.message, "InvalidGroupMember");
+      }
+
+      await scheduleRateChange(streamGroup);
+
+      const updated = await program.account.stream.fetch(members[0].stream);
+      const increase = updated.pendingRateChange.newTotalAmount.toNumber() - memberAccount.totalAmount.toNumber();
+      const groupAfter = await program.account.streamGroup.fetch(streamGroup);
+      assert.equal(groupAfter.totalAmount.toNumber(), groupBefore.totalAmount.toNumber() + increase);
+    });
+
+    it("Should reject streams outside the group", async () => {
+      try {
+        await program.methods
+          .pauseStreamGroup()
+          .accounts({ daoConfig, authority: daoAuthority.publicKey, streamGroup })
+          .remainingAccounts([{ pubkey: stream, isWritable: true, isSigner: false }])
+          .signers([daoAuthority])
+          .rpc();
+        assert.fail("Should have failed");
+      } catch (error) {
+        assert.include(error.message, "InvalidGroupMember");
+      }
+    });
+
+    it("Should cancel every member stream and refund the treasury", async () => {
+      const remainingAccounts = [];
+      for (const { recipient: memberRecipient, stream: memberStream } of members) {
+        const recipientAta = await getAssociatedTokenAddress(treasuryMint, memberRecipient.publicKey);
+        await provider.sendAndConfirm(
+          new anchor.web3.Transaction().add(
+            createAssociatedTokenAccountInstruction(
+              daoAuthority.publicKey,
+              recipientAta,
+              memberRecipient.publicKey,
+              treasuryMint
+            )
+          ),
+          [daoAuthority]
+        );
+        const memberStreamAta = (await program.account.stream.fetch(memberStream)).streamAta;
+        remainingAccounts.push(
+          { pubkey: memberStream, isWritable: true, isSigner: false },
+          { pubkey: memberStreamAta, isWritable: true, isSigner: false },
+          { pubkey: recipientAta, isWritable: true, isSigner: false },
+          {
+            pubkey: getPda([
+              Buffer.from("payout_allowlist"),
+              daoConfig.toBuffer(),
+              memberRecipient.publicKey.toBuffer(),
+            ])[0],
+            isWritable: false,
+            isSigner: false,
+          },
+          {
+            pubkey: getPda([Buffer.from("payout_split"), memberStream.toBuffer()])[0],
+            isWritable: false,
+            isSigner: false,
+          },
+          {
+            pubkey: getPda([Buffer.from("withholding"), memberStream.toBuffer()])[0],
+            isWritable: false,
+            isSigner: false,
+          }
+        );
+      }
+
+      await program.methods
+        .cancelStreamGroup()
+        .accounts({
+          daoConfig,
+          authority: daoAuthority.publicKey,
+          streamGroup,
+          authorityAta,
+          treasuryMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .remainingAccounts(remainingAccounts)
+        .signers([daoAuthority])
+        .rpc();
+
+      for (const { stream: memberStream } of members) {
+        const memberAccount = await program.account.stream.fetch(memberStream);
+        assert.deepEqual(memberAccount.status, { cancelled: { reason: { terminated: {} } } });
+      }
+
+      const group = await program.account.streamGroup.fetch(streamGroup);
+      assert.equal(group.cancelledCount, 2);
+      assert.equal(group.totalSettled.toNumber() + group.totalRefunded.toNumber(), group.totalAmount.toNumber());
+    });
+  });
+
+  describe("Payout Destination Allowlist", () => {
+    const payee = Keypair.generate();
+    const coldWallet = Keypair.generate();
+    const exchangeWallet = Keypair.generate();
+    let payeeStream: PublicKey;
+    let payeeStreamAta: PublicKey;
+    let payeeAta: PublicKey;
+    let coldAta: PublicKey;
+    let exchangeAta: PublicKey;
+    let payoutAllowlist: PublicKey;
+
+    const redeem = (amount: number, payoutDestination: PublicKey | null) =>
+      program.methods
+        .redeemStream(new anchor.BN(amount))
+        .accounts({
+          daoConfig,
+          stream: payeeStream,
+          recipient: payee.publicKey,
+          streamAta: payeeStreamAta,
+          recipientAta: payeeAta,
+          payoutAllowlist,
+          payoutDestination,
+          treasuryMint,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([payee])
+        .rpc();
+
+    before(async () => {
+      await provider.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(payee.publicKey, LAMPORTS_PER_SOL)
+      );
+      payeeAta = await createTokenAccount(treasuryMint, payee.publicKey);
+      coldAta = await createTokenAccount(treasuryMint, coldWallet.publicKey);
+      exchangeAta = await createTokenAccount(treasuryMint, exchangeWallet.publicKey);
+
+      [payeeStream] = getPda([Buffer.from("stream"), daoConfig.toBuffer(), payee.publicKey.toBuffer()]);
+      [payoutAllowlist] = getPda([
+        Buffer.from("payout_allowlist"),
+        daoConfig.toBuffer(),
+        payee.publicKey.toBuffer(),
+      ]);
+
+      const startTime = getCurrentTimestamp() - 1;
+      await program.methods
+        .createStream(
+          new anchor.BN(startTime),
+          new anchor.BN(startTime + 20),
+          new anchor.BN(20000000),
+          { contributors: {} },
+          "Allowlisted payee",
+          null,
+          null,
+          defaultTerms
+        )
+        .accounts({
+          daoConfig,
+          authority: daoAuthority.publicKey,
+          recipient: payee.publicKey,
+          stream: payeeStream,
+          treasuryMint,
+          authorityAta,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([daoAuthority])
+        .rpc();
+      payeeStreamAta = (await program.account.stream.fetch(payeeStream)).streamAta;
+
+      await program.methods
+        .createPayoutAllowlist(new anchor.BN(3))
+        .accounts({
+          daoConfig,
+          recipient: payee.publicKey,
+          payoutAllowlist,
+          destination: coldAta,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([payee])
+        .rpc();
+    });
+
+    it("Should reject payouts to the recipient ATA once an allowlist exists", async () => {
+      await new Promise(resolve => setTimeout(resolve, 2000));
+      try {
+        await redeem(100000, null);
+        assert.fail("Should have failed");
+      } catch (error) {
+        assert.include(error.message, "PayoutDestinationNotAllowed");
+      }
+    });
+
+    it("Should pay out to a registered destination", async () => {
+      await redeem(100000, coldAta);
+      const cold = await getAccount(provider.connection, coldAta);
+      assert.equal(Number(cold.amount), 100000);
+    });
+
+    it("Should only allow a queued destination after the change delay", async () => {
+      await program.methods
+        .queuePayoutDestination()
+        .accounts({
+          daoConfig,
+          recipient: payee.publicKey,
+          payoutAllowlist,
+          destination: exchangeAta,
+        })
+        .signers([payee])
+        .rpc();
+
+      try {
+        await redeem(100000, exchangeAta);
+        assert.fail("Should have failed");
+      } catch (error) {
+        assert.include(error.message, "PayoutDestinationNotAllowed");
+      }
+
+      await new Promise(resolve => setTimeout(resolve, 4000));
+      await redeem(100000, exchangeAta);
+      const exchange = await getAccount(provider.connection, exchangeAta);
+      assert.equal(Number(exchange.amount), 100000);
+    });
+
+    it("Should remove a destination immediately", async () => {
+      await program.methods
+        .removePayoutDestination(coldAta)
+        .accounts({ daoConfig, recipient: payee.publicKey, payoutAllowlist })
+        .signers([payee])
+        .rpc();
+
+      try {
+        await redeem(100000, coldAta);
+        assert.fail("Should have failed");
+      } catch (error) {
+        assert.include(error.message, "PayoutDestinationNotAllowed");
+      }
+    });
+
+    it("Should settle a cancelled stream to an allowed destination", async () => {
+      const cancel = (payoutDestination: PublicKey | null) =>
+        program.methods
+          .cancelStream()
+          .accounts({
+            daoConfig,
+            authority: daoAuthority.publicKey,
+            stream: payeeStream,
+            recipient: payee.publicKey,
+            streamAta: payeeStreamAta,
+            recipientAta: payeeAta,
+            payoutAllowlist,
+            payoutDestination,
+            authorityAta,
+            treasuryMint,
+            systemProgram: SystemProgram.programId,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          })
+          .signers([daoAuthority])
+          .rpc();
+
+      try {
+        await cancel(null);
+        assert.fail("Should have failed");
+      } catch (error) {
+        assert.include(error.message, "PayoutDestinationNotAllowed");
+      }
+
+      const exchangeBefore = Number((await getAccount(provider.connection, exchangeAta)).amount);
+      await cancel(exchangeAta);
+      const exchangeAfter = Number((await getAccount(provider.connection, exchangeAta)).amount);
+      assert.isAbove(exchangeAfter, exchangeBefore);
+    });
+  });
+
+  describe("Split Withdrawals", () => {
+    const splitRecipient = Keypair.generate();
+    const savingsWallet = Keypair.generate();
+    let splitStream: PublicKey;
+    let splitStreamAta: PublicKey;
+    let splitRecipientAta: PublicKey;
+    let savingsAta: PublicKey;
+    let payoutSplit: PublicKey;
+
+    before(async () => {
+      await provider.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(splitRecipient.publicKey, LAMPORTS_PER_SOL)
+      );
+      splitRecipientAta = await createTokenAccount(treasuryMint, splitRecipient.publicKey);
+      savingsAta = await createTokenAccount(treasuryMint, savingsWallet.publicKey);
+
+      [splitStream] = getPda([Buffer.from("stream"), daoConfig.toBuffer(), splitRecipient.publicKey.toBuffer()]);
+      [payoutSplit] = getPda([Buffer.from("payout_split"), splitStream.toBuffer()]);
+
+      const startTime = getCurrentTimestamp() - 1;
+      await program.methods
+        .createStream(
+          new anchor.BN(startTime),
+          new anchor.BN(startTime + 10),
+          new anchor.BN(10000000),
+          { contributors: {} },
+          "Split payee",
+          null,
+          null,
+          defaultTerms
+        )
+        .accounts({
+          daoConfig,
+          authority: daoAuthority.publicKey,
+          recipient: splitRecipient.publicKey,
+          stream: splitStream,
+          treasuryMint,
+          authorityAta,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([daoAuthority])
+        .rpc();
+      splitStreamAta = (await program.account.stream.fetch(splitStream)).streamAta;
+    });
+
+    it("Should reject splits that do not add up to 100%", async () => {
+      try {
+        await program.methods
+          .setStreamPayoutSplit(
+            [
+              { destination: splitRecipientAta, bps: 7000 },
+              { destination: savingsAta, bps: 2000 },
+            ],
+            splitRecipientAta
+          )
+          .accounts({
+            stream: splitStream,
+            payoutSplit,
+            recipient: splitRecipient.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([splitRecipient])
+          .rpc();
+        assert.fail("Should have failed");
+      } catch (error) {
+        assert.include(error.message, "InvalidPayoutSplit");
+      }
+    });
+
+    it("Should split a redemption and give the remainder to the primary destination", async () => {
+      await program.methods
+        .setStreamPayoutSplit(
+          [
+            { destination: splitRecipientAta, bps: 7000 },
+            { destination: savingsAta, bps: 3000 },
+          ],
+          splitRecipientAta
+        )
+        .accounts({
+          stream: splitStream,
+          payoutSplit,
+          recipient: splitRecipient.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([splitRecipient])
+        .rpc();
+
+      await new Promise(resolve => setTimeout(resolve, 3000));
+
+      await program.methods
+        .redeemStream(new anchor.BN(1000001))
+        .accounts({
+          daoConfig,
+          stream: splitStream,
+          recipient: splitRecipient.publicKey,
+          streamAta: splitStreamAta,
+          recipientAta: splitRecipientAta,
+          payoutSplit,
+          treasuryMint,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .remainingAccounts([
+          { pubkey: splitRecipientAta, isWritable: true, isSigner: false },
+          { pubkey: savingsAta, isWritable: true, isSigner: false },
+        ])
+        .signers([splitRecipient])
+        .rpc();
+
+      const primary = await getAccount(provider.connection, splitRecipientAta);
+      const savings = await getAccount(provider.connection, savingsAta);
+      assert.equal(Number(primary.amount), 700001);
+      assert.equal(Number(savings.amount), 300000);
+    });
+
+    it("Should pay the recipient ATA again once the split is cleared", async () => {
+      await program.methods
+        .clearPayoutSplit()
+        .accounts({ payoutSplit, recipient: splitRecipient.publicKey })
+        .signers([splitRecipient])
+        .rpc();
+
+      await program.methods
+        .redeemStream(new anchor.BN(1000000))
+        .accounts({
+          daoConfig,
+          stream: splitStream,
+          recipient: splitRecipient.publicKey,
+          streamAta: splitStreamAta,
+          recipientAta: splitRecipientAta,
+          payoutSplit,
+          treasuryMint,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([splitRecipient])
+        .rpc();
+
+      const primary = await getAccount(provider.connection, splitRecipientAta);
+      assert.equal(Number(primary.amount), 1700001);
+    });
+
+    it("Should split the settlement when the stream is cancelled", async () => {
+      await program.methods
+        .setStreamPayoutSplit(
+          [
+            { destination: splitRecipientAta, bps: 5000 },
+            { destination: savingsAta, bps: 5000 },
+          ],
+          splitRecipientAta
+        )
+        .accounts({
+          stream: splitStream,
+          payoutSplit,
+          recipient: splitRecipient.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([splitRecipient])
+        .rpc();
+
+      await program.methods
+        .cancelStream()
+        .accounts({
+          daoConfig,
+          authority: daoAuthority.publicKey,
+          stream: splitStream,
+          recipient: splitRecipient.publicKey,
+          streamAta: splitStreamAta,
+          recipientAta: splitRecipientAta,
+          payoutSplit,
+          authorityAta,
+          treasuryMint,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        })
+        .remainingAccounts([
+          { pubkey: splitRecipientAta, isWritable: true, isSigner: false },
+          { pubkey: savingsAta, isWritable: true, isSigner: false },
+        ])
+        .signers([daoAuthority])
+        .rpc();
+
+      // The remaining 7,999,999 is halved, with the odd token going to the primary
+      const primary = await getAccount(provider.connection, splitRecipientAta);
+      const savings = await getAccount(provider.connection, savingsAta);
+      assert.equal(Number(primary.amount), 1700001 + 4000000);
+      assert.equal(Number(savings.amount), 300000 + 3999999);
+    });
+  });
+
+  describe("Team Streams", () => {
+    const teamName = "core-protocol";
+    const teamAdmin = Keypair.generate();
+    const alice = Keypair.generate();
+    const bob = Keypair.generate();
+    let team: PublicKey;
+    let teamStream: PublicKey;
+    let teamStreamAta: PublicKey;
+
+    const withdrawShare = (member: Keypair, amount: number) =>
+      program.methods
+        .withdrawTeamShare(new anchor.BN(amount))
+        .accounts({
+          daoConfig,
+          team,
+          stream: teamStream,
+          streamAta: teamStreamAta,
+          member: member.publicKey,
+          memberAta: getAssociatedTokenAddressSync(treasuryMint, member.publicKey),
+          treasuryMint,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        })
+        .signers([member])
+        .rpc();
+
+    before(async () => {
+      for (const wallet of [teamAdmin, alice, bob]) {
+        await provider.connection.confirmTransaction(
+          await provider.connection.requestAirdrop(wallet.publicKey, LAMPORTS_PER_SOL)
+        );
+      }
+
+      [team] = getPda([Buffer.from("team"), daoConfig.toBuffer(), Buffer.from(teamName)]);
+      [teamStream] = getPda([Buffer.from("stream"), daoConfig.toBuffer(), team.toBuffer()]);
+
+      await program.methods
+        .createTeam(teamName, [
+          { member: alice.publicKey, bps: 6000 },
+          { member: bob.publicKey, bps: 4000 },
+        ])
+        .accounts({
+          daoConfig,
+          admin: teamAdmin.publicKey,
+          team,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([teamAdmin])
+        .rpc();
+
+      const startTime = getCurrentTimestamp() - 1;
+      await program.methods
+        .createStream(
+          new anchor.BN(startTime),
+          new anchor.BN(startTime + 4),
+          new anchor.BN(10000000),
+          { development: {} },
+          "Core protocol team",
+          null,
+          null,
+          defaultTerms
+        )
+        .accounts({
+          daoConfig,
+          authority: daoAuthority.publicKey,
+          recipient: team,
+          stream: teamStream,
+          treasuryMint,
+          authorityAta,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([daoAuthority])
+        .rpc();
+      teamStreamAta = (await program.account.stream.fetch(teamStream)).streamAta;
+
+      // Let the whole stream accrue under the initial shares
+      await new Promise(resolve => setTimeout(resolve, 6000));
+    });
+
+    it("Should let each member withdraw their own share", async () => {
+      await withdrawShare(alice, 6000000);
+
+      const teamAccount = await program.account.team.fetch(team);
+      const aliceEntry = teamAccount.members.find(m => m.member.equals(alice.publicKey));
+      const bobEntry = teamAccount.members.find(m => m.member.equals(bob.publicKey));
+      assert.equal(aliceEntry.withdrawnAmount.toNumber(), 6000000);
+      assert.equal(bobEntry.accruedAmount.toNumber(), 4000000);
+
+      try {
+        await withdrawShare(bob, 4000001);
+        assert.fail("Should have failed");
+      } catch (error) {
+        assert.include(error.message, "InsufficientTeamShare");
+      }
+    });
+
+    it("Should rebalance shares without touching accrued amounts", async () => {
+      await program.methods
+        .setTeamShares([
+          { member: alice.publicKey, bps: 5000 },
+          { member: bob.publicKey, bps: 5000 },
+        ])
+        .accounts({ admin: teamAdmin.publicKey, team, stream: teamStream })
+        .signers([teamAdmin])
+        .rpc();
+
+      const teamAccount = await program.account.team.fetch(team);
+      const bobEntry = teamAccount.members.find(m => m.member.equals(bob.publicKey));
+      assert.equal(bobEntry.bps, 5000);
+      assert.equal(bobEntry.accruedAmount.toNumber(), 4000000);
+
+      await withdrawShare(bob, 4000000);
+      const bobAta = await getAccount(provider.connection, getAssociatedTokenAddressSync(treasuryMint, bob.publicKey));
+      assert.equal(Number(bobAta.amount), 4000000);
+    });
+
+    it("Should only let the admin rebalance", async () => {
+      try {
+        await program.methods
+          .setTeamShares([{ member: alice.publicKey, bps: 10000 }])
+          .accounts({ admin: alice.publicKey, team, stream: teamStream })
+          .signers([alice])
+          .rpc();
+        assert.fail("Should have failed");
+      } catch (error) {
+        assert.include(error.message, "UnauthorizedTeamAdmin");
+      }
+    });
+
+    describe("Cancelled team streams", () => {
+      const cancelledTeamName = "ops-guild";
+      let cancelledTeam: PublicKey;
+      let cancelledStream: PublicKey;
+
+      const createTeamStream = (acceptanceDeadline: anchor.BN | null) => {
+        const startTime = getCurrentTimestamp() - 1;
+        return program.methods
+          .createStream(
+            new anchor.BN(startTime),
+            new anchor.BN(startTime + 100),
+            new anchor.BN(10000000),
+            { development: {} },
+            "Ops guild",
+            acceptanceDeadline,
+            null,
+            defaultTerms
+          )
+          .accounts({
+            daoConfig,
+            authority: daoAuthority.publicKey,
+            recipient: cancelledTeam,
+            stream: cancelledStream,
+            treasuryMint,
+            authorityAta,
+            systemProgram: SystemProgram.programId,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            rent: SYSVAR_RENT_PUBKEY,
+          })
+          .signers([daoAuthority])
+          .rpc();
+      };
+
+      before(async () => {
+        [cancelledTeam] = getPda([Buffer.from("team"), daoConfig.toBuffer(), Buffer.from(cancelledTeamName)]);
+        [cancelledStream] = getPda([Buffer.from("stream"), daoConfig.toBuffer(), cancelledTeam.toBuffer()]);
+
+        await program.methods
+          .createTeam(cancelledTeamName, [
+            { member: alice.publicKey, bps: 6000 },
+            { member: bob.publicKey, bps: 4000 },
+          ])
+          .accounts({
+            daoConfig,
+            admin: teamAdmin.publicKey,
+            team: cancelledTeam,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([teamAdmin])
+          .rpc();
+      });
+
+      it("Should reject a team stream that waits for acceptance", async () => {
+        try {
+          await createTeamStream(new anchor.BN(getCurrentTimestamp() + 3600));
+          assert.fail("Should have failed");
+        } catch (error) {
+          assert.include(error.message, "TeamCannotAccept");
+        }
+      });
+
+      it("Should let members withdraw their settled shares after cancellation", async () => {
+        await createTeamStream(null);
+        const cancelledStreamAta = (await program.account.stream.fetch(cancelledStream)).streamAta;
+        await new Promise(resolve => setTimeout(resolve, 2000));
+
+        await program.methods
+          .cancelStream()
+          .accounts({
+            daoConfig,
+            authority: daoAuthority.publicKey,
+            stream: cancelledStream,
+            recipient: cancelledTeam,
+            streamAta: cancelledStreamAta,
+            recipientAta: getAssociatedTokenAddressSync(treasuryMint, cancelledTeam, true),
+            authorityAta,
+            treasuryMint,
+            systemProgram: SystemProgram.programId,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          })
+          .signers([daoAuthority])
+          .rpc();
+
+        // The settlement stays in the escrow for the members
+        const settledAmount = (await program.account.stream.fetch(cancelledStream)).withdrawnAmount.toNumber();
+        const escrow = await getAccount(provider.connection, cancelledStreamAta);
+        assert.isAbove(settledAmount, 0);
+        assert.equal(Number(escrow.amount), settledAmount);
+
+        const bobShare = Math.floor((settledAmount * 4000) / 10000);
+        const bobAta = getAssociatedTokenAddressSync(treasuryMint, bob.publicKey);
+        const bobBefore = Number((await getAccount(provider.connection, bobAta)).amount);
+        await program.methods
+          .withdrawTeamShare(new anchor.BN(bobShare))
+          .accounts({
+            daoConfig,
+            team: cancelledTeam,
+            stream: cancelledStream,
+            streamAta: cancelledStreamAta,
+            member: bob.publicKey,
+            memberAta: bobAta,
+            treasuryMint,
+            systemProgram: SystemProgram.programId,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          })
+          .signers([bob])
+          .rpc();
+
+        const bobAfter = Number((await getAccount(provider.connection, bobAta)).amount);
+        assert.equal(bobAfter - bobBefore, bobShare);
+      });
+    });
+  });
+
+  describe("Tax Withholding", () => {
+    const taxedRecipient = Keypair.generate();
+    let taxedStream: PublicKey;
+    let taxedStreamAta: PublicKey;
+    let taxedRecipientAta: PublicKey;
+    let withholdingVault: PublicKey;
+    let withholdingPolicy: PublicKey;
+    let withholdingLedger: PublicKey;
+    const year = new Date().getUTCFullYear();
+
+    const redeem = (amount: number, withholdingAccounts: { withholdingVault?: PublicKey; withholdingLedger?: PublicKey }) =>
+      program.methods
+        .redeemStream(new anchor.BN(amount))
+        .accounts({
+          daoConfig,
+          stream: taxedStream,
+          recipient: taxedRecipient.publicKey,
+          streamAta: taxedStreamAta,
+          recipientAta: taxedRecipientAta,
+          withholdingPolicy,
+          withholdingVault: withholdingAccounts.withholdingVault ?? null,
+          withholdingLedger: withholdingAccounts.withholdingLedger ?? null,
+          treasuryMint,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([taxedRecipient])
+        .rpc();
+
+    before(async () => {
+      await provider.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(taxedRecipient.publicKey, LAMPORTS_PER_SOL)
+      );
+      taxedRecipientAta = await createTokenAccount(treasuryMint, taxedRecipient.publicKey);
+
+      [withholdingVault] = getPda([Buffer.from("withholding_vault"), daoConfig.toBuffer()]);
+      [taxedStream] = getPda([Buffer.from("stream"), daoConfig.toBuffer(), taxedRecipient.publicKey.toBuffer()]);
+      [withholdingPolicy] = getPda([Buffer.from("withholding"), taxedStream.toBuffer()]);
+      const yearBytes = Buffer.alloc(2);
+      yearBytes.writeUInt16LE(year);
+      [withholdingLedger] = getPda([
+        Buffer.from("withholding_ledger"),
+        daoConfig.toBuffer(),
+        taxedRecipient.publicKey.toBuffer(),
+        yearBytes,
+      ]);
+
+      await program.methods
+        .initWithholdingVault()
+        .accounts({
+          daoConfig,
+          authority: daoAuthority.publicKey,
+          withholdingVault,
+          treasuryMint,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([daoAuthority])
+        .rpc();
+
+      const startTime = getCurrentTimestamp() - 1;
+      await program.methods
+        .createStream(
+          new anchor.BN(startTime),
+          new anchor.BN(startTime + 4),
+          new anchor.BN(4000000),
+          { contributors: {} },
+          "Withheld contributor",
+          null,
+          null,
+          defaultTerms
+        )
+        .accounts({
+          daoConfig,
+          authority: daoAuthority.publicKey,
+          recipient: taxedRecipient.publicKey,
+          stream: taxedStream,
+          treasuryMint,
+          authorityAta,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([daoAuthority])
+        .rpc();
+      taxedStreamAta = (await program.account.stream.fetch(taxedStream)).streamAta;
+
+      await program.methods
+        .setStreamWithholding(2500)
+        .accounts({
+          daoConfig,
+          authority: daoAuthority.publicKey,
+          stream: taxedStream,
+          withholdingPolicy,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([daoAuthority])
+        .rpc();
+
+      await new Promise(resolve => setTimeout(resolve, 6000));
+    });
+
+    it("Should require the vault and ledger once a rate is set", async () => {
+      try {
+        await redeem(1000000, {});
+        assert.fail("Should have failed");
+      } catch (error) {
+        assert.include(error.message, "WithholdingAccountsMissing");
+      }
+    });
+
+    it("Should divert the withheld share into the vault and record it on the ledger", async () => {
+      await program.methods
+        .openWithholdingLedger(year)
+        .accounts({
+          daoConfig,
+          recipient: taxedRecipient.publicKey,
+          withholdingLedger,
+          payer: taxedRecipient.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([taxedRecipient])
+        .rpc();
+
+      await redeem(1000000, { withholdingVault, withholdingLedger });
+
+      const recipientBalance = await getAccount(provider.connection, taxedRecipientAta);
+      const vaultBalance = await getAccount(provider.connection, withholdingVault);
+      assert.equal(Number(recipientBalance.amount), 750000);
+      assert.equal(Number(vaultBalance.amount), 250000);
+
+      const ledger = await program.account.withholdingLedger.fetch(withholdingLedger);
+      assert.equal(ledger.year, year);
+      assert.equal(ledger.grossAmount.toNumber(), 1000000);
+      assert.equal(ledger.withheldAmount.toNumber(), 250000);
+      assert.equal(ledger.payoutCount, 1);
+    });
+
+    it("Should let the DAO remit withheld funds", async () => {
+      const before = await getAccount(provider.connection, authorityAta);
+
+      await program.methods
+        .remitWithholding(new anchor.BN(250000))
+        .accounts({
+          daoConfig,
+          authority: daoAuthority.publicKey,
+          withholdingVault,
+          destination: authorityAta,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([daoAuthority])
+        .rpc();
+
+      const after = await getAccount(provider.connection, authorityAta);
+      assert.equal(Number(after.amount) - Number(before.amount), 250000);
+    });
+
+    it("Should withhold from the settlement when the stream is cancelled", async () => {
+      const recipientBefore = await getAccount(provider.connection, taxedRecipientAta);
+      const vaultBefore = await getAccount(provider.connection, withholdingVault);
+
+      await program.methods
+        .cancelStream()
+        .accounts({
+          daoConfig,
+          authority: daoAuthority.publicKey,
+          stream: taxedStream,
+          recipient: taxedRecipient.publicKey,
+          streamAta: taxedStreamAta,
+          recipientAta: taxedRecipientAta,
+          withholdingPolicy,
+          withholdingVault,
+          withholdingLedger,
+          authorityAta,
+          treasuryMint,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        })
+        .signers([daoAuthority])
+        .rpc();
+
+      const recipientAfter = await getAccount(provider.connection, taxedRecipientAta);
+      const vaultAfter = await getAccount(provider.connection, withholdingVault);
+      assert.equal(Number(recipientAfter.amount) - Number(recipientBefore.amount), 2250000);
+      assert.equal(Number(vaultAfter.amount) - Number(vaultBefore.amount), 750000);
+
+      const ledger = await program.account.withholdingLedger.fetch(withholdingLedger);
+      assert.equal(ledger.grossAmount.toNumber(), 4000000);
+      assert.equal(ledger.withheldAmount.toNumber(), 1000000);
+      assert.equal(ledger.payoutCount, 2);
+    });
+  });
+
+  describe("Vesting Revocation", () => {
+    const grantee = Keypair.generate();
+    let granteeVesting: PublicKey;
+    let granteeVestingAta: PublicKey;
+    let granteeAta: PublicKey;
+    const totalAmount = 10000000;
+
+    before(async () => {
+      await provider.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(grantee.publicKey, LAMPORTS_PER_SOL)
+      );
+      granteeAta = await createTokenAccount(treasuryMint, grantee.publicKey);
+      [granteeVesting] = getPda([Buffer.from("vesting"), daoConfig.toBuffer(), grantee.publicKey.toBuffer()]);
+      granteeVestingAta = getAssociatedTokenAddressSync(treasuryMint, granteeVesting, true);
+
+      const startTime = getCurrentTimestamp() + 2;
+      await program.methods
+        .createVesting(
+          { linear: {} },
+          new anchor.BN(totalAmount),
+          new anchor.BN(startTime),
+          new anchor.BN(startTime + 10),
+          new anchor.BN(startTime),
+          { contributors: {} },
+          "Revocable grant",
+          null,
+          defaultTerms,
+          null,
+          null,
+          null
+        )
+        .accounts({
+          daoConfig,
+          authority: daoAuthority.publicKey,
+          recipient: grantee.publicKey,
+          vesting: granteeVesting,
+          vestingAta: granteeVestingAta,
+          treasuryMint,
+          authorityAta,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([daoAuthority])
+        .rpc();
+
+      // Revoke roughly halfway through the schedule
+      await new Promise(resolve => setTimeout(resolve, 7000));
+    });
+
+    it("Should return the unvested remainder and keep the vested portion claimable", async () => {
+      const authorityBefore = await getAccount(provider.connection, authorityAta);
+      const daoBefore = await program.account.daoConfig.fetch(daoConfig);
+
+      await program.methods
+        .revokeVesting()
+        .accounts({
+          daoConfig,
+          authority: daoAuthority.publicKey,
+          vesting: granteeVesting,
+          vestingAta: granteeVestingAta,
+          authorityAta,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([daoAuthority])
+        .rpc();
+
+      const vestingAccount = await program.account.vesting.fetch(granteeVesting);
+      assert.deepEqual(vestingAccount.status, { revoked: {} });
+      assert.isNotNull(vestingAccount.revokedAt);
+
+      const vestedAmount = vestingAccount.totalAmount.toNumber();
+      assert.isAbove(vestedAmount, 0);
+      assert.isBelow(vestedAmount, totalAmount);
+
+      const authorityAfter = await getAccount(provider.connection, authorityAta);
+      assert.equal(Number(authorityAfter.amount) - Number(authorityBefore.amount), totalAmount - vestedAmount);
+
+      const daoAfter = await program.account.daoConfig.fetch(daoConfig);
+      assert.equal(
+        daoBefore.totalAllocated.toNumber() - daoAfter.totalAllocated.toNumber(),
+        totalAmount - vestedAmount
+      );
+
+      const escrow = await getAccount(provider.connection, granteeVestingAta);
+      assert.equal(Number(escrow.amount), vestedAmount);
+    });
+
+    it("Should let the recipient claim everything that vested before the revocation", async () => {
+      const vestedAmount = (await program.account.vesting.fetch(granteeVesting)).totalAmount.toNumber();
+
+      await program.methods
+        .claimVesting(new anchor.BN(vestedAmount))
+        .accounts({
+          daoConfig,
+          vesting: granteeVesting,
+          recipient: grantee.publicKey,
+          vestingAta: granteeVestingAta,
+          recipientAta: granteeAta,
+          treasuryMint,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([grantee])
+        .rpc();
+
+      const vestingAccount = await program.account.vesting.fetch(granteeVesting);
+      assert.deepEqual(vestingAccount.status, { completed: {} });
+      const granteeBalance = await getAccount(provider.connection, granteeAta);
+      assert.equal(Number(granteeBalance.amount), vestedAmount);
+    });
+  });
+
+  describe("Leaver Termination Policies", () => {
+    const leaver = Keypair.generate();
+    let leaverVesting: PublicKey;
+    let leaverVestingAta: PublicKey;
+    const totalAmount = 10000000;
+
+    const terminationPolicy = {
+      voluntary: { vestedKeepBps: 10000, unvestedKeepBps: 0 },
+      withoutCause: { vestedKeepBps: 10000, unvestedKeepBps: 2500 },
+      forCause: { vestedKeepBps: 5000, unvestedKeepBps: 0 },
+      claimWindow: new anchor.BN(60),
+    };
+
+    before(async () => {
+      [leaverVesting] = getPda([Buffer.from("vesting"), daoConfig.toBuffer(), leaver.publicKey.toBuffer()]);
+      leaverVestingAta = getAssociatedTokenAddressSync(treasuryMint, leaverVesting, true);
+
+      const startTime = getCurrentTimestamp() + 2;
+      await program.methods
+        .createVesting(
+          { linear: {} },
+          new anchor.BN(totalAmount),
+          new anchor.BN(startTime),
+          new anchor.BN(startTime + 10),
+          new anchor.BN(startTime),
+          { contributors: {} },
+          "Grant with leaver terms",
+          null,
+          defaultTerms,
+          terminationPolicy,
+          null,
+          null
+        )
+        .accounts({
+          daoConfig,
+          authority: daoAuthority.publicKey,
+          recipient: leaver.publicKey,
+          vesting: leaverVesting,
+          vestingAta: leaverVestingAta,
+          treasuryMint,
+          authorityAta,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([daoAuthority])
+        .rpc();
+
+      await new Promise(resolve => setTimeout(resolve, 7000));
+    });
+
+    it("Should apply the for-cause terms and open a claim window", async () => {
+      const authorityBefore = await getAccount(provider.connection, authorityAta);
+
+      await program.methods
+        .terminateVesting({ forCause: {} })
+        .accounts({
+          daoConfig,
+          authority: daoAuthority.publicKey,
+          vesting: leaverVesting,
+          vestingAta: leaverVestingAta,
+          authorityAta,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([daoAuthority])
+        .rpc();
+
+      const vestingAccount = await program.account.vesting.fetch(leaverVesting);
+      assert.deepEqual(vestingAccount.status, { revoked: {} });
+      assert.deepEqual(vestingAccount.terminationReason, { forCause: {} });
+
+      // Half of what had vested is kept; nothing unvested is
+      const retainedAmount = vestingAccount.totalAmount.toNumber();
+      assert.isAbove(retainedAmount, 0);
+      assert.isBelow(retainedAmount, totalAmount / 2);
+
+      const authorityAfter = await getAccount(provider.connection, authorityAta);
+      assert.equal(Number(authorityAfter.amount) - Number(authorityBefore.amount), totalAmount - retainedAmount);
+
+      assert.equal(
+        vestingAccount.expiryPolicy.claimDeadline.toNumber(),
+        vestingAccount.revokedAt.toNumber() + 60
+      );
+    });
+
+    it("Should not terminate a vesting twice", async () => {
+      try {
+        await program.methods
+          .terminateVesting({ voluntary: {} })
+          .accounts({
+            daoConfig,
+            authority: daoAuthority.publicKey,
+            vesting: leaverVesting,
+            vestingAta: leaverVestingAta,
+            authorityAta,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([daoAuthority])
+          .rpc();
+        assert.fail("Should have failed");
+      } catch (error) {
+        assert.include(error.message, "VestingCannotCancel");
+      }
+    });
+  });
+
+  describe("Multi-Tranche Vesting", () => {
+    const grantee = Keypair.generate();
+    let trancheVesting: PublicKey;
+    let trancheVestingAta: PublicKey;
+    const baseAmount = 4000000;
+    const refresherAmount = 2000000;
+
+    before(async () => {
+      [trancheVesting] = getPda([Buffer.from("vesting"), daoConfig.toBuffer(), grantee.publicKey.toBuffer()]);
+      trancheVestingAta = getAssociatedTokenAddressSync(treasuryMint, trancheVesting, true);
+
+      const startTime = getCurrentTimestamp() + 2;
+      await program.methods
+        .createVesting(
+          { linear: {} },
+          new anchor.BN(baseAmount),
+          new anchor.BN(startTime),
+          new anchor.BN(startTime + 3600),
+          new anchor.BN(startTime),
+          { contributors: {} },
+          "Graded grant",
+          null,
+          defaultTerms,
+          null,
+          null,
+          null
+        )
+        .accounts({
+          daoConfig,
+          authority: daoAuthority.publicKey,
+          recipient: grantee.publicKey,
+          vesting: trancheVesting,
+          vestingAta: trancheVestingAta,
+          treasuryMint,
+          authorityAta,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([daoAuthority])
+        .rpc();
+    });
+
+    it("Should add a refresher tranche funded by the authority", async () => {
+      const startTime = getCurrentTimestamp() + 3600;
+      const vaultBefore = await getAccount(provider.connection, trancheVestingAta);
+
+      await program.methods
+        .addVestingTranche({
+          amount: new anchor.BN(refresherAmount),
+          startTime: new anchor.BN(startTime),
+          cliffTime: new anchor.BN(startTime),
+          endTime: new anchor.BN(startTime + 7200),
+        })
+        .accounts({
+          daoConfig,
+          authority: daoAuthority.publicKey,
+          vesting: trancheVesting,
+          vestingAta: trancheVestingAta,
+          authorityAta,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([daoAuthority])
+        .rpc();
+
+      const vestingAccount = await program.account.vesting.fetch(trancheVesting);
+      assert.equal(vestingAccount.tranches.length, 1);
+      assert.equal(vestingAccount.tranches[0].amount.toNumber(), refresherAmount);
+      assert.equal(vestingAccount.totalAmount.toNumber(), baseAmount + refresherAmount);
+
+      const vaultAfter = await getAccount(provider.connection, trancheVestingAta);
+      assert.equal(Number(vaultAfter.amount) - Number(vaultBefore.amount), refresherAmount);
+    });
+
+    it("Should reject a tranche that ends before it starts", async () => {
+      const startTime = getCurrentTimestamp() + 3600;
+      try {
+        await program.methods
+          .addVestingTranche({
+            amount: new anchor.BN(refresherAmount),
+            startTime: new anchor.BN(startTime),
+            cliffTime: new anchor.BN(startTime),
+            endTime: new anchor.BN(startTime - 1),
+          })
+          .accounts({
+            daoConfig,
+            authority: daoAuthority.publicKey,
+            vesting: trancheVesting,
+            vestingAta: trancheVestingAta,
+            authorityAta,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([daoAuthority])
+          .rpc();
+        assert.fail("Should have failed");
+      } catch (error) {
+        assert.include(error.message, "InvalidVestingTiming");
+      }
+    });
+
+    it("Should let a tranche that starts before the base schedule be claimed", async () => {
+      const earlyGrantee = Keypair.generate();
+      const [earlyVesting] = getPda([Buffer.from("vesting"), daoConfig.toBuffer(), earlyGrantee.publicKey.toBuffer()]);
+      const earlyVestingAta = getAssociatedTokenAddressSync(treasuryMint, earlyVesting, true);
+      await provider.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(earlyGrantee.publicKey, LAMPORTS_PER_SOL)
+      );
+      const earlyGranteeAta = await createTokenAccount(treasuryMint, earlyGrantee.publicKey);
+
+      const baseStart = getCurrentTimestamp() + 3600;
+      await program.methods
+        .createVesting(
+          { linear: {} },
+          new anchor.BN(baseAmount),
+          new anchor.BN(baseStart),
+          new anchor.BN(baseStart + 3600),
+          new anchor.BN(baseStart),
+          { contributors: {} },
+          "Deferred grant",
+          null,
+          defaultTerms,
+          null,
+          null,
+          null
+        )
+        .accounts({
+          daoConfig,
+          authority: daoAuthority.publicKey,
+          recipient: earlyGrantee.publicKey,
+          vesting: earlyVesting,
+          vestingAta: earlyVestingAta,
+          treasuryMint,
+          authorityAta,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([daoAuthority])
+        .rpc();
+
+      const trancheStart = getCurrentTimestamp() + 1;
+      await program.methods
+        .addVestingTranche({
+          amount: new anchor.BN(refresherAmount),
+          startTime: new anchor.BN(trancheStart),
+          cliffTime: new anchor.BN(trancheStart),
+          endTime: new anchor.BN(trancheStart + 4),
+        })
+        .accounts({
+          daoConfig,
+          authority: daoAuthority.publicKey,
+          vesting: earlyVesting,
+          vestingAta: earlyVestingAta,
+          authorityAta,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([daoAuthority])
+        .rpc();
+
+      await new Promise(resolve => setTimeout(resolve, 6000));
+
+      await program.methods
+        .claimVesting(new anchor.BN(refresherAmount))
+        .accounts({
+          daoConfig,
+          vesting: earlyVesting,
+          recipient: earlyGrantee.publicKey,
+          vestingAta: earlyVestingAta,
+          recipientAta: earlyGranteeAta,
+          treasuryMint,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([earlyGrantee])
+        .rpc();
+
+      const granteeBalance = await getAccount(provider.connection, earlyGranteeAta);
+      assert.equal(Number(granteeBalance.amount), refresherAmount);
+    });
+  });
+
+  describe("TGE Unlock and Cliff Lump Sum", () => {
+    const holder = Keypair.generate();
+    let holderAta: PublicKey;
+    let tgeVesting: PublicKey;
+    let tgeVestingAta: PublicKey;
+    const totalAmount = 36000000;
+    const tgeUnlockBps = 2000;
+    const tgeAmount = (totalAmount * tgeUnlockBps) / 10000;
+
+    const claimAccounts = () => ({
+      daoConfig,
+      vesting: tgeVesting,
+      recipient: holder.publicKey,
+      vestingAta: tgeVestingAta,
+      recipientAta: holderAta,
+      treasuryMint,
+      systemProgram: SystemProgram.programId,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      rent: SYSVAR_RENT_PUBKEY,
+    });
+
+    before(async () => {
+      await provider.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(holder.publicKey, LAMPORTS_PER_SOL)
+      );
+      holderAta = await createTokenAccount(treasuryMint, holder.publicKey);
+      [tgeVesting] = getPda([Buffer.from("vesting"), daoConfig.toBuffer(), holder.publicKey.toBuffer()]);
+      tgeVestingAta = getAssociatedTokenAddressSync(treasuryMint, tgeVesting, true);
+
+      const startTime = getCurrentTimestamp() + 2;
+      await program.methods
+        .createVesting(
+          { cliff: {} },
+          new anchor.BN(totalAmount),
+          new anchor.BN(startTime),
+          new anchor.BN(startTime + 3600),
+          new anchor.BN(startTime + 8),
+          { contributors: {} },
+          "Launch allocation",
+          null,
+          defaultTerms,
+          null,
+          tgeUnlockBps,
+          null
+        )
+        .accounts({
+          daoConfig,
+          authority: daoAuthority.publicKey,
+          recipient: holder.publicKey,
+          vesting: tgeVesting,
+          vestingAta: tgeVestingAta,
+          treasuryMint,
+          authorityAta,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([daoAuthority])
+        .rpc();
+
+      await new Promise(resolve => setTimeout(resolve, 4000));
+    });
+
+    it("Should release only the TGE unlock before the cliff", async () => {
+      try {
+        await program.methods
+          .claimVesting(new anchor.BN(tgeAmount + 1))
+          .accounts(claimAccounts())
+          .signers([holder])
+          .rpc();
+        assert.fail("Should have failed");
+      } catch (error) {
+        assert.include(error.message, "InsufficientVestedTokens");
+      }
+
+      await program.methods
+        .claimVesting(new anchor.BN(tgeAmount))
+        .accounts(claimAccounts())
+        .signers([holder])
+        .rpc();
+
+      const holderBalance = await getAccount(provider.connection, holderAta);
+      assert.equal(Number(holderBalance.amount), tgeAmount);
+    });
+
+    it("Should release what accrued since start as a lump sum at the cliff", async () => {
+      await new Promise(resolve => setTimeout(resolve, 8000));
+
+      // At least eight seconds of the linear part accrued before the cliff
+      const lumpSum = ((totalAmount - tgeAmount) * 8) / 3600;
+      await program.methods
+        .claimVesting(new anchor.BN(lumpSum))
+        .accounts(claimAccounts())
+        .signers([holder])
+        .rpc();
+
+      const vestingAccount = await program.account.vesting.fetch(tgeVesting);
+      assert.equal(vestingAccount.tgeUnlockBps, tgeUnlockBps);
+      assert.equal(vestingAccount.claimedAmount.toNumber(), tgeAmount + lumpSum);
+    });
+
+    it("Should reject a TGE unlock above 100%", async () => {
+      const other = Keypair.generate();
+      const [otherVesting] = getPda([Buffer.from("vesting"), daoConfig.toBuffer(), other.publicKey.toBuffer()]);
+      const startTime = getCurrentTimestamp() + 10;
+      try {
+        await program.methods
+          .createVesting(
+            { linear: {} },
+            new anchor.BN(totalAmount),
+            new anchor.BN(startTime),
+            new anchor.BN(startTime + 3600),
+            new anchor.BN(startTime),
+            { contributors: {} },
+            "Over-unlocked grant",
+            null,
+            defaultTerms,
+            null,
+            10001,
+            null
+          )
+          .accounts({
+            daoConfig,
+            authority: daoAuthority.publicKey,
+            recipient: other.publicKey,
+            vesting: otherVesting,
+            vestingAta: getAssociatedTokenAddressSync(treasuryMint, otherVesting, true),
+            treasuryMint,
+            authorityAta,
+            systemProgram: SystemProgram.programId,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            rent: SYSVAR_RENT_PUBKEY,
+          })
+          .signers([daoAuthority])
+          .rpc();
+        assert.fail("Should have failed");
+      } catch (error) {
+        assert.include(error.message, "InvalidTgeUnlock");
+      }
+    });
+  });
+
+  describe("Backdated Vesting Grants", () => {
+    const veteran = Keypair.generate();
+    let veteranAta: PublicKey;
+    let backdatedVesting: PublicKey;
+    let backdatedVestingAta: PublicKey;
+    const totalAmount = 7200000;
+
+    const createBackdatedVesting = (startTime: number) =>
+      program.methods
+        .createVesting(
+          { linear: {} },
+          new anchor.BN(totalAmount),
+          new anchor.BN(startTime),
+          new anchor.BN(startTime + 7200),
+          new anchor.BN(startTime),
+          { contributors: {} },
+          "Prior service grant",
+          null,
+          defaultTerms,
+          null,
+          null,
+          null
+        )
+        .accounts({
+          daoConfig,
+          authority: daoAuthority.publicKey,
+          recipient: veteran.publicKey,
+          vesting: backdatedVesting,
+          vestingAta: backdatedVestingAta,
+          treasuryMint,
+          authorityAta,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([daoAuthority])
+        .rpc();
+
+    before(async () => {
+      await provider.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(veteran.publicKey, LAMPORTS_PER_SOL)
+      );
+      veteranAta = await createTokenAccount(treasuryMint, veteran.publicKey);
+      [backdatedVesting] = getPda([Buffer.from("vesting"), daoConfig.toBuffer(), veteran.publicKey.toBuffer()]);
+      backdatedVestingAta = getAssociatedTokenAddressSync(treasuryMint, backdatedVesting, true);
+    });
+
+    it("Should reject backdating while the look-back is disabled", async () => {
+      try {
+        await createBackdatedVesting(getCurrentTimestamp() - 3600);
+        assert.fail("Should have failed");
+      } catch (error) {
+        assert.include(error.message, "BackdateExceedsLimit");
+      }
+    });
+
+    it("Should create a flagged grant within the look-back", async () => {
+      await program.methods
+        .setMaxBackdatePeriod(new anchor.BN(86400))
+        .accounts({ daoConfig, authority: daoAuthority.publicKey })
+        .signers([daoAuthority])
+        .rpc();
+
+      const dao = await program.account.daoConfig.fetch(daoConfig);
+      assert.equal(dao.maxBackdatePeriod.toNumber(), 86400);
+
+      await createBackdatedVesting(getCurrentTimestamp() - 3600);
+
+      const vestingAccount = await program.account.vesting.fetch(backdatedVesting);
+      assert.isTrue(vestingAccount.backdated);
+      assert.isBelow(vestingAccount.startTime.toNumber(), vestingAccount.createdAt.toNumber());
+    });
+
+    it("Should make the vested-to-date amount claimable immediately", async () => {
+      const claimAmount = totalAmount / 2 - 100000;
+      await program.methods
+        .claimVesting(new anchor.BN(claimAmount))
+        .accounts({
+          daoConfig,
+          vesting: backdatedVesting,
+          recipient: veteran.publicKey,
+          vestingAta: backdatedVestingAta,
+          recipientAta: veteranAta,
+          treasuryMint,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([veteran])
+        .rpc();
+
+      const veteranBalance = await getAccount(provider.connection, veteranAta);
+      assert.equal(Number(veteranBalance.amount), claimAmount);
+
+      await program.methods
+        .setMaxBackdatePeriod(new anchor.BN(0))
+        .accounts({ daoConfig, authority: daoAuthority.publicKey })
+        .signers([daoAuthority])
+        .rpc();
+    });
+
+    it("Should hold tranches to the same look-back", async () => {
+      const trancheStart = getCurrentTimestamp() - 3600;
+      try {
+        await program.methods
+          .addVestingTranche({
+            amount: new anchor.BN(1000000),
+            startTime: new anchor.BN(trancheStart),
+            cliffTime: new anchor.BN(trancheStart),
+            endTime: new anchor.BN(trancheStart + 7200),
+          })
+          .accounts({
+            daoConfig,
+            authority: daoAuthority.publicKey,
+            vesting: backdatedVesting,
+            vestingAta: backdatedVestingAta,
+            authorityAta,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([daoAuthority])
+          .rpc();
+        assert.fail("Should have failed");
+      } catch (error) {
+        assert.include(error.message, "BackdateExceedsLimit");
+      }
+    });
+
+    it("Should leave an up-to-date DAO config as it is when migrating", async () => {
+      const daoBefore = await provider.connection.getAccountInfo(daoConfig);
+      await program.methods
+        .migrateDaoConfig()
+        .accounts({
+          daoConfig,
+          authority: daoAuthority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([daoAuthority])
+        .rpc();
+
+      const daoAfter = await provider.connection.getAccountInfo(daoConfig);
+      assert.equal(daoAfter.data.length, daoBefore.data.length);
+      assert.equal(daoAfter.lamports, daoBefore.lamports);
+    });
+  });
+
+  describe("Vesting Acceleration", () => {
+    const founder = Keypair.generate();
+    const engineer = Keypair.generate();
+    const lateHire = Keypair.generate();
+    let founderAta: PublicKey;
+    let founderVesting: PublicKey;
+    let founderVestingAta: PublicKey;
+    let engineerVesting: PublicKey;
+    let engineerVestingAta: PublicKey;
+    let accelerationEvent: PublicKey;
+    const totalAmount = 10000000;
+
+    const getAccelerationEvent = (index: number) => {
+      const indexBytes = Buffer.alloc(4);
+      indexBytes.writeUInt32LE(index);
+      return getPda([Buffer.from("acceleration_event"), daoConfig.toBuffer(), indexBytes])[0];
+    };
+
+    const createAcceleratedVesting = async (recipient: PublicKey, vesting: PublicKey, vestingAta: PublicKey) => {
+      const startTime = getCurrentTimestamp() + 2;
+      await program.methods
+        .createVesting(
+          { linear: {} },
+          new anchor.BN(totalAmount),
+          new anchor.BN(startTime),
+          new anchor.BN(startTime + 3600),
+          new anchor.BN(startTime),
+          { contributors: {} },
+          "Grant with acceleration",
+          null,
+          defaultTerms,
+          null,
+          null,
+          null
+        )
+        .accounts({
+          daoConfig,
+          authority: daoAuthority.publicKey,
+          recipient,
+          vesting,
+          vestingAta,
+          treasuryMint,
+          authorityAta,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([daoAuthority])
+        .rpc();
+    };
+
+    before(async () => {
+      await provider.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(founder.publicKey, LAMPORTS_PER_SOL)
+      );
+      founderAta = await createTokenAccount(treasuryMint, founder.publicKey);
+      [founderVesting] = getPda([Buffer.from("vesting"), daoConfig.toBuffer(), founder.publicKey.toBuffer()]);
+      founderVestingAta = getAssociatedTokenAddressSync(treasuryMint, founderVesting, true);
+      [engineerVesting] = getPda([Buffer.from("vesting"), daoConfig.toBuffer(), engineer.publicKey.toBuffer()]);
+      engineerVestingAta = getAssociatedTokenAddressSync(treasuryMint, engineerVesting, true);
+      accelerationEvent = getAccelerationEvent(0);
+
+      await createAcceleratedVesting(founder.publicKey, founderVesting, founderVestingAta);
+      await createAcceleratedVesting(engineer.publicKey, engineerVesting, engineerVestingAta);
+
+      await program.methods
+        .setAccelerationTerms({ trigger: { singleTrigger: {} }, accelerateBps: 5000, terminationWindow: new anchor.BN(0) })
+        .accounts({ daoConfig, authority: daoAuthority.publicKey, vesting: founderVesting })
+        .signers([daoAuthority])
+        .rpc();
+      await program.methods
+        .setAccelerationTerms({ trigger: { doubleTrigger: {} }, accelerateBps: 10000, terminationWindow: new anchor.BN(3600) })
+        .accounts({ daoConfig, authority: daoAuthority.publicKey, vesting: engineerVesting })
+        .signers([daoAuthority])
+        .rpc();
+
+      await new Promise(resolve => setTimeout(resolve, 3000));
+    });
+
+    it("Should not accelerate before the DAO triggers the event", async () => {
+      try {
+        await program.methods
+          .applyAcceleration()
+          .accounts({ accelerationEvent, vesting: founderVesting })
+          .rpc();
+        assert.fail("Should have failed");
+      } catch (error) {
+        assert.include(error.message, "AccountNotInitialized");
+      }
+    });
+
+    it("Should only raise the acceleration terms of an irrevocable grant", async () => {
+      const irrevocable = Keypair.generate();
+      const [irrevocableVesting] = getPda([Buffer.from("vesting"), daoConfig.toBuffer(), irrevocable.publicKey.toBuffer()]);
+      const startTime = getCurrentTimestamp() + 2;
+      await program.methods
+        .createVesting(
+          { linear: {} },
+          new anchor.BN(totalAmount),
+          new anchor.BN(startTime),
+          new anchor.BN(startTime + 3600),
+          new anchor.BN(startTime),
+          { contributors: {} },
+          "Irrevocable grant with acceleration",
+          null,
+          { ...defaultTerms, cancelableByAuthority: false },
+          null,
+          null,
+          null
+        )
+        .accounts({
+          daoConfig,
+          authority: daoAuthority.publicKey,
+          recipient: irrevocable.publicKey,
+          vesting: irrevocableVesting,
+          vestingAta: getAssociatedTokenAddressSync(treasuryMint, irrevocableVesting, true),
+          treasuryMint,
+          authorityAta,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([daoAuthority])
+        .rpc();
+
+      const setTerms = (terms: any) =>
+        program.methods
+          .setAccelerationTerms(terms)
+          .accounts({ daoConfig, authority: daoAuthority.publicKey, vesting: irrevocableVesting })
+          .signers([daoAuthority])
+          .rpc();
+
+      await setTerms({ trigger: { doubleTrigger: {} }, accelerateBps: 5000, terminationWindow: new anchor.BN(3600) });
+      await setTerms({ trigger: { singleTrigger: {} }, accelerateBps: 7500, terminationWindow: new anchor.BN(0) });
+
+      for (const cut of [
+        null,
+        { trigger: { singleTrigger: {} }, accelerateBps: 5000, terminationWindow: new anchor.BN(0) },
+        { trigger: { doubleTrigger: {} }, accelerateBps: 10000, terminationWindow: new anchor.BN(3600) },
+      ]) {
+        try {
+          await setTerms(cut);
+          assert.fail("Should have failed");
+        } catch (error) {
+          assert.include(error.message, "NotCancelableByAuthority");
+        }
+      }
+    });
+
+    it("Should let a crank accelerate a single-trigger vesting", async () => {
+      await program.methods
+        .triggerAcceleration()
+        .accounts({
+          daoConfig,
+          authority: daoAuthority.publicKey,
+          accelerationEvent,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([daoAuthority])
+        .rpc();
+
+      await program.methods
+        .applyAcceleration()
+        .accounts({ accelerationEvent, vesting: founderVesting })
+        .rpc();
+
+      const vestingAccount = await program.account.vesting.fetch(founderVesting);
+      assert.isNotNull(vestingAccount.acceleratedAt);
+
+      // Half of the unvested amount vested on top of the schedule
+      await program.methods
+        .claimVesting(new anchor.BN(totalAmount / 2))
+        .accounts({
+          daoConfig,
+          vesting: founderVesting,
+          recipient: founder.publicKey,
+          vestingAta: founderVestingAta,
+          recipientAta: founderAta,
+          treasuryMint,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([founder])
+        .rpc();
+
+      const founderBalance = await getAccount(provider.connection, founderAta);
+      assert.equal(Number(founderBalance.amount), totalAmount / 2);
+    });
+
+    it("Should not accelerate a grant made after the event", async () => {
+      const [lateVesting] = getPda([Buffer.from("vesting"), daoConfig.toBuffer(), lateHire.publicKey.toBuffer()]);
+      const lateVestingAta = getAssociatedTokenAddressSync(treasuryMint, lateVesting, true);
+      await new Promise(resolve => setTimeout(resolve, 1000));
+      await createAcceleratedVesting(lateHire.publicKey, lateVesting, lateVestingAta);
+      await program.methods
+        .setAccelerationTerms({ trigger: { singleTrigger: {} }, accelerateBps: 5000, terminationWindow: new anchor.BN(0) })
+        .accounts({ daoConfig, authority: daoAuthority.publicKey, vesting: lateVesting })
+        .signers([daoAuthority])
+        .rpc();
+
+      try {
+        await program.methods
+          .applyAcceleration()
+          .accounts({ accelerationEvent, vesting: lateVesting })
+          .rpc();
+        assert.fail("Should have failed");
+      } catch (error) {
+        assert.include(error.message, "AccelerationNotTriggered");
+      }
+    });
+
+    it("Should wait for the second trigger on a double-trigger vesting", async () => {
+      try {
+        await program.methods
+          .applyAcceleration()
+          .accounts({ accelerationEvent, vesting: engineerVesting })
+          .rpc();
+        assert.fail("Should have failed");
+      } catch (error) {
+        assert.include(error.message, "AccelerationNotTriggered");
+      }
+    });
+
+    it("Should fix the terms of grants the event covers", async () => {
+      try {
+        await program.methods
+          .setAccelerationTerms(null)
+          .accounts({ daoConfig, authority: daoAuthority.publicKey, vesting: engineerVesting })
+          .signers([daoAuthority])
+          .rpc();
+        assert.fail("Should have failed");
+      } catch (error) {
+        assert.include(error.message, "AccelerationTermsLocked");
+      }
+    });
+
+    it("Should accelerate on termination without cause after the event", async () => {
+      await program.methods
+        .terminateVesting({ withoutCause: {} })
+        .accounts({
+          daoConfig,
+          authority: daoAuthority.publicKey,
+          vesting: engineerVesting,
+          vestingAta: engineerVestingAta,
+          authorityAta,
+          accelerationEvent,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([daoAuthority])
+        .rpc();
+
+      const vestingAccount = await program.account.vesting.fetch(engineerVesting);
+      assert.isNotNull(vestingAccount.acceleratedAt);
+      assert.equal(vestingAccount.totalAmount.toNumber(), totalAmount);
+    });
+
+    it("Should declare a later change of control under its own event", async () => {
+      const [lateVesting] = getPda([Buffer.from("vesting"), daoConfig.toBuffer(), lateHire.publicKey.toBuffer()]);
+      const secondEvent = getAccelerationEvent(1);
+      await new Promise(resolve => setTimeout(resolve, 1000));
+      await program.methods
+        .triggerAcceleration()
+        .accounts({
+          daoConfig,
+          authority: daoAuthority.publicKey,
+          accelerationEvent: secondEvent,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([daoAuthority])
+        .rpc();
+
+      const dao = await program.account.daoConfig.fetch(daoConfig);
+      assert.equal(dao.accelerationEventCount, 2);
+      const event = await program.account.accelerationEvent.fetch(secondEvent);
+      assert.equal(event.index, 1);
+
+      // The late hire predates the second change of control, so it is covered by it
+      await program.methods
+        .applyAcceleration()
+        .accounts({ accelerationEvent: secondEvent, vesting: lateVesting })
+        .rpc();
+
+      const vestingAccount = await program.account.vesting.fetch(lateVesting);
+      assert.isNotNull(vestingAccount.acceleratedAt);
+    });
+  });
+
+  describe("Merge and Split Vestings", () => {
+    const primary = Keypair.generate();
+    const secondary = Keypair.generate();
+    const carvedRecipient = Keypair.generate();
+    const transferableTerms = { ...defaultTerms, transferable: true };
+    const primaryAmount = 6000000;
+    const secondaryAmount = 3000000;
+    let primaryVesting: PublicKey;
+    let primaryVestingAta: PublicKey;
+    let secondaryVesting: PublicKey;
+    let secondaryVestingAta: PublicKey;
+
+    const createMergeableVesting = async (recipient: PublicKey, vesting: PublicKey, vestingAta: PublicKey, amount: number, startTime: number) => {
+      await program.methods
+        .createVesting(
+          { linear: {} },
+          new anchor.BN(amount),
+          new anchor.BN(startTime),
+          new anchor.BN(startTime + 3600),
+          new anchor.BN(startTime),
+          { contributors: {} },
+          "Fragmented grant",
+          null,
+          transferableTerms,
+          null,
+          null,
+          null
+        )
+        .accounts({
+          daoConfig,
+          authority: daoAuthority.publicKey,
+          recipient,
+          vesting,
+          vestingAta,
+          treasuryMint,
+          authorityAta,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([daoAuthority])
+        .rpc();
+    };
+
+    before(async () => {
+      for (const holder of [primary, secondary]) {
+        await provider.connection.confirmTransaction(
+          await provider.connection.requestAirdrop(holder.publicKey, LAMPORTS_PER_SOL)
+        );
+      }
+      [primaryVesting] = getPda([Buffer.from("vesting"), daoConfig.toBuffer(), primary.publicKey.toBuffer()]);
+      primaryVestingAta = getAssociatedTokenAddressSync(treasuryMint, primaryVesting, true);
+      [secondaryVesting] = getPda([Buffer.from("vesting"), daoConfig.toBuffer(), secondary.publicKey.toBuffer()]);
+      secondaryVestingAta = getAssociatedTokenAddressSync(treasuryMint, secondaryVesting, true);
+
+      const startTime = getCurrentTimestamp() + 10;
+      await createMergeableVesting(primary.publicKey, primaryVesting, primaryVestingAta, primaryAmount, startTime);
+      await createMergeableVesting(secondary.publicKey, secondaryVesting, secondaryVestingAta, secondaryAmount, startTime + 600);
+    });
+
+    it("Should merge a position into another, keeping its schedule as a tranche", async () => {
+      const daoBefore = await program.account.daoConfig.fetch(daoConfig);
+      await program.methods
+        .mergeVestings()
+        .accounts({
+          daoConfig,
+          sourceVesting: secondaryVesting,
+          targetVesting: primaryVesting,
+          sourceRecipient: secondary.publicKey,
+          targetRecipient: primary.publicKey,
+          sourceVestingAta: secondaryVestingAta,
+          targetVestingAta: primaryVestingAta,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([secondary, primary])
+        .rpc();
+
+      const merged = await program.account.vesting.fetch(primaryVesting);
+      assert.equal(merged.totalAmount.toNumber(), primaryAmount + secondaryAmount);
+      assert.equal(merged.tranches.length, 1);
+      assert.equal(merged.tranches[0].amount.toNumber(), secondaryAmount);
+
+      const escrow = await getAccount(provider.connection, primaryVestingAta);
+      assert.equal(Number(escrow.amount), primaryAmount + secondaryAmount);
+      assert.isNull(await provider.connection.getAccountInfo(secondaryVesting));
+
+      const daoAfter = await program.account.daoConfig.fetch(daoConfig);
+      assert.equal(daoAfter.totalStreams, daoBefore.totalStreams - 1);
+    });
+
+    it("Should split a position into two that add back up to the original", async () => {
+      const [carvedVesting] = getPda([Buffer.from("vesting"), daoConfig.toBuffer(), carvedRecipient.publicKey.toBuffer()]);
+      const carvedVestingAta = getAssociatedTokenAddressSync(treasuryMint, carvedVesting, true);
+
+      await program.methods
+        .splitVesting(2500)
+        .accounts({
+          daoConfig,
+          vesting: primaryVesting,
+          recipient: primary.publicKey,
+          newRecipient: carvedRecipient.publicKey,
+          newVesting: carvedVesting,
+          vestingAta: primaryVestingAta,
+          newVestingAta: carvedVestingAta,
+          treasuryMint,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        })
+        .signers([primary])
+        .rpc();
+
+      const remaining = await program.account.vesting.fetch(primaryVesting);
+      const carved = await program.account.vesting.fetch(carvedVesting);
+      assert.equal(carved.totalAmount.toNumber(), (primaryAmount + secondaryAmount) / 4);
+      assert.equal(carved.tranches[0].amount.toNumber(), secondaryAmount / 4);
+      assert.equal(
+        remaining.totalAmount.toNumber() + carved.totalAmount.toNumber(),
+        primaryAmount + secondaryAmount
+      );
+      assert.deepEqual(carved.recipient, carvedRecipient.publicKey);
+
+      const carvedEscrow = await getAccount(provider.connection, carvedVestingAta);
+      assert.equal(Number(carvedEscrow.amount), carved.totalAmount.toNumber());
+    });
+
+    it("Should reject a split that carves out everything", async () => {
+      const other = Keypair.generate();
+      const [otherVesting] = getPda([Buffer.from("vesting"), daoConfig.toBuffer(), other.publicKey.toBuffer()]);
+      try {
+        await program.methods
+          .splitVesting(10000)
+          .accounts({
+            daoConfig,
+            vesting: primaryVesting,
+            recipient: primary.publicKey,
+            newRecipient: other.publicKey,
+            newVesting: otherVesting,
+            vestingAta: primaryVestingAta,
+            newVestingAta: getAssociatedTokenAddressSync(treasuryMint, otherVesting, true),
+            treasuryMint,
+            systemProgram: SystemProgram.programId,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          })
+          .signers([primary])
+          .rpc();
+        assert.fail("Should have failed");
+      } catch (error) {
+        assert.include(error.message, "InvalidSplitShare");
+      }
+    });
+  });
+
+  describe("Vesting Pools", () => {
+    const poolName = "esop";
+    const employee = Keypair.generate();
+    let vestingPool: PublicKey;
+    let poolAta: PublicKey;
+    let poolVesting: PublicKey;
+    let poolVestingAta: PublicKey;
+    const fundedAmount = 5000000;
+    const grantAmount = 2000000;
+
+    const createPoolVesting = (recipient: PublicKey, vesting: PublicKey, amount: number) => {
+      const startTime = getCurrentTimestamp() + 2;
+      return program.methods
+        .createVesting(
+          { linear: {} },
+          new anchor.BN(amount),
+          new anchor.BN(startTime),
+          new anchor.BN(startTime + 3600),
+          new anchor.BN(startTime),
+          { contributors: {} },
+          "Pool grant",
+          null,
+          defaultTerms,
+          null,
+          null,
+          null
+        )
+        .accounts({
+          daoConfig,
+          authority: daoAuthority.publicKey,
+          recipient,
+          vesting,
+          vestingAta: getAssociatedTokenAddressSync(treasuryMint, vesting, true),
+          treasuryMint,
+          authorityAta,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+          vestingPool,
+          poolAta,
+        })
+        .signers([daoAuthority])
+        .rpc();
+    };
+
+    before(async () => {
+      [vestingPool] = getPda([Buffer.from("vesting_pool"), daoConfig.toBuffer(), Buffer.from(poolName)]);
+      poolAta = getAssociatedTokenAddressSync(treasuryMint, vestingPool, true);
+      [poolVesting] = getPda([Buffer.from("vesting"), daoConfig.toBuffer(), employee.publicKey.toBuffer()]);
+      poolVestingAta = getAssociatedTokenAddressSync(treasuryMint, poolVesting, true);
+
+      await program.methods
+        .createVestingPool(poolName)
+        .accounts({
+          daoConfig,
+          authority: daoAuthority.publicKey,
+          vestingPool,
+          poolAta,
+          treasuryMint,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        })
+        .signers([daoAuthority])
+        .rpc();
+
+      const daoBefore = await program.account.daoConfig.fetch(daoConfig);
+      await program.methods
+        .fundVestingPool(new anchor.BN(fundedAmount))
+        .accounts({
+          daoConfig,
+          authority: daoAuthority.publicKey,
+          vestingPool,
+          poolAta,
+          authorityAta,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([daoAuthority])
+        .rpc();
+
+      // Funding commits the tokens against the treasury's allocation limit
+      const daoAfter = await program.account.daoConfig.fetch(daoConfig);
+      assert.equal(daoAfter.totalAllocated.toNumber(), daoBefore.totalAllocated.toNumber() + fundedAmount);
+    });
+
+    it("Should carve a grant out of the pool", async () => {
+      const authorityBefore = await getAccount(provider.connection, authorityAta);
+      const daoBefore = await program.account.daoConfig.fetch(daoConfig);
+      await createPoolVesting(employee.publicKey, poolVesting, grantAmount);
+
+      // The pool's funding already counts the grant, so the treasury does not count it again
+      const daoAfter = await program.account.daoConfig.fetch(daoConfig);
+      assert.equal(daoAfter.totalAllocated.toNumber(), daoBefore.totalAllocated.toNumber());
+
+      const pool = await program.account.vestingPool.fetch(vestingPool);
+      assert.equal(pool.allocatedAmount.toNumber(), grantAmount);
+      assert.equal(pool.grantCount, 1);
+
+      const vestingAccount = await program.account.vesting.fetch(poolVesting);
+      assert.deepEqual(vestingAccount.pool, vestingPool);
+
+      const poolBalance = await getAccount(provider.connection, poolAta);
+      assert.equal(Number(poolBalance.amount), fundedAmount - grantAmount);
+      const authorityAfter = await getAccount(provider.connection, authorityAta);
+      assert.equal(Number(authorityAfter.amount), Number(authorityBefore.amount));
+    });
+
+    it("Should not grant more than the pool holds", async () => {
+      const other = Keypair.generate();
+      const [otherVesting] = getPda([Buffer.from("vesting"), daoConfig.toBuffer(), other.publicKey.toBuffer()]);
+      try {
+        await createPoolVesting(other.publicKey, otherVesting, fundedAmount);
+        assert.fail("Should have failed");
+      } catch (error) {
+        assert.include(error.message, "InsufficientPoolBalance");
+      }
+    });
+
+    it("Should recycle forfeited tokens back into the pool", async () => {
+      await new Promise(resolve => setTimeout(resolve, 4000));
+      const daoBefore = await program.account.daoConfig.fetch(daoConfig);
+
+      await program.methods
+        .revokeVesting()
+        .accounts({
+          daoConfig,
+          authority: daoAuthority.publicKey,
+          vesting: poolVesting,
+          vestingAta: poolVestingAta,
+          authorityAta,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          vestingPool,
+          poolAta,
+        })
+        .signers([daoAuthority])
+        .rpc();
+
+      const vestingAccount = await program.account.vesting.fetch(poolVesting);
+      const forfeited = grantAmount - vestingAccount.totalAmount.toNumber();
+      assert.isAbove(forfeited, 0);
+
+      const pool = await program.account.vestingPool.fetch(vestingPool);
+      assert.equal(pool.forfeitedAmount.toNumber(), forfeited);
+      assert.equal(pool.allocatedAmount.toNumber(), grantAmount - forfeited);
+
+      const poolBalance = await getAccount(provider.connection, poolAta);
+      assert.equal(Number(poolBalance.amount), fundedAmount - grantAmount + forfeited);
+
+      const daoAfter = await program.account.daoConfig.fetch(daoConfig);
+      assert.equal(daoAfter.totalAllocated.toNumber(), daoBefore.totalAllocated.toNumber());
+    });
+
+    it("Should report pool totals across its grants", async () => {
+      await program.methods
+        .reportVestingPool()
+        .accounts({ vestingPool })
+        .remainingAccounts([{ pubkey: poolVesting, isWritable: false, isSigner: false }])
+        .rpc();
+    });
+  });
+
+  describe("Option Grants", () => {
+    const optionee = Keypair.generate();
+    let optioneeAta: PublicKey;
+    let optioneeStrikeAta: PublicKey;
+    let treasuryStrikeAta: PublicKey;
+    let strikeMint: PublicKey;
+    let optionVesting: PublicKey;
+    let optionVestingAta: PublicKey;
+    const totalAmount = 36000000;
+    const strikePrice = 500000; // 0.5 strike tokens per whole token
+
+    before(async () => {
+      await provider.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(optionee.publicKey, LAMPORTS_PER_SOL)
+      );
+      strikeMint = await createMint(provider.connection, daoAuthority, daoAuthority.publicKey, null, 6);
+      optioneeStrikeAta = await createTokenAccount(strikeMint, optionee.publicKey);
+      treasuryStrikeAta = await createTokenAccount(strikeMint, daoAuthority.publicKey);
+      await mintTo(provider.connection, daoAuthority, strikeMint, optioneeStrikeAta, daoAuthority, 10000000);
+
+      optioneeAta = getAssociatedTokenAddressSync(treasuryMint, optionee.publicKey);
+      [optionVesting] = getPda([Buffer.from("vesting"), daoConfig.toBuffer(), optionee.publicKey.toBuffer()]);
+      optionVestingAta = getAssociatedTokenAddressSync(treasuryMint, optionVesting, true);
+
+      const startTime = getCurrentTimestamp() + 2;
+      await program.methods
+        .createVesting(
+          { linear: {} },
+          new anchor.BN(totalAmount),
+          new anchor.BN(startTime),
+          new anchor.BN(startTime + 20),
+          new anchor.BN(startTime),
+          { contributors: {} },
+          "Option grant",
+          null,
+          defaultTerms,
+          null,
+          null,
+          { strikeMint, strikePrice: new anchor.BN(strikePrice), exerciseWindow: new anchor.BN(3600) }
+        )
+        .accounts({
+          daoConfig,
+          authority: daoAuthority.publicKey,
+          recipient: optionee.publicKey,
+          vesting: optionVesting,
+          vestingAta: optionVestingAta,
+          treasuryMint,
+          authorityAta,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([daoAuthority])
+        .rpc();
+
+      await new Promise(resolve => setTimeout(resolve, 8000));
+    });
+
+    it("Should not let an option grant be claimed without paying the strike", async () => {
+      try {
+        await program.methods
+          .claimVesting(new anchor.BN(1000000))
+          .accounts({
+            daoConfig,
+            vesting: optionVesting,
+            recipient: optionee.publicKey,
+            vestingAta: optionVestingAta,
+            recipientAta: optioneeAta,
+            treasuryMint,
+            systemProgram: SystemProgram.programId,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            rent: SYSVAR_RENT_PUBKEY,
+          })
+          .signers([optionee])
+          .rpc();
+        assert.fail("Should have failed");
+      } catch (error) {
+        assert.include(error.message, "OptionMustBeExercised");
+      }
+    });
+
+    it("Should exercise vested options against the strike price", async () => {
+      const exerciseAmount = 2000000;
+
+      await program.methods
+        .exerciseOption(new anchor.BN(exerciseAmount))
+        .accounts({
+          daoConfig,
+          vesting: optionVesting,
+          recipient: optionee.publicKey,
+          vestingAta: optionVestingAta,
+          recipientAta: optioneeAta,
+          strikeMint,
+          recipientStrikeAta: optioneeStrikeAta,
+          treasuryStrikeAta,
+          treasuryMint,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        })
+        .signers([optionee])
+        .rpc();
+
+      const optioneeBalance = await getAccount(provider.connection, optioneeAta);
+      assert.equal(Number(optioneeBalance.amount), exerciseAmount);
+
+      const treasuryStrike = await getAccount(provider.connection, treasuryStrikeAta);
+      assert.equal(Number(treasuryStrike.amount), (exerciseAmount * strikePrice) / 1000000);
+
+      const vestingAccount = await program.account.vesting.fetch(optionVesting);
+      assert.equal(vestingAccount.claimedAmount.toNumber(), exerciseAmount);
+    });
+  });
+
+  describe("Milestone-Gated Vesting", () => {
+    const builder = Keypair.generate();
+    const attester = Keypair.generate();
+    let builderAta: PublicKey;
+    let gatedVesting: PublicKey;
+    let gatedVestingAta: PublicKey;
+    let mainnetMilestone: PublicKey;
+    let auditMilestone: PublicKey;
+    const totalAmount = 10000000;
+
+    const claimAccounts = () => ({
+      daoConfig,
+      vesting: gatedVesting,
+      recipient: builder.publicKey,
+      vestingAta: gatedVestingAta,
+      recipientAta: builderAta,
+      treasuryMint,
+      systemProgram: SystemProgram.programId,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      rent: SYSVAR_RENT_PUBKEY,
+    });
+
+    const createMilestone = (name: string, milestone: PublicKey, deadline: number) =>
+      program.methods
+        .createMilestone(name, attester.publicKey, new anchor.BN(deadline))
+        .accounts({
+          daoConfig,
+          authority: daoAuthority.publicKey,
+          milestone,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([daoAuthority])
+        .rpc();
+
+    before(async () => {
+      await provider.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(builder.publicKey, LAMPORTS_PER_SOL)
+      );
+      builderAta = await createTokenAccount(treasuryMint, builder.publicKey);
+      [gatedVesting] = getPda([Buffer.from("vesting"), daoConfig.toBuffer(), builder.publicKey.toBuffer()]);
+      gatedVestingAta = getAssociatedTokenAddressSync(treasuryMint, gatedVesting, true);
+      [mainnetMilestone] = getPda([Buffer.from("milestone"), daoConfig.toBuffer(), Buffer.from("mainnet")]);
+      [auditMilestone] = getPda([Buffer.from("milestone"), daoConfig.toBuffer(), Buffer.from("audit")]);
+
+      const now = getCurrentTimestamp();
+      await createMilestone("mainnet", mainnetMilestone, now + 3600);
+      await createMilestone("audit", auditMilestone, now + 8);
+
+      const startTime = now + 4;
+      await program.methods
+        .createVesting(
+          { linear: {} },
+          new anchor.BN(totalAmount),
+          new anchor.BN(startTime),
+          new anchor.BN(startTime + 10),
+          new anchor.BN(startTime),
+          { contributors: {} },
+          "Milestone grant",
+          null,
+          defaultTerms,
+          null,
+          null,
+          null
+        )
+        .accounts({
+          daoConfig,
+          authority: daoAuthority.publicKey,
+          recipient: builder.publicKey,
+          vesting: gatedVesting,
+          vestingAta: gatedVestingAta,
+          treasuryMint,
+          authorityAta,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([daoAuthority])
+        .rpc();
+
+      await program.methods
+        .setVestingMilestones([
+          { milestone: mainnetMilestone, unlockBps: 6000 },
+          { milestone: auditMilestone, unlockBps: 4000 },
+        ])
+        .accounts({ daoConfig, authority: daoAuthority.publicKey, vesting: gatedVesting })
+        .remainingAccounts([
+          { pubkey: mainnetMilestone, isWritable: false, isSigner: false },
+          { pubkey: auditMilestone, isWritable: false, isSigner: false },
+        ])
+        .signers([daoAuthority])
+        .rpc();
+    });
+
+    it("Should hold back time-vested tokens until a milestone is achieved", async () => {
+      await new Promise(resolve => setTimeout(resolve, 4000));
+      try {
+        await program.methods
+          .claimVesting(new anchor.BN(1))
+          .accounts(claimAccounts())
+          .signers([builder])
+          .rpc();
+        assert.fail("Should have failed");
+      } catch (error) {
+        assert.include(error.message, "InsufficientVestedTokens");
+      }
+    });
+
+    it("Should not re-gate a grant once it has started vesting", async () => {
+      try {
+        await program.methods
+          .setVestingMilestones([{ milestone: auditMilestone, unlockBps: 10000 }])
+          .accounts({ daoConfig, authority: daoAuthority.publicKey, vesting: gatedVesting })
+          .remainingAccounts([{ pubkey: auditMilestone, isWritable: false, isSigner: false }])
+          .signers([daoAuthority])
+          .rpc();
+        assert.fail("Should have failed");
+      } catch (error) {
+        assert.include(error.message, "MilestoneGatesLocked");
+      }
+    });
+
+    it("Should unlock the achieved milestone's share once synced", async () => {
+      await program.methods
+        .attestMilestone()
+        .accounts({ milestone: mainnetMilestone, attester: attester.publicKey })
+        .signers([attester])
+        .rpc();
+
+      await program.methods
+        .syncVestingMilestones()
+        .accounts({ vesting: gatedVesting })
+        .remainingAccounts([{ pubkey: mainnetMilestone, isWritable: false, isSigner: false }])
+        .rpc();
+
+      await new Promise(resolve => setTimeout(resolve, 11000));
+
+      await program.methods
+        .claimVesting(new anchor.BN((totalAmount * 6) / 10))
+        .accounts(claimAccounts())
+        .signers([builder])
+        .rpc();
+
+      const builderBalance = await getAccount(provider.connection, builderAta);
+      assert.equal(Number(builderBalance.amount), (totalAmount * 6) / 10);
+    });
+
+    it("Should let the DAO reclaim the share of a missed milestone", async () => {
+      const authorityBefore = await getAccount(provider.connection, authorityAta);
+
+      await program.methods
+        .reclaimMissedMilestone()
+        .accounts({
+          daoConfig,
+          authority: daoAuthority.publicKey,
+          vesting: gatedVesting,
+          milestone: auditMilestone,
+          vestingAta: gatedVestingAta,
+          authorityAta,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([daoAuthority])
+        .rpc();
+
+      const authorityAfter = await getAccount(provider.connection, authorityAta);
+      assert.equal(Number(authorityAfter.amount) - Number(authorityBefore.amount), (totalAmount * 4) / 10);
+
+      const vestingAccount = await program.account.vesting.fetch(gatedVesting);
+      assert.equal(vestingAccount.totalAmount.toNumber(), (totalAmount * 6) / 10);
+      assert.equal(vestingAccount.milestones.length, 1);
+      assert.equal(vestingAccount.milestones[0].unlockBps, 10000);
+      assert.deepEqual(vestingAccount.status, { completed: {} });
+    });
+
+    it("Should keep milestone gates in place when a grant accelerates", async () => {
+      const acquiree = Keypair.generate();
+      const [acquireeVesting] = getPda([Buffer.from("vesting"), daoConfig.toBuffer(), acquiree.publicKey.toBuffer()]);
+      const acquireeVestingAta = getAssociatedTokenAddressSync(treasuryMint, acquireeVesting, true);
+      const acquireeAta = await createTokenAccount(treasuryMint, acquiree.publicKey);
+      const [launchMilestone] = getPda([Buffer.from("milestone"), daoConfig.toBuffer(), Buffer.from("launch")]);
+
+      const now = getCurrentTimestamp();
+      await createMilestone("launch", launchMilestone, now + 3600);
+      await program.methods
+        .createVesting(
+          { linear: {} },
+          new anchor.BN(totalAmount),
+          new anchor.BN(now + 600),
+          new anchor.BN(now + 4200),
+          new anchor.BN(now + 600),
+          { contributors: {} },
+          "Milestone grant with acceleration",
+          null,
+          defaultTerms,
+          null,
+          null,
+          null
+        )
+        .accounts({
+          daoConfig,
+          authority: daoAuthority.publicKey,
+          recipient: acquiree.publicKey,
+          vesting: acquireeVesting,
+          vestingAta: acquireeVestingAta,
+          treasuryMint,
+          authorityAta,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([daoAuthority])
+        .rpc();
+
+      await program.methods
+        .setVestingMilestones([{ milestone: launchMilestone, unlockBps: 10000 }])
+        .accounts({ daoConfig, authority: daoAuthority.publicKey, vesting: acquireeVesting })
+        .remainingAccounts([{ pubkey: launchMilestone, isWritable: false, isSigner: false }])
+        .signers([daoAuthority])
+        .rpc();
+      await program.methods
+        .setAccelerationTerms({ trigger: { singleTrigger: {} }, accelerateBps: 10000, terminationWindow: new anchor.BN(0) })
+        .accounts({ daoConfig, authority: daoAuthority.publicKey, vesting: acquireeVesting })
+        .signers([daoAuthority])
+        .rpc();
+
+      const dao = await program.account.daoConfig.fetch(daoConfig);
+      const indexBytes = Buffer.alloc(4);
+      indexBytes.writeUInt32LE(dao.accelerationEventCount);
+      const [accelerationEvent] = getPda([Buffer.from("acceleration_event"), daoConfig.toBuffer(), indexBytes]);
+      await program.methods
+        .triggerAcceleration()
+        .accounts({
+          daoConfig,
+          authority: daoAuthority.publicKey,
+          accelerationEvent,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([daoAuthority])
+        .rpc();
+      await program.methods
+        .applyAcceleration()
+        .accounts({ accelerationEvent, vesting: acquireeVesting })
+        .rpc();
+
+      // The whole schedule accelerated, but the launch milestone still gates all of it
+      try {
+        await program.methods
+          .claimVesting(new anchor.BN(1))
+          .accounts({
+            ...claimAccounts(),
+            vesting: acquireeVesting,
+            recipient: acquiree.publicKey,
+            vestingAta: acquireeVestingAta,
+            recipientAta: acquireeAta,
+          })
+          .signers([acquiree])
+          .rpc();
+        assert.fail("Should have failed");
+      } catch (error) {
+        assert.include(error.message, "InsufficientVestedTokens");
+      }
+    });
+  });
 }); 
\ No newline at end of file